@@ -4,6 +4,7 @@ use std::fs::{read_dir, write};
 
 fn main() {
     println!("cargo:rerun-if-changed=src/");
+    println!("cargo:rerun-if-changed=assets/");
     create_asset_paths()
 }
 
@@ -0,0 +1,158 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::core::prelude::*;
+
+pub(super) struct AccessibilityPlugin;
+
+impl Plugin for AccessibilityPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .register_type::<AccessibilitySettings>()
+            .register_type::<GhostPalette>()
+        ;
+    }
+}
+
+/// Options which make the game easier to read for players who cannot tell the ghosts or their states
+/// apart by color alone. Part of the settings.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+#[serde(default)]
+pub struct AccessibilitySettings {
+    /// The body colors of the ghosts. Takes effect with the next start of the game.
+    pub ghost_palette: GhostPalette,
+    /// Shows the initial of every ghost above it. Takes effect when the ghosts spawn the next time.
+    pub ghost_markers: bool,
+    /// Draws the maze walls in a bright color instead of blue or the color of the ruleset. Takes effect
+    /// when the next round starts.
+    pub high_contrast_maze: bool,
+    /// Lets frightened ghosts pulse in size when the energizer is running out, additionally to their blinking.
+    pub frightened_warning: bool,
+}
+
+/// The colors the ghost bodies are drawn with.
+///
+/// The alternative palettes keep the ghosts apart by brightness and use colors which stay distinct with
+/// the named color vision deficiency. The textures get recolored by replacing the exact body colors of the
+/// arcade, so a theme whose ghosts have other colors keeps them with every palette.
+#[derive(Reflect, Serialize, Deserialize, Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum GhostPalette {
+    /// The colors of the arcade
+    #[default]
+    Arcade,
+    /// For protanopia and deuteranopia, where red and green look alike
+    RedGreen,
+    /// For tritanopia, where blue and green, as well as yellow and pink, look alike. The ghosts differ
+    /// between red and teal, which tritanopia keeps apart, and in brightness
+    BlueYellow,
+}
+
+impl GhostPalette {
+    const ALL: [GhostPalette; 3] = [
+        GhostPalette::Arcade,
+        GhostPalette::RedGreen,
+        GhostPalette::BlueYellow,
+    ];
+
+    pub fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|palette| palette == self).unwrap_or_default();
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    pub fn previous(&self) -> Self {
+        let index = Self::ALL.iter().position(|palette| palette == self).unwrap_or_default();
+        Self::ALL[(index + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    /// The body color of the given ghost as RGB bytes.
+    pub fn body_color(&self, ghost: Ghost) -> [u8; 3] {
+        match (self, ghost) {
+            (GhostPalette::Arcade, Blinky) => [0xFF, 0x00, 0x00],
+            (GhostPalette::Arcade, Pinky) => [0xFF, 0xB8, 0xFF],
+            (GhostPalette::Arcade, Inky) => [0x00, 0xFF, 0xFF],
+            (GhostPalette::Arcade, Clyde) => [0xFF, 0xB8, 0x52],
+            (GhostPalette::RedGreen, Blinky) => [0xD5, 0x5E, 0x00],
+            (GhostPalette::RedGreen, Pinky) => [0xCC, 0x79, 0xA7],
+            (GhostPalette::RedGreen, Inky) => [0x56, 0xB4, 0xE9],
+            (GhostPalette::RedGreen, Clyde) => [0xF0, 0xE4, 0x42],
+            (GhostPalette::BlueYellow, Blinky) => [0xE0, 0x00, 0x00],
+            (GhostPalette::BlueYellow, Pinky) => [0xFF, 0xA0, 0xC8],
+            (GhostPalette::BlueYellow, Inky) => [0x00, 0x80, 0x80],
+            (GhostPalette::BlueYellow, Clyde) => [0xD0, 0xD0, 0xD0],
+        }
+    }
+}
+
+/// The blue of the maze walls in the arcade textures.
+pub const ARCADE_WALL_COLOR: [u8; 3] = [0x1E, 0x1E, 0xE9];
+/// The color of the maze walls with [AccessibilitySettings::high_contrast_maze]. The walls still flash white at
+/// the end of a level.
+pub const HIGH_CONTRAST_WALL_COLOR: [u8; 3] = [0xFF, 0xFF, 0x00];
+
+/// Replace every opaque pixel with the color `from` in the given RGBA8 image data with the color `to`.
+/// Returns true if there was any pixel to replace.
+pub fn replace_color(data: &mut [u8], from: [u8; 3], to: [u8; 3]) -> bool {
+    let mut replaced = false;
+
+    for pixel in data.chunks_exact_mut(4) {
+        if pixel[3] > 0 && pixel[..3] == from {
+            pixel[..3].copy_from_slice(&to);
+            replaced = true;
+        }
+    }
+
+    replaced
+}
+
+/// The letter which marks the given ghost with [AccessibilitySettings::ghost_markers].
+pub fn ghost_marker(ghost: Ghost) -> &'static str {
+    match ghost {
+        Blinky => "B",
+        Pinky => "P",
+        Inky => "I",
+        Clyde => "C",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::prelude::*;
+
+    #[test]
+    fn only_opaque_pixels_of_the_given_color_are_replaced() {
+        let mut data = vec![
+            0xFF, 0x00, 0x00, 0xFF,
+            0xFF, 0x00, 0x00, 0x00,
+            0xFF, 0xFF, 0xFF, 0xFF,
+        ];
+
+        assert!(replace_color(&mut data, [0xFF, 0x00, 0x00], [0x00, 0x00, 0xFF]));
+        assert!(!replace_color(&mut data, [0xFF, 0x00, 0x00], [0x00, 0x00, 0xFF]));
+
+        assert_eq!(data, vec![
+            0x00, 0x00, 0xFF, 0xFF,
+            0xFF, 0x00, 0x00, 0x00,
+            0xFF, 0xFF, 0xFF, 0xFF,
+        ]);
+    }
+
+    #[test]
+    fn every_palette_keeps_the_ghosts_apart() {
+        for palette in [GhostPalette::Arcade, GhostPalette::RedGreen, GhostPalette::BlueYellow] {
+            let mut colors = [Blinky, Pinky, Inky, Clyde].map(|ghost| palette.body_color(ghost)).to_vec();
+            colors.sort();
+            colors.dedup();
+
+            assert_eq!(colors.len(), 4);
+        }
+    }
+
+    #[test]
+    fn the_alternative_palettes_recolor_every_ghost() {
+        for palette in [GhostPalette::RedGreen, GhostPalette::BlueYellow] {
+            for ghost in [Blinky, Pinky, Inky, Clyde] {
+                assert_ne!(palette.body_color(ghost), GhostPalette::Arcade.body_color(ghost));
+            }
+        }
+    }
+}
@@ -7,33 +7,120 @@ pub struct AnimationPlugin;
 impl Plugin for AnimationPlugin {
     fn build(&self, app: &mut App) {
         app
-            .add_systems(Update, update_entities_with_animations)
+            .add_event::<AnimationFinished>()
+            .init_resource::<AnimationClocks>()
+            .add_systems(Update, (
+                tick_animation_clocks,
+                update_entities_with_animations
+            ).chain())
         ;
     }
 }
 
+/// The clock of the ghost bodies, so all ghosts flap in sync.
+pub const GHOST_BODY_CLOCK: &str = "ghost_body";
+/// The clock of the energizers, so they all blink together.
+pub const ENERGIZER_BLINK_CLOCK: &str = "energizer_blink";
+/// The clock of the walls, so the maze flashes in phase when a level is cleared.
+pub const MAZE_FLASH_CLOCK: &str = "maze_flash";
+
+/// Named clocks which are shared between many entities.
+///
+/// An [Animations] component which subscribed to a clock does not use the timers of its animations.
+/// Instead, the current texture is picked by how long the clock is running. Every entity with the same
+/// clock therefore shows the same phase of an animation, no matter when it switched to it.
+///
+/// Clocks which are not known yet get started when the first animation subscribes to them.
+#[derive(Resource)]
+pub struct AnimationClocks {
+    clocks: HashMap<String, Duration>,
+}
+
+impl Default for AnimationClocks {
+    fn default() -> Self {
+        AnimationClocks {
+            clocks: [GHOST_BODY_CLOCK, ENERGIZER_BLINK_CLOCK, MAZE_FLASH_CLOCK]
+                .into_iter()
+                .map(|name| (name.to_string(), Duration::ZERO))
+                .collect()
+        }
+    }
+}
+
+impl AnimationClocks {
+    /// How long the clock with the given name is running. Zero if it does not exist.
+    pub fn elapsed(&self, name: &str) -> Duration {
+        self.clocks.get(name).copied().unwrap_or_default()
+    }
+
+    /// How often the given interval passed since the clock with the given name started.
+    pub fn intervals(&self, name: &str, interval: Duration) -> u128 {
+        self.elapsed(name).as_nanos() / interval.as_nanos()
+    }
+
+    /// Start the clock with the given name again, so every animation subscribed to it starts from the beginning.
+    pub fn reset(&mut self, name: &str) {
+        self.clocks.insert(name.to_string(), Duration::ZERO);
+    }
+
+    fn tick(&mut self, delta: Duration) {
+        for elapsed in self.clocks.values_mut() {
+            *elapsed += delta;
+        }
+    }
+}
+
+fn tick_animation_clocks(
+    time: Res<Time>,
+    mut clocks: ResMut<AnimationClocks>,
+) {
+    clocks.tick(time.delta());
+}
+
+/// Fired when the current animation of an entity finished, which means a non repeating animation
+/// showed its last texture for its full duration. Repeating animations never finish.
+#[derive(Event, Clone, Debug)]
+pub struct AnimationFinished {
+    pub entity: Entity,
+    /// The name of the finished animation in the [Animations] of the entity
+    pub name: String,
+}
+
 fn update_entities_with_animations(
     time: Res<Time>,
-    mut query: Query<(&Visibility, &mut Handle<Image>, &mut Animations)>,
+    mut clocks: ResMut<AnimationClocks>,
+    mut event_writer: EventWriter<AnimationFinished>,
+    mut query: Query<(Entity, &Visibility, &mut Handle<Image>, &mut Animations)>,
 ) {
     let delta = time.delta();
-    for (visibility, mut texture, mut animations) in query.iter_mut() {
-        if visibility != Visibility::Hidden && animations.is_running() {
-            animations.current_mut().update(delta);
-            *texture = animations.current().texture()
+    for (entity, visibility, mut texture, mut animations) in query.iter_mut() {
+        if visibility == Visibility::Hidden || !animations.is_running() {
+            continue;
+        }
+
+        match animations.clock {
+            Some(ref clock) => {
+                let elapsed = *clocks.clocks.entry(clock.clone()).or_default();
+                *texture = animations.current().texture_at(elapsed);
+            }
+            None => {
+                if animations.current_mut().update(delta) {
+                    event_writer.send(AnimationFinished { entity, name: animations.current_name().to_string() });
+                }
+
+                *texture = animations.current().texture()
+            }
         }
     }
 }
 
 /// Component that describes a running animation of some entity.
 ///
-/// Basically, you provide a vec of image handles and an animation time. Based
+/// Basically, you provide a vec of image handles and how long each of them is shown. Based
 /// on the elapsed time the index of the current texture is determined.
 ///
 /// The animation can be repeatable or not. If it is not repeatable, the last texture
 /// in the vector is returned forever.
-///
-/// TODO: add the ability to check if an animation changed
 #[derive(Clone)]
 pub enum Animation {
     SingleTexture {
@@ -44,6 +131,10 @@ pub enum Animation {
         timer: Timer,
         repeating: bool,
         textures: Vec<Handle<Image>>,
+        /// How long every texture is shown, by the index of the texture
+        durations: Vec<Duration>,
+        /// If a non repeating animation showed all its textures
+        finished: bool,
     },
 }
 
@@ -55,18 +146,17 @@ impl Animation {
         Animation::SingleTexture { texture }
     }
 
-    /// Create an animation from an iterator of image handles.
-    pub fn from_textures(duration_secs: f32, repeating: bool, textures: impl IntoIterator<Item=Handle<Image>>) -> Self {
-        let textures = textures
-            .into_iter()
-            .collect::<Vec<_>>();
-        let texture_display_time = duration_secs / textures.len() as f32;
+    /// Create an animation from an iterator of image handles and how long each of them is shown.
+    pub fn from_frames(repeating: bool, frames: impl IntoIterator<Item=(Handle<Image>, Duration)>) -> Self {
+        let (textures, durations): (Vec<_>, Vec<_>) = frames.into_iter().unzip();
 
         Animation::TextureList {
             current_texture_index: 0,
-            timer: Timer::new(Duration::from_secs_f32(texture_display_time), TimerMode::Repeating),
+            timer: Timer::new(durations[0], TimerMode::Repeating),
             repeating,
             textures,
+            durations,
+            finished: false,
         }
     }
 
@@ -74,26 +164,41 @@ impl Animation {
     ///
     /// For texture lists and sprite sheet, this process is mostly the same: The timer for the current sprite gets
     /// updated with the given delta.
-    /// If the timer finished, increase the current texture index.
+    /// For every time the timer finished, increase the current texture index.
     /// But if the index is at its max, set it to zero if the animation does not repeat.
+    /// The timer then runs as long as the new texture should be shown.
     ///
-    /// If the animation is stopped or it is a single texture animation, do nothing.
-    pub fn update(&mut self, delta: Duration) {
-        let (current_texture_index, timer, repeating, num_textures) = match self {
-            Animation::SingleTexture { .. } => return,
-            Animation::TextureList { ref mut current_texture_index, timer, repeating, textures, .. } => (current_texture_index, timer, repeating, textures.len()),
+    /// If the animation is stopped, finished or it is a single texture animation, do nothing.
+    ///
+    /// Returns true if the animation finished with this update.
+    pub fn update(&mut self, delta: Duration) -> bool {
+        let (current_texture_index, timer, repeating, durations, finished) = match self {
+            Animation::SingleTexture { .. } => return false,
+            Animation::TextureList { ref mut current_texture_index, timer, repeating, durations, finished, .. } => (current_texture_index, timer, repeating, durations, finished),
         };
 
+        if *finished {
+            return false;
+        }
+
         timer.tick(delta);
 
-        if timer.just_finished() {
-            let at_last_index = *current_texture_index == num_textures - 1;
-            match (repeating, at_last_index) {
+        // A long delta can finish the timer more than once, so the animation skips as many textures.
+        // The time left over stays on the timer and counts for the next texture.
+        for _ in 0..timer.times_finished_this_tick() {
+            let at_last_index = *current_texture_index == durations.len() - 1;
+            match (*repeating, at_last_index) {
                 (true, true) => *current_texture_index = 0,
                 (_, false) => *current_texture_index += 1,
-                (false, true) => ()
+                (false, true) => {
+                    *finished = true;
+                    return true;
+                }
             }
+            timer.set_duration(durations[*current_texture_index]);
         }
+
+        false
     }
 
     pub fn texture(&self) -> Handle<Image> {
@@ -103,38 +208,45 @@ impl Animation {
         }
     }
 
+    /// The texture the animation shows after it ran for the given time, without using its own timer.
+    /// A non repeating animation shows its last texture once the time is over.
+    pub fn texture_at(&self, elapsed: Duration) -> Handle<Image> {
+        let (repeating, textures, durations) = match self {
+            Animation::SingleTexture { texture } => return texture.clone(),
+            Animation::TextureList { repeating, textures, durations, .. } => (repeating, textures, durations),
+        };
+
+        let total = durations.iter().sum::<Duration>().as_nanos().max(1);
+        let mut time_in_iteration = match repeating {
+            true => elapsed.as_nanos() % total,
+            false => elapsed.as_nanos().min(total - 1),
+        };
+
+        for (texture, duration) in textures.iter().zip(durations) {
+            if time_in_iteration < duration.as_nanos() {
+                return texture.clone();
+            }
+            time_in_iteration -= duration.as_nanos();
+        }
+
+        textures[textures.len() - 1].clone()
+    }
+
     /// Rewind the animation back to the start. This means:
     /// - reset the timer
     /// - set the current texture index to zero
+    /// - make a finished animation play again
     pub fn reset(&mut self) {
-        let (current_texture_index, timer) = match self {
+        let (current_texture_index, timer, durations, finished) = match self {
             Animation::SingleTexture { .. } => return,
-            Animation::TextureList { ref mut current_texture_index, timer, .. } => (current_texture_index, timer),
+            Animation::TextureList { ref mut current_texture_index, timer, durations, finished, .. } => (current_texture_index, timer, durations, finished),
         };
 
+        timer.set_duration(durations[0]);
         timer.reset();
         *current_texture_index = 0;
+        *finished = false;
     }
-
-    // Return if the current animation iteration is over
-    // pub fn is_finished(&self) -> bool {
-    //     let (current_texture_index, timer, num_textures) = match self {
-    //         Animation::SingleTexture { .. } => return true,
-    //         Animation::TextureList { current_texture_index, timer, textures, .. } => (current_texture_index, timer, textures.len()),
-    //     };
-    //
-    //     *current_texture_index == num_textures - 1 && timer.just_finished()
-    // }
-
-    // Return if an animation is completely finished, not just the current iteration
-    // pub fn is_completely_finished(&self) -> bool {
-    //     let repeating = match self {
-    //         Animation::SingleTexture { .. } => false,
-    //         Animation::TextureList { repeating, .. } => *repeating,
-    //     };
-    //
-    //     !repeating && self.is_finished()
-    // }
 }
 
 /// Component for entities that might have more than one animation.
@@ -144,14 +256,14 @@ impl Animation {
 ///
 /// The animation can be switched at runtime. Every animation is identified by a string.
 ///
-/// TODO: We could need an Animations variant with a shared timer between animations.
-///  The ghosts currently use all the same timer duration, and the animation transition could look
-///  smoother this way
+/// The animations can subscribe to one of the [AnimationClocks], so they run in sync with every other
+/// entity using the same clock.
 #[derive(Clone, Component)]
 pub struct Animations {
     atlas: HashMap<String, Animation>,
     current: String,
-    running: bool
+    running: bool,
+    clock: Option<String>,
 }
 
 impl Animations {
@@ -159,14 +271,27 @@ impl Animations {
         Animations {
             atlas: animations.into_iter().map(|(s, anims)| (s.to_string(), anims)).collect(),
             current: current.to_string(),
-            running: true
+            running: true,
+            clock: None,
         }
     }
 
+    /// Let the animations follow the shared clock with the given name instead of their own timers.
+    /// They never send an [AnimationFinished] then.
+    pub fn with_clock(mut self, clock: impl ToString) -> Self {
+        self.clock = Some(clock.to_string());
+        self
+    }
+
     pub fn current(&self) -> &Animation {
         self.atlas.get(&self.current).expect("current set animation is not part of the animation atlas")
     }
 
+    /// The name of the current animation.
+    pub fn current_name(&self) -> &str {
+        &self.current
+    }
+
     pub fn current_mut(&mut self) -> &mut Animation {
         self.atlas.get_mut(&self.current).expect("current set animation is not part of the animation atlas")
     }
@@ -199,4 +324,76 @@ impl Animations {
     pub fn resume(&mut self) {
         self.running = true
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::prelude::*;
+
+    use crate::core::prelude::*;
+
+    fn frames(millis: [u64; 3]) -> impl IntoIterator<Item=(Handle<Image>, Duration)> {
+        (0..).zip(millis).map(|(i, ms)| (Handle::weak_from_u128(i), Duration::from_millis(ms)))
+    }
+
+    #[test]
+    fn every_texture_is_shown_for_its_own_duration() {
+        let mut animation = Animation::from_frames(true, frames([100, 300, 100]));
+
+        animation.update(Duration::from_millis(100));
+        animation.update(Duration::from_millis(200));
+        let Animation::TextureList { current_texture_index, .. } = &animation else { unreachable!() };
+        assert_eq!(*current_texture_index, 1);
+
+        animation.update(Duration::from_millis(100));
+        let Animation::TextureList { current_texture_index, .. } = &animation else { unreachable!() };
+        assert_eq!(*current_texture_index, 2);
+    }
+
+    #[test]
+    fn a_non_repeating_animation_finishes_once() {
+        let mut animation = Animation::from_frames(false, frames([100, 100, 100]));
+
+        let finished = (0..10)
+            .filter(|_| animation.update(Duration::from_millis(100)))
+            .count();
+        assert_eq!(finished, 1);
+
+        animation.reset();
+        assert!(!animation.update(Duration::from_millis(250)));
+        assert!(animation.update(Duration::from_millis(50)));
+    }
+
+    #[test]
+    fn a_repeating_animation_never_finishes() {
+        let mut animation = Animation::from_frames(true, frames([100, 100, 100]));
+
+        assert!((0..10).all(|_| !animation.update(Duration::from_millis(100))));
+    }
+
+    #[test]
+    fn an_animation_on_a_clock_shows_the_texture_for_the_elapsed_time() {
+        let repeating = Animation::from_frames(true, frames([100, 300, 100]));
+        let once = Animation::from_frames(false, frames([100, 300, 100]));
+        let texture_at = |animation: &Animation, ms| animation.texture_at(Duration::from_millis(ms));
+
+        assert_eq!(texture_at(&repeating, 50), Handle::weak_from_u128(0));
+        assert_eq!(texture_at(&repeating, 350), Handle::weak_from_u128(1));
+        assert_eq!(texture_at(&repeating, 450), Handle::weak_from_u128(2));
+        assert_eq!(texture_at(&repeating, 550), Handle::weak_from_u128(0));
+        assert_eq!(texture_at(&once, 550), Handle::weak_from_u128(2));
+    }
+
+    #[test]
+    fn a_reset_clock_starts_from_zero() {
+        let mut clocks = AnimationClocks::default();
+        clocks.tick(Duration::from_millis(450));
+        assert_eq!(clocks.intervals(MAZE_FLASH_CLOCK, Duration::from_millis(150)), 3);
+
+        clocks.reset(MAZE_FLASH_CLOCK);
+        assert_eq!(clocks.elapsed(MAZE_FLASH_CLOCK), Duration::ZERO);
+        assert_eq!(clocks.elapsed(GHOST_BODY_CLOCK), Duration::from_millis(450));
+    }
+}
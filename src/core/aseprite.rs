@@ -0,0 +1,457 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
+
+use bevy::asset::{AssetLoader, AsyncReadExt, LoadContext, LoadState};
+use bevy::asset::io::Reader;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension};
+use serde::{Deserialize, Deserializer};
+use serde::de::{MapAccess, SeqAccess, Visitor};
+
+use crate::core::prelude::*;
+
+/// Loads the animations of the game from Aseprite JSON exports, so the frames and their timing are defined
+/// in Aseprite and not in code.
+///
+/// Every `.aseprite.json` file in the assets becomes an [AsepriteAnimations] asset. The sprite sheet crate
+/// reads these files too, so this plugin must be added before it: if both can load an untyped path, the
+/// one added last wins. Typed loads (which this plugin uses) always pick the right loader.
+pub(super) struct AsepritePlugin;
+
+impl Plugin for AsepritePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_asset::<AsepriteAnimations>()
+            .register_asset_loader(AsepriteLoader)
+            .add_systems(OnEnter(Setup(LoadAnimations)), start_loading_animation_sheets)
+            .add_systems(Update, finish_loading_animation_sheets.run_if(in_state(Setup(LoadAnimations))))
+        ;
+    }
+}
+
+const ASEPRITE_EXTENSION: &str = ".aseprite.json";
+
+/// The name of the animation which contains every frame of a sheet without frame tags.
+pub const UNTAGGED_ANIMATION: &str = "default";
+
+/// The animations of a single Aseprite export, by the names of their frame tags.
+///
+/// Every frame keeps the duration it has in Aseprite. A tag which repeats a fixed number of times
+/// plays that often and stops at its last frame, every other tag repeats forever. A sheet without tags has a single animation
+/// with all frames, called [UNTAGGED_ANIMATION].
+#[derive(Asset, TypePath, Clone, Debug)]
+pub struct AsepriteAnimations {
+    animations: HashMap<String, AsepriteAnimation>,
+}
+
+#[derive(Clone, Debug)]
+struct AsepriteAnimation {
+    frames: Vec<(Handle<Image>, Duration)>,
+    repeating: bool,
+}
+
+impl AsepriteAnimations {
+    /// Create a fresh [Animation] from the frame tag with the given name.
+    pub fn animation(&self, name: &str) -> Animation {
+        let animation = self.animations
+            .get(name)
+            .unwrap_or_else(|| panic!("the sheet has no animation called {name}"));
+
+        Animation::from_frames(animation.repeating, animation.frames.iter().cloned())
+    }
+
+    /// Create an [Animation] from a sheet without frame tags.
+    pub fn untagged(&self) -> Animation {
+        self.animation(UNTAGGED_ANIMATION)
+    }
+
+    /// Every image of the sheet. Frames which belong to several animations are returned once per animation.
+    pub fn images(&self) -> impl Iterator<Item=&Handle<Image>> {
+        self.animations
+            .values()
+            .flat_map(|animation| animation.frames.iter().map(|(image, _)| image))
+    }
+}
+
+/// Every loaded Aseprite export, by its path relative to the assets folder and without the extension,
+/// like "textures/ghost/blinky_up". Exists from Setup(LoadTheme) on.
+#[derive(Resource)]
+pub struct AnimationSheets {
+    sheets: HashMap<String, AsepriteAnimations>,
+}
+
+impl AnimationSheets {
+    pub fn get_sheet(&self, key: impl AsRef<str>) -> &AsepriteAnimations {
+        let key = key.as_ref();
+        self.sheets.get(key).unwrap_or_else(|| panic!("the animation sheet {key} does not exist"))
+    }
+}
+
+/// The Aseprite exports which are still loading.
+#[derive(Resource)]
+struct LoadingAnimationSheets(HashMap<String, Handle<AsepriteAnimations>>);
+
+fn start_loading_animation_sheets(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    let handles = asset_paths_with_extension(ASEPRITE_EXTENSION)
+        .into_iter()
+        .filter_map(|path| path.strip_suffix(ASEPRITE_EXTENSION).map(|key| (key.to_string(), asset_server.load::<AsepriteAnimations>(&path))))
+        .collect();
+
+    commands.insert_resource(LoadingAnimationSheets(handles));
+}
+
+fn finish_loading_animation_sheets(
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<GameState>>,
+    asset_server: Res<AssetServer>,
+    animations: Res<Assets<AsepriteAnimations>>,
+    loading: Res<LoadingAnimationSheets>,
+) {
+    for (key, handle) in &loading.0 {
+        if let Some(LoadState::Failed(e)) = asset_server.get_load_state(handle) {
+            panic!("the animation sheet {key} could not be loaded: {e}")
+        }
+    }
+
+    let Some(sheets) = loading.0
+        .iter()
+        .map(|(key, handle)| animations.get(handle).map(|animations| (key.clone(), animations.clone())))
+        .collect::<Option<HashMap<_, _>>>() else {
+        return;
+    };
+
+    commands.insert_resource(AnimationSheets { sheets });
+    commands.remove_resource::<LoadingAnimationSheets>();
+    next_state.set(Setup(LoadTheme));
+}
+
+#[derive(Default)]
+struct AsepriteLoader;
+
+impl AssetLoader for AsepriteLoader {
+    type Asset = AsepriteAnimations;
+    type Settings = ();
+    type Error = AsepriteLoaderError;
+
+    async fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader<'_>,
+        _settings: &'a (),
+        load_context: &'a mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await.map_err(|e| AsepriteLoaderError::Io(e.to_string()))?;
+        let export = serde_json::from_slice::<AsepriteExport>(&bytes).map_err(|e| AsepriteLoaderError::Json(e.to_string()))?;
+
+        let sheet_path = sheet_path(load_context.path(), &export.meta);
+        let sheet = load_context
+            .loader()
+            .direct()
+            .load::<Image>(sheet_path)
+            .await
+            .map_err(|e| AsepriteLoaderError::Sheet(e.to_string()))?
+            .take();
+
+        let frames = export.frames
+            .iter()
+            .enumerate()
+            .map(|(i, frame)| Ok((
+                load_context.add_labeled_asset(format!("frame{i}"), crop(&sheet, &frame.frame).ok_or(AsepriteLoaderError::Frame(i))?),
+                Duration::from_millis(frame.duration),
+            )))
+            .collect::<Result<Vec<_>, AsepriteLoaderError>>()?;
+
+        let animations = export
+            .frame_sequences()
+            .into_iter()
+            .map(|(name, indices, repeating)| (name, AsepriteAnimation {
+                frames: indices.into_iter().map(|i| frames[i].clone()).collect(),
+                repeating,
+            }))
+            .collect();
+
+        Ok(AsepriteAnimations { animations })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["aseprite.json"]
+    }
+}
+
+#[derive(Debug)]
+enum AsepriteLoaderError {
+    Io(String),
+    Json(String),
+    Sheet(String),
+    /// The frame with this index lies outside of the sprite sheet
+    Frame(usize),
+}
+
+impl fmt::Display for AsepriteLoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AsepriteLoaderError::Io(e) => write!(f, "could not read the export: {e}"),
+            AsepriteLoaderError::Json(e) => write!(f, "the export is no valid Aseprite JSON: {e}"),
+            AsepriteLoaderError::Sheet(e) => write!(f, "could not load the sprite sheet of the export: {e}"),
+            AsepriteLoaderError::Frame(i) => write!(f, "the frame {i} lies outside of the sprite sheet"),
+        }
+    }
+}
+
+impl std::error::Error for AsepriteLoaderError {}
+
+/// The image of the export. Exports without an image name belong to the PNG next to them with the same name.
+fn sheet_path(export_path: &Path, meta: &AsepriteMeta) -> String {
+    let file_name = export_path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+    let image = match &meta.image {
+        Some(image) => image.clone(),
+        None => format!("{}.png", file_name.strip_suffix(ASEPRITE_EXTENSION).unwrap_or(file_name)),
+    };
+
+    export_path
+        .with_file_name(image)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Copy the given rectangle of the sheet into a new image. None if the rectangle does not fit into the sheet.
+fn crop(sheet: &Image, rect: &FrameRect) -> Option<Image> {
+    let pixel_size = sheet.texture_descriptor.format.block_copy_size(None).unwrap_or(4) as usize;
+    let sheet_width = sheet.width() as usize;
+    let (x, y, w, h) = (rect.x as usize, rect.y as usize, rect.w as usize, rect.h as usize);
+
+    if x + w > sheet_width || y + h > sheet.height() as usize || sheet.data.len() < sheet_width * sheet.height() as usize * pixel_size {
+        return None;
+    }
+
+    let data = (y..y + h)
+        .flat_map(|row| {
+            let start = (row * sheet_width + x) * pixel_size;
+            sheet.data[start..start + w * pixel_size].iter().copied()
+        })
+        .collect();
+
+    let mut image = Image::new(
+        Extent3d { width: rect.w, height: rect.h, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        data,
+        sheet.texture_descriptor.format,
+        sheet.asset_usage,
+    );
+    image.sampler = sheet.sampler.clone();
+    Some(image)
+}
+
+/// The parts of an Aseprite JSON export the game needs. Aseprite exports the frames either as
+/// a list or as a map from the frame name to the frame, both are supported.
+#[derive(Deserialize, Debug)]
+struct AsepriteExport {
+    #[serde(deserialize_with = "deserialize_frames")]
+    frames: Vec<AsepriteFrame>,
+    meta: AsepriteMeta,
+}
+
+#[derive(Deserialize, Debug)]
+struct AsepriteFrame {
+    frame: FrameRect,
+    /// The duration of the frame in milliseconds
+    duration: u64,
+}
+
+#[derive(Deserialize, Debug)]
+struct FrameRect {
+    x: u32,
+    y: u32,
+    w: u32,
+    h: u32,
+}
+
+#[derive(Deserialize, Debug)]
+struct AsepriteMeta {
+    image: Option<String>,
+    #[serde(default, rename = "frameTags")]
+    frame_tags: Vec<FrameTag>,
+}
+
+#[derive(Deserialize, Debug)]
+struct FrameTag {
+    name: String,
+    from: usize,
+    to: usize,
+    #[serde(default)]
+    direction: TagDirection,
+    /// How often the tag gets played, like "2". Missing if it repeats forever.
+    repeat: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum TagDirection {
+    #[default]
+    Forward,
+    Reverse,
+    Pingpong,
+    PingpongReverse,
+}
+
+impl AsepriteExport {
+    /// The name, the frame indices in playing order and if it repeats forever, for every animation of the export.
+    /// A tag which is played a fixed number of times contains its frames that often.
+    fn frame_sequences(&self) -> Vec<(String, Vec<usize>, bool)> {
+        if self.meta.frame_tags.is_empty() {
+            return vec![(UNTAGGED_ANIMATION.to_string(), (0..self.frames.len()).collect(), true)];
+        }
+
+        self.meta.frame_tags
+            .iter()
+            .map(|tag| match tag.repeat_count() {
+                Some(count) => (tag.name.clone(), tag.frame_indices().repeat(count), false),
+                None => (tag.name.clone(), tag.frame_indices(), true),
+            })
+            .collect()
+    }
+}
+
+impl FrameTag {
+    /// How often the tag gets played. None if it repeats forever, which is also the case for counts
+    /// Aseprite would not write, like "0".
+    fn repeat_count(&self) -> Option<usize> {
+        self.repeat
+            .as_ref()
+            .and_then(|repeat| repeat.parse::<usize>().ok())
+            .filter(|count| *count > 0)
+    }
+
+    fn frame_indices(&self) -> Vec<usize> {
+        let forward = (self.from..=self.to).collect::<Vec<_>>();
+        let reverse = forward.iter().rev().copied().collect::<Vec<_>>();
+        // a ping pong does not show the frames at its turning points twice
+        let there_and_back = |there: &[usize], back: &[usize]| there
+            .iter()
+            .chain(back.iter().skip(1).take(back.len().saturating_sub(2)))
+            .copied()
+            .collect();
+
+        match self.direction {
+            TagDirection::Forward => forward,
+            TagDirection::Reverse => reverse,
+            TagDirection::Pingpong => there_and_back(&forward, &reverse),
+            TagDirection::PingpongReverse => there_and_back(&reverse, &forward),
+        }
+    }
+}
+
+fn deserialize_frames<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<AsepriteFrame>, D::Error> {
+    struct FramesVisitor;
+
+    impl<'de> Visitor<'de> for FramesVisitor {
+        type Value = Vec<AsepriteFrame>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a list or a map of frames")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut frames = vec![];
+            while let Some(frame) = seq.next_element()? {
+                frames.push(frame);
+            }
+            Ok(frames)
+        }
+
+        /// The frames keep the order of the file, which is the order of the frames in Aseprite.
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut frames = vec![];
+            while let Some((_, frame)) = map.next_entry::<String, AsepriteFrame>()? {
+                frames.push(frame);
+            }
+            Ok(frames)
+        }
+    }
+
+    deserializer.deserialize_any(FramesVisitor)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use bevy::prelude::*;
+    use bevy::render::render_asset::RenderAssetUsages;
+    use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+    use crate::core::aseprite::{AsepriteExport, crop, FrameRect, sheet_path, UNTAGGED_ANIMATION};
+
+    #[test]
+    fn an_export_without_tags_is_one_repeating_animation() {
+        let export = serde_json::from_str::<AsepriteExport>(include_str!("../../test/blinky_left.json")).unwrap();
+
+        assert_eq!(export.frames.len(), 2);
+        assert_eq!(export.frames[1].frame.x, 16);
+        assert_eq!(export.frames[1].duration, 100);
+        assert_eq!(export.frame_sequences(), vec![(UNTAGGED_ANIMATION.to_string(), vec![0, 1], true)]);
+    }
+
+    #[test]
+    fn frame_tags_become_animations() {
+        let json = r#"{
+            "frames": [
+                { "frame": { "x": 0, "y": 0, "w": 16, "h": 16 }, "duration": 100 },
+                { "frame": { "x": 16, "y": 0, "w": 16, "h": 16 }, "duration": 200 },
+                { "frame": { "x": 32, "y": 0, "w": 16, "h": 16 }, "duration": 300 }
+            ],
+            "meta": {
+                "frameTags": [
+                    { "name": "idle", "from": 0, "to": 0, "direction": "forward" },
+                    { "name": "bounce", "from": 0, "to": 2, "direction": "pingpong" },
+                    { "name": "once", "from": 1, "to": 2, "direction": "reverse", "repeat": "1" },
+                    { "name": "twice", "from": 0, "to": 1, "direction": "forward", "repeat": "2" }
+                ]
+            }
+        }"#;
+        let export = serde_json::from_str::<AsepriteExport>(json).unwrap();
+
+        assert_eq!(export.frame_sequences(), vec![
+            ("idle".to_string(), vec![0], true),
+            ("bounce".to_string(), vec![0, 1, 2, 1], true),
+            ("once".to_string(), vec![2, 1], false),
+            ("twice".to_string(), vec![0, 1, 0, 1], false),
+        ]);
+    }
+
+    #[test]
+    fn the_frames_of_a_map_keep_their_order() {
+        let export = serde_json::from_str::<AsepriteExport>(include_str!("../../test/pacman_dying.aseprite.json")).unwrap();
+        let xs = export.frames.iter().map(|frame| frame.frame.x).collect::<Vec<_>>();
+
+        assert_eq!(xs, (0..xs.len() as u32).map(|i| i * 16).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn a_frame_outside_of_the_sheet_cannot_be_cropped() {
+        let sheet = Image::new_fill(
+            Extent3d { width: 32, height: 16, depth_or_array_layers: 1 },
+            TextureDimension::D2,
+            &[0, 0, 0, 255],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        let rect = |x, y| FrameRect { x, y, w: 16, h: 16 };
+
+        assert!(crop(&sheet, &rect(16, 0)).is_some());
+        assert!(crop(&sheet, &rect(17, 0)).is_none());
+        assert!(crop(&sheet, &rect(0, 1)).is_none());
+    }
+
+    #[test]
+    fn the_sheet_is_the_png_next_to_the_export() {
+        let export = serde_json::from_str::<AsepriteExport>(include_str!("../../test/blinky_left.json")).unwrap();
+
+        assert_eq!(sheet_path(Path::new("textures/ghost/blinky_left.aseprite.json"), &export.meta), "textures/ghost/blinky_left.png");
+    }
+}
@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub(super) struct AudioBusPlugin;
+
+impl Plugin for AudioBusPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .register_type::<AudioBus>()
+        ;
+    }
+}
+
+/// The channel a sound is played on. Every bus has its own volume and can be muted on its own,
+/// which is configured in the settings.
+///
+/// Every entity with an AudioBundle should also get one of these, otherwise it ignores the volume settings.
+#[derive(Component, Reflect, Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum AudioBus {
+    /// The background tracks and jingles
+    Music,
+    /// Sounds of things happening in the maze, like pacman eating dots
+    Sfx,
+    /// Sounds which notify the player, like a broken high score
+    Ui,
+}
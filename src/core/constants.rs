@@ -8,6 +8,7 @@ pub const NUM_GHOST_TYPES: usize = 4;
 pub const POINTS_PER_DOT: usize = 10;
 pub const POINTS_PER_ENERGIZER: usize = 50;
 pub const POINTS_PER_GHOST: usize = 200;
+pub const POINTS_PER_CAUGHT_PACMAN: usize = 1000;
 
 pub const TUNNEL_Z: f32 = 300.0;
 pub const TEXT_Z: f32 = 200.0;
@@ -20,12 +21,20 @@ pub const ENERGIZER_Z: f32 = 50.0;
 pub const DOT_Z: f32 = 40.0;
 pub const FRUIT_Z: f32 = 30.0;
 
-pub const FONT: &'static str = "fonts/PressStart2P-Regular.ttf";
+pub const FONT: &str = "fonts/PressStart2P-Regular.ttf";
+/// The font size of all texts. PressStart2P is drawn on an 8x8 grid, so one tile fits one character.
+pub const FONT_SIZE: f32 = FIELD_SIZE;
 
-pub const FIELD_SIZE: f32 = 18.5;
+/// Rows above the maze which are reserved for the scores.
+pub const HUD_TOP_ROWS: usize = 3;
+/// Rows beneath the maze which are reserved for the lives and fruits.
+pub const HUD_BOTTOM_ROWS: usize = 2;
+
+/// The native size of a single tile in pixels. The maze is always rendered at an integer multiple of it.
+pub const FIELD_SIZE: f32 = 8.0;
 pub const FIELD_DIMENSION: Vec2 = Vec2::splat(FIELD_SIZE);
 pub const WALL_DIMENSION: f32 = FIELD_SIZE;
-pub const PACMAN_DIMENSION: f32 = FIELD_SIZE + FIELD_SIZE * 0.6;
+pub const PACMAN_DIMENSION: f32 = FIELD_SIZE * 2.0;
 pub const TUNNEL_DIMENSION: f32 = PACMAN_DIMENSION;
 pub const DOT_DIMENSION: f32 = PACMAN_DIMENSION;
 pub const ENERGIZER_DIMENSION: f32 = PACMAN_DIMENSION;
@@ -35,4 +44,4 @@ pub const GHOST_DIMENSION: f32 = PACMAN_DIMENSION;
 pub const PACMAN_BASE_SPEED: f32 = FIELD_SIZE * 9.0;
 pub const GHOST_BASE_SPEED: f32 = PACMAN_BASE_SPEED;
 
-pub const MAP_SCENE_PATH: &'static str = "maps/map.scn.ron";
\ No newline at end of file
+pub const MAP_SCENE_PATH: &str = "maps/map.scn.ron";
\ No newline at end of file
@@ -0,0 +1,192 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+
+use crate::core::prelude::*;
+
+/// The tiles of the maze a ghost can walk on and how they are connected.
+///
+/// Neighboured tiles are connected if neither of them is blocked. The two tiles of a tunnel are connected
+/// too: stepping out of the maze through a tunnel is the same as stepping onto the other tunnel tile.
+#[derive(Resource, Clone, Debug)]
+pub struct WalkableTiles {
+    tiles: HashSet<Pos>,
+    /// Maps the position right outside of a tunnel to the tunnel tile a ghost gets teleported to.
+    portals: HashMap<Pos, Pos>,
+}
+
+impl WalkableTiles {
+    /// Create the walkable tiles of a maze with the given size. The tunnels are given as pairs of
+    /// (position, direction) of the two tunnel tiles which lead into each other.
+    pub fn new(
+        width: usize,
+        height: usize,
+        blocked: impl IntoIterator<Item=Pos>,
+        tunnels: impl IntoIterator<Item=((Pos, Dir), (Pos, Dir))>,
+    ) -> Self {
+        let blocked = blocked.into_iter().collect::<HashSet<_>>();
+        let tiles = (0..width as isize)
+            .flat_map(|x| (0..height as isize).map(move |y| Pos::new(x, y)))
+            .filter(|pos| !blocked.contains(pos))
+            .collect();
+        let portals = tunnels
+            .into_iter()
+            .flat_map(|((pos_a, dir_a), (pos_b, dir_b))| [
+                (pos_a.neighbour_in_direction(dir_a), pos_b),
+                (pos_b.neighbour_in_direction(dir_b), pos_a),
+            ])
+            .collect();
+
+        WalkableTiles { tiles, portals }
+    }
+
+    /// The tile a ghost ends up on when it walks to the given position. This is the position itself,
+    /// unless it lies right outside a tunnel.
+    pub fn resolve(&self, pos: Pos) -> Pos {
+        self.portals.get(&pos).copied().unwrap_or(pos)
+    }
+
+    /// Calculate the real path distance of every walkable tile to the nearest of the given goals with a
+    /// breadth first search. Tiles which cannot reach any goal get no distance.
+    pub fn distance_field(&self, goals: impl IntoIterator<Item=Pos>) -> DistanceField {
+        let mut distances = HashMap::new();
+        let mut queue = VecDeque::new();
+
+        for goal in goals.into_iter().map(|pos| self.resolve(pos)).filter(|pos| self.tiles.contains(pos)) {
+            if distances.insert(goal, 0).is_none() {
+                queue.push_back(goal);
+            }
+        }
+
+        while let Some(pos) = queue.pop_front() {
+            let distance = distances[&pos] + 1;
+
+            for (neighbour, _) in pos.neighbours_with_directions() {
+                let neighbour = self.resolve(neighbour);
+
+                if self.tiles.contains(&neighbour) && !distances.contains_key(&neighbour) {
+                    distances.insert(neighbour, distance);
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        DistanceField {
+            distances,
+            portals: self.portals.clone(),
+        }
+    }
+
+    /// The number of steps on a shortest path from one tile to another. None if the goal cannot be reached.
+    pub fn path_distance(&self, from: Pos, to: Pos) -> Option<usize> {
+        self.distance_field([to]).distance(from)
+    }
+
+    /// The tiles of a shortest path from one tile to another, without the start but with the goal. Walking
+    /// through a tunnel leads from one tunnel tile right to the other. None if the goal cannot be reached.
+    pub fn path(&self, from: Pos, to: Pos) -> Option<Vec<Pos>> {
+        let field = self.distance_field([to]);
+        let mut current = self.resolve(from);
+        let mut distance = field.distance(current)?;
+        let mut path = Vec::with_capacity(distance);
+
+        while distance > 0 {
+            current = current
+                .neighbours_with_directions()
+                .into_iter()
+                .map(|(neighbour, _)| self.resolve(neighbour))
+                .find(|neighbour| field.distance(*neighbour) == Some(distance - 1))?;
+            distance -= 1;
+            path.push(current);
+        }
+
+        Some(path)
+    }
+}
+
+/// The number of steps from every tile of the maze to a set of goals, following the corridors
+/// and tunnels of the maze. Unlike [Pos::distance], walls cannot be walked through.
+#[derive(Clone, Debug)]
+pub struct DistanceField {
+    distances: HashMap<Pos, usize>,
+    portals: HashMap<Pos, Pos>,
+}
+
+impl DistanceField {
+    /// The steps from the given position to the nearest goal. None if the position is blocked or
+    /// no goal can be reached from it.
+    pub fn distance(&self, pos: Pos) -> Option<usize> {
+        let pos = self.portals.get(&pos).unwrap_or(&pos);
+        self.distances.get(pos).copied()
+    }
+}
+
+/// The distance of every tile to the positions in front of the entrance of every ghost house, by the index
+/// of the house. Gets calculated when a game starts.
+#[derive(Resource, Clone, Debug)]
+pub struct GhostHouseDistances(pub HashMap<usize, DistanceField>);
+
+impl GhostHouseDistances {
+    pub fn of_house(&self, house: usize) -> Option<&DistanceField> {
+        self.0.get(&house)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::prelude::*;
+
+    /// A 5x3 maze with a wall in the middle of the center row and a tunnel connecting the
+    /// ends of the center row:
+    ///
+    /// ```text
+    /// . . . . .
+    /// . . # . .
+    /// . . . . .
+    /// ```
+    fn walkable_tiles() -> WalkableTiles {
+        WalkableTiles::new(
+            5,
+            3,
+            [Pos::new(2, 1)],
+            [((Pos::new(0, 1), Left), (Pos::new(4, 1), Right))],
+        )
+    }
+
+    #[test]
+    fn walls_cannot_be_walked_through() {
+        let field = walkable_tiles().distance_field([Pos::new(1, 1)]);
+
+        assert_eq!(field.distance(Pos::new(1, 1)), Some(0));
+        assert_eq!(field.distance(Pos::new(2, 1)), None);
+        assert_eq!(field.distance(Pos::new(3, 2)), Some(3));
+    }
+
+    #[test]
+    fn a_path_leads_around_walls_and_through_tunnels() {
+        let tiles = walkable_tiles();
+
+        assert_eq!(tiles.path(Pos::new(1, 1), Pos::new(1, 1)), Some(vec![]));
+        assert_eq!(tiles.path(Pos::new(1, 1), Pos::new(4, 1)), Some(vec![Pos::new(0, 1), Pos::new(4, 1)]));
+        assert_eq!(tiles.path(Pos::new(1, 1), Pos::new(2, 1)), None);
+        assert_eq!(tiles.path(Pos::new(1, 0), Pos::new(3, 0)).map(|path| path.len()), Some(2));
+    }
+
+    #[test]
+    fn the_path_distance_counts_the_steps_around_walls() {
+        let tiles = walkable_tiles();
+
+        assert_eq!(tiles.path_distance(Pos::new(1, 1), Pos::new(3, 1)), Some(3));
+        assert_eq!(tiles.path_distance(Pos::new(1, 1), Pos::new(2, 1)), None);
+    }
+
+    #[test]
+    fn tunnels_connect_the_ends_of_the_maze() {
+        let field = walkable_tiles().distance_field([Pos::new(1, 1)]);
+
+        assert_eq!(field.distance(Pos::new(4, 1)), Some(2));
+        assert_eq!(field.distance(Pos::new(5, 1)), Some(1));
+        assert_eq!(field.distance(Pos::new(-1, 1)), Some(2));
+    }
+}
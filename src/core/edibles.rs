@@ -1,6 +1,7 @@
 use std::time::Duration;
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 #[cfg(debug_assertions)]
 use bevy_inspector_egui::quick::ResourceInspectorPlugin;
 
@@ -49,7 +50,7 @@ pub struct Dots;
 pub struct Dot;
 
 /// Keeps track of how many dots are already eaten by pacman
-#[derive(Resource, Default, Reflect)]
+#[derive(Resource, Default, Reflect, Serialize, Deserialize, Clone)]
 pub struct EatenDots {
     max: usize,
     eaten: usize,
@@ -79,8 +80,9 @@ impl EatenDots {
         self.max
     }
 
-    pub(crate) fn reset(&mut self) {
-        self.eaten = 0
+    /// Start over with the given number of dots already eaten.
+    pub(crate) fn reset_to(&mut self, eaten: usize) {
+        self.eaten = eaten
     }
 }
 
@@ -98,29 +100,33 @@ pub enum Fruit {
     Key,
 }
 
+impl Fruit {
+    /// The points pacman gets for eating this fruit in the arcade.
+    pub fn points(&self) -> usize {
+        match self {
+            Cherry => 100,
+            Strawberry => 300,
+            Peach => 500,
+            Apple => 700,
+            Grapes => 1000,
+            Galaxian => 2000,
+            Bell => 3000,
+            Key => 5000
+        }
+    }
+}
+
 /// Timer which keeps track on when to despawn a fruit
 #[derive(Resource, Reflect, Deref, DerefMut)]
 pub struct FruitDespawnTimer(Timer);
 
 impl FruitDespawnTimer {
-    pub fn new() -> Self {
-        FruitDespawnTimer(Timer::new(Duration::from_secs_f32(9.5), TimerMode::Once))
+    /// A timer which finishes after the lifetime of the fruit in seconds.
+    pub fn new(lifetime: f32) -> Self {
+        FruitDespawnTimer(Timer::new(Duration::from_secs_f32(lifetime), TimerMode::Once))
     }
 }
 
-pub fn get_texture_for_fruit(fruit: &Fruit, asset_server: &AssetServer) -> Handle<Image> {
-    asset_server.load(&format!("textures/fruits/{}.png", match fruit {
-        Cherry => "cherry",
-        Strawberry => "strawberry",
-        Peach => "peach",
-        Apple => "apple",
-        Grapes => "grapes",
-        Galaxian => "galaxian",
-        Bell => "bell",
-        Key => "key"
-    }))
-}
-
 /// Parent component for all energizer (for organization only)
 #[derive(Component, Reflect)]
 pub struct Energizers;
@@ -133,6 +139,9 @@ pub struct Energizer;
 #[derive(Event, Reflect, Copy, Clone)]
 pub struct EnergizerOver;
 
+/// Frightened ghosts warn that they turn back to normal when the energizer has less seconds left than this.
+pub const ENERGIZER_RUNNING_OUT_SECONDS: f32 = 2.0;
+
 /// Keeps track of how long an active energizer remains active
 #[derive(Resource, Reflect)]
 pub struct EnergizerTimer {
@@ -158,4 +167,9 @@ impl EnergizerTimer {
     pub fn remaining(&self) -> f32 {
         self.timer.duration().as_secs_f32() - self.timer.elapsed_secs()
     }
+
+    /// Tells if the frightened ghosts should warn that the energizer ends soon.
+    pub fn is_running_out(&self) -> bool {
+        self.remaining() < ENERGIZER_RUNNING_OUT_SECONDS
+    }
 }
\ No newline at end of file
@@ -0,0 +1,270 @@
+use bevy::prelude::*;
+
+use crate::core::prelude::*;
+
+pub(super) struct FruitTablePlugin;
+
+impl Plugin for FruitTablePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .register_type::<FruitTable>()
+            .register_type::<FruitLevel>()
+            .register_type::<FruitTrigger>()
+            .register_type::<FruitPoints>()
+        ;
+    }
+}
+
+/// Which fruit appears in which level of a map, when it appears, how long it stays and what it is worth.
+///
+/// A map defines its fruits with this component on the entity with the [Map]. Maps without one get
+/// the fruits of the arcade when they get enhanced.
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct FruitTable {
+    /// The fruit of every level, starting with the first one. Levels after the last entry use the last entry.
+    pub levels: Vec<FruitLevel>,
+}
+
+impl FruitTable {
+    pub fn for_level(&self, level: &Level) -> &FruitLevel {
+        self.levels
+            .get(level.saturating_sub(1))
+            .or(self.levels.last())
+            .expect("a fruit table needs at least one level")
+    }
+
+    /// Fix what a map got wrong in its table, so the game can use it: a table without levels gets the
+    /// fruits of the arcade, triggers which can never fire are dropped, and a fruit whose sprite does not
+    /// exist is drawn like the fruit itself.
+    pub fn validate(&mut self, sprite_exists: impl Fn(&SpriteId) -> bool) {
+        if self.levels.is_empty() {
+            warn!("the fruit table of the map has no levels, using the fruits of the arcade instead");
+            *self = FruitTable::default();
+        }
+
+        for level in &mut self.levels {
+            level.triggers.retain(|trigger| {
+                if !trigger.can_fire() {
+                    warn!("the fruit trigger {trigger:?} of the {:?} can never fire, ignoring it", level.fruit);
+                }
+
+                trigger.can_fire()
+            });
+
+            let Some(sprite) = &level.sprite else {
+                continue;
+            };
+
+            if !sprite_exists(&SpriteId::named(sprite)) {
+                warn!("the theme has no sprite {sprite}, using the sprite of the {:?} instead", level.fruit);
+                level.sprite = None;
+            }
+        }
+    }
+}
+
+impl Default for FruitTable {
+    fn default() -> Self {
+        FruitTable {
+            levels: [Cherry, Strawberry, Peach, Peach, Apple, Apple, Grapes, Grapes, Galaxian, Galaxian, Bell, Bell, Key]
+                .into_iter()
+                .map(FruitLevel::arcade)
+                .collect(),
+        }
+    }
+}
+
+/// The fruit of one level.
+#[derive(Reflect, Clone, Debug, PartialEq)]
+pub struct FruitLevel {
+    /// One of the fruits of the arcade. A map cannot add new kinds of fruit, only draw one with another sprite.
+    pub fruit: Fruit,
+    /// The sprite of the theme the fruit is drawn with, if it should not look like the fruit itself.
+    pub sprite: Option<String>,
+    /// The points pacman gets for eating the fruit
+    pub points: usize,
+    /// The fruit appears once for every trigger.
+    pub triggers: Vec<FruitTrigger>,
+    /// How many seconds the fruit stays if nobody eats it. A walking fruit of the Ms. Pac-Man rules
+    /// stays until it leaves the maze.
+    pub lifetime: f32,
+}
+
+impl FruitLevel {
+    /// The fruit of the arcade, which appears after 70 and 170 eaten dots and stays 9.5 seconds.
+    pub fn arcade(fruit: Fruit) -> Self {
+        FruitLevel {
+            fruit,
+            sprite: None,
+            points: fruit.points(),
+            triggers: vec![FruitTrigger::EatenDots(70), FruitTrigger::EatenDots(170)],
+            lifetime: 9.5,
+        }
+    }
+
+    pub fn sprite(&self) -> SpriteId {
+        match &self.sprite {
+            Some(sprite) => SpriteId::named(sprite),
+            None => SpriteId::fruit(self.fruit),
+        }
+    }
+
+    /// Tells if the fruit appears now that the given number of dots was eaten.
+    pub fn appears_at(&self, eaten_dots: &EatenDots) -> bool {
+        self.triggers
+            .iter()
+            .any(|trigger| trigger.eaten_dots(eaten_dots.get_max()) == eaten_dots.get_eaten())
+    }
+}
+
+/// When a fruit appears.
+#[derive(Reflect, Copy, Clone, Debug, PartialEq)]
+pub enum FruitTrigger {
+    /// After the given number of dots was eaten
+    EatenDots(usize),
+    /// After the given percentage of all dots of the maze was eaten, so the fruit appears at the same time
+    /// in mazes with more or less dots
+    EatenPercent(f32),
+}
+
+impl FruitTrigger {
+    /// Tells if the trigger can let a fruit appear. Triggers are checked when a dot gets eaten, so none
+    /// of zero dots (or zero percent of them) ever fires.
+    pub fn can_fire(&self) -> bool {
+        match self {
+            FruitTrigger::EatenDots(dots) => *dots > 0,
+            FruitTrigger::EatenPercent(percent) => *percent > 0.0 && *percent <= 100.0,
+        }
+    }
+
+    /// The number of eaten dots which lets the fruit appear in a maze with the given number of dots.
+    pub fn eaten_dots(&self, max_dots: usize) -> usize {
+        match self {
+            FruitTrigger::EatenDots(dots) => *dots,
+            FruitTrigger::EatenPercent(percent) => (max_dots as f32 * percent / 100.0).round() as usize,
+        }
+    }
+}
+
+/// The points a spawned fruit is worth, taken from the [FruitTable].
+#[derive(Component, Reflect, Deref, Copy, Clone)]
+pub struct FruitPoints(pub usize);
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use bevy::reflect::FromReflect;
+    use bevy::scene::serde::SceneDeserializer;
+    use serde::de::DeserializeSeed;
+
+    use crate::core::fruit_table::FruitTablePlugin;
+    use crate::core::prelude::*;
+
+    #[test]
+    fn levels_after_the_last_entry_use_the_last_entry() {
+        let table = FruitTable::default();
+
+        assert_eq!(table.for_level(&Level(1)).fruit, Cherry);
+        assert_eq!(table.for_level(&Level(13)).fruit, Key);
+        assert_eq!(table.for_level(&Level(42)).fruit, Key);
+    }
+
+    #[test]
+    fn percentage_triggers_scale_with_the_dots_of_the_maze() {
+        let trigger = FruitTrigger::EatenPercent(25.0);
+
+        assert_eq!(trigger.eaten_dots(240), 60);
+        assert_eq!(trigger.eaten_dots(100), 25);
+        assert_eq!(FruitTrigger::EatenDots(70).eaten_dots(100), 70);
+    }
+
+    #[test]
+    fn the_arcade_fruit_appears_after_70_and_170_dots() {
+        let fruit = FruitLevel::arcade(Cherry);
+        let mut eaten_dots = EatenDots::new(240);
+
+        eaten_dots.reset_to(69);
+        assert!(!fruit.appears_at(&eaten_dots));
+        eaten_dots.reset_to(70);
+        assert!(fruit.appears_at(&eaten_dots));
+        eaten_dots.reset_to(170);
+        assert!(fruit.appears_at(&eaten_dots));
+    }
+
+    #[test]
+    fn an_empty_table_and_unknown_sprites_get_replaced() {
+        let mut empty = FruitTable { levels: vec![] };
+        empty.validate(|_| true);
+        assert_eq!(empty, FruitTable::default());
+
+        let mut table = FruitTable {
+            levels: vec![
+                FruitLevel { sprite: Some("fruit_key".to_string()), ..FruitLevel::arcade(Cherry) },
+                FruitLevel { sprite: Some("banana".to_string()), ..FruitLevel::arcade(Peach) },
+            ],
+        };
+        table.validate(|sprite| sprite.as_str() != "banana");
+
+        assert_eq!(table.levels[0].sprite(), SpriteId::named("fruit_key"));
+        assert_eq!(table.levels[1].sprite(), SpriteId::fruit(Peach));
+    }
+
+    #[test]
+    fn triggers_which_can_never_fire_get_dropped() {
+        let mut table = FruitTable {
+            levels: vec![FruitLevel {
+                triggers: vec![
+                    FruitTrigger::EatenPercent(0.0),
+                    FruitTrigger::EatenDots(0),
+                    FruitTrigger::EatenPercent(150.0),
+                    FruitTrigger::EatenPercent(50.0),
+                    FruitTrigger::EatenDots(100),
+                ],
+                ..FruitLevel::arcade(Cherry)
+            }],
+        };
+        table.validate(|_| true);
+
+        assert_eq!(table.levels[0].triggers, vec![FruitTrigger::EatenPercent(50.0), FruitTrigger::EatenDots(100)]);
+    }
+
+    #[test]
+    fn a_map_scene_can_define_a_fruit_table() {
+        let scene = r#"(
+            resources: {},
+            entities: {
+                4294967296: (
+                    components: {
+                        "pacman::core::fruit_table::FruitTable": (
+                            levels: [
+                                (
+                                    fruit: Key,
+                                    sprite: Some("fruit_bell"),
+                                    points: 1234,
+                                    triggers: [EatenDots(50), EatenPercent(75.0)],
+                                    lifetime: 5.0,
+                                ),
+                            ],
+                        ),
+                    },
+                ),
+            },
+        )"#;
+        let mut app = App::new();
+        app.add_plugins(FruitTablePlugin);
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+
+        let mut deserializer = ron::de::Deserializer::from_str(scene).unwrap();
+        let scene = SceneDeserializer { type_registry: &registry }.deserialize(&mut deserializer).unwrap();
+        let table = FruitTable::from_reflect(scene.entities[0].components[0].as_ref()).unwrap();
+
+        assert_eq!(table.levels, vec![FruitLevel {
+            fruit: Key,
+            sprite: Some("fruit_bell".to_string()),
+            points: 1234,
+            triggers: vec![FruitTrigger::EatenDots(50), FruitTrigger::EatenPercent(75.0)],
+            lifetime: 5.0,
+        }]);
+    }
+}
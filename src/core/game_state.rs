@@ -32,6 +32,14 @@ impl Default for GameState {
 pub enum Setup {
     /// Start the preload of all assets of the game
     PreloadAssets,
+    /// Load the animations from the Aseprite exports of the preloaded assets
+    LoadAnimations,
+    /// Load the theme selected in the settings
+    LoadTheme,
+    /// Load the texts of the language selected in the settings
+    LoadLanguage,
+    /// Load the sound registry, which defines how every sound gets played
+    LoadSounds,
     /// Create all sprite sheets from the preloaded assets
     CreateSpriteSheets
 }
@@ -3,6 +3,7 @@ use std::collections::HashSet;
 use std::time::Duration;
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use ActiveCounter::*;
 
@@ -28,7 +29,7 @@ use crate::core::prelude::*;
 ///
 /// There is also a timer active. If the timer reaches zero, the waiting ghost can return immediately.
 /// The timer gets reset when pacman eats a dot.
-#[derive(Resource)]
+#[derive(Resource, Serialize, Deserialize, Clone)]
 pub struct GhostHouseGate {
     released_ghosts: HashSet<Ghost>,
     ghost_preference_iterator: GhostPreferenceIterator,
@@ -63,6 +64,24 @@ impl GhostHouseGate {
         self.released_ghosts.contains(ghost)
     }
 
+    /// The ghost which waits for its release, or None if every ghost was released.
+    pub fn waiting_ghost(&self) -> Option<Ghost> {
+        match self.all_ghosts_released() {
+            true => None,
+            false => Some(self.current_waiting_ghost)
+        }
+    }
+
+    /// The value of the active counter for the given ghost and the limit which releases it.
+    pub fn counter_of(&self, ghost: &Ghost) -> (usize, usize) {
+        self.counter.value_and_limit(ghost)
+    }
+
+    /// The seconds until the waiting ghost gets released, if pacman does not eat a dot in the meantime.
+    pub fn remaining_release_time(&self) -> f32 {
+        self.release_timer.remaining_secs()
+    }
+
     /// Increment the current counter. Typically when a dot was eaten.
     /// Also resets the release timer.
     pub fn increment_counter(&mut self) {
@@ -106,6 +125,7 @@ impl GhostHouseGate {
     }
 }
 
+#[derive(Serialize, Deserialize, Clone)]
 struct GhostPreferenceIterator {
     ghost_preferences: [Ghost; NUM_GHOST_TYPES],
     current: usize,
@@ -135,6 +155,7 @@ impl Iterator for GhostPreferenceIterator {
     }
 }
 
+#[derive(Serialize, Deserialize, Clone)]
 pub(crate) struct Counter {
     active_counter: ActiveCounter,
     per_ghost_counter: PerGhostCounter,
@@ -162,6 +183,13 @@ impl Counter {
         self.global_counter = Some(GlobalCounter::new())
     }
 
+    pub fn value_and_limit(&self, ghost: &Ghost) -> (usize, usize) {
+        match (&self.active_counter, &self.global_counter) {
+            (Global, Some(global_counter)) => (global_counter.value, global_counter.ghost_limit_map[ghost]),
+            _ => (self.per_ghost_counter.ghost_counter_map[ghost], self.per_ghost_counter.ghost_limit_map[ghost])
+        }
+    }
+
     /// Check if the limit for the current ghost is reached.
     ///
     /// Also switches from the global counter to the per ghost counter if the global counter is finished.
@@ -180,11 +208,13 @@ impl Counter {
     }
 }
 
+#[derive(Serialize, Deserialize, Clone)]
 enum ActiveCounter {
     PerGhost,
     Global,
 }
 
+#[derive(Serialize, Deserialize, Clone)]
 struct PerGhostCounter {
     ghost_counter_map: HashMap<Ghost, usize>,
     ghost_limit_map: HashMap<Ghost, usize>,
@@ -219,6 +249,7 @@ impl PerGhostCounter {
     }
 }
 
+#[derive(Serialize, Deserialize, Clone)]
 struct GlobalCounter {
     value: usize,
     ghost_limit_map: HashMap<Ghost, usize>,
@@ -2,6 +2,7 @@ use std::time::Duration;
 
 use bevy::prelude::*;
 use bevy::utils::HashMap;
+use serde::{Deserialize, Serialize};
 
 use crate::core::prelude::*;
 use crate::core::prelude::Level;
@@ -82,7 +83,7 @@ impl ScheduleByLevel {
 }
 
 /// Defines what a ghost will do (chase or retreat) based on the passed time since the level started.
-#[derive(Resource, Reflect, Clone)]
+#[derive(Resource, Reflect, Serialize, Deserialize, Clone)]
 pub struct GhostSchedule {
     current_phase_index: usize,
     current_phase_timer: Option<Timer>,
@@ -95,7 +96,7 @@ impl GhostSchedule {
 
         GhostSchedule {
             current_phase_index: 0,
-            current_phase_timer: phases.get(0).expect("at least one phase must be provided").phase_timer(),
+            current_phase_timer: phases.first().expect("at least one phase must be provided").phase_timer(),
             phases,
         }
     }
@@ -104,6 +105,11 @@ impl GhostSchedule {
         self.phases[self.current_phase_index].state
     }
 
+    /// Tells if the schedule is still in the first phase of the level.
+    pub fn is_in_first_phase(&self) -> bool {
+        self.current_phase_index == 0
+    }
+
     pub fn update(&mut self, delta: Duration) {
         if let Some(ref mut timer) = self.current_phase_timer {
             timer.tick(delta);
@@ -123,7 +129,7 @@ impl GhostSchedule {
 }
 
 /// Tells which state a ghost should enter and for how long
-#[derive(Reflect, Clone)]
+#[derive(Reflect, Serialize, Deserialize, Clone)]
 pub struct Phase {
     state: GhostState,
     time: Option<f32>,
@@ -1,4 +1,7 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::core::prelude::*;
 
 pub(super) struct GhostPlugin;
 
@@ -7,11 +10,12 @@ impl Plugin for GhostPlugin {
         app
             .register_type::<Ghost>()
             .register_type::<CurrentlyEatenGhost>()
+            .register_type::<PlayerControlledGhost>()
         ;
     }
 }
 
-#[derive(Component, Reflect, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[derive(Component, Reflect, Serialize, Deserialize, Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
 pub enum Ghost {
     #[default]
     Blinky,
@@ -23,4 +27,11 @@ pub enum Ghost {
 /// Resource that holds the entity id of the ghost that is currently eaten by pacman
 /// The currently eaten ghost be known to ste him invisible while the ghost eaten pause is active
 #[derive(Resource, Reflect, Deref)]
-pub struct CurrentlyEatenGhost(pub Entity);
\ No newline at end of file
+pub struct CurrentlyEatenGhost(pub Entity);
+
+/// Marks the ghost which is steered by player two in a versus game. Holds the direction the player
+/// wants to turn to at the next intersection.
+#[derive(Component, Reflect, Default)]
+pub struct PlayerControlledGhost {
+    pub wished_direction: Option<Dir>,
+}
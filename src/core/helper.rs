@@ -1,7 +1,45 @@
+use std::fs::read_dir;
+use std::path::Path;
+
+use bevy::asset::io::file::FileAssetReader;
 use bevy::prelude::*;
 
 use crate::core::prelude::*;
 
+/// The paths of every file in the assets folder with the given extension, relative to the assets folder
+/// and in alphabetical order.
+///
+/// The folder is read while the game runs, so files which were added after the game was built are found too.
+pub fn asset_paths_with_extension(extension: &str) -> Vec<String> {
+    let assets_folder = FileAssetReader::get_base_path().join("assets");
+    let mut paths = vec![];
+    collect_asset_paths(&assets_folder, &assets_folder, extension, &mut paths);
+    paths.sort();
+    paths
+}
+
+fn collect_asset_paths(folder: &Path, assets_folder: &Path, extension: &str, paths: &mut Vec<String>) {
+    let Ok(entries) = read_dir(folder) else {
+        return;
+    };
+
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if path.is_dir() {
+            collect_asset_paths(&path, assets_folder, extension, paths);
+            continue;
+        }
+
+        let Some(relative) = path.strip_prefix(assets_folder).ok().and_then(|relative| relative.to_str()) else {
+            continue;
+        };
+        let relative = relative.replace('\\', "/");
+
+        if relative.ends_with(extension) {
+            paths.push(relative)
+        }
+    }
+}
+
 /// Provides a helper method to set the x and y value from this Vec3 to the x and y from another Vec3.
 /// Using 'this = other' might overwrite the z value wrong, leading to graphic errors.
 pub trait SetXY {
@@ -39,7 +77,7 @@ impl FromPositions for Vec3 {
         assert_eq!(positions.len(), 2);
 
         let (pos0, pos1) = (positions[0], positions[1]);
-        let neighbour_direction = pos0.get_direction_to_neighbour(&pos1);
+        let neighbour_direction = pos0.get_direction_to_neighbour(pos1);
         let (vec0, vec1) = (pos0.to_vec3(0.0), pos1.to_vec3(0.0));
 
         match neighbour_direction {
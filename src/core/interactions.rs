@@ -20,26 +20,28 @@ impl Plugin for InteractionsPlugin {
     }
 }
 
-/// Fired when pacman was hit by a ghost.
+/// Fired when pacman was hit by a ghost. Contains the pacman entity which was hit, followed by
+/// the ghost entity which caught pacman.
 #[derive(Event, Reflect)]
-pub struct PacmanWasHit;
+pub struct PacmanWasHit(pub Entity, pub Entity);
 
 /// Fired when Pacman ate a ghost in frightened state.
 /// Contains the eaten ghost entity and the transform to show a score on the ghosts
-/// former position.
+/// former position, followed by the pacman entity which ate the ghost.
 #[derive(Event, Reflect, Copy, Clone)]
-pub struct GhostWasEaten(pub Entity, pub Transform);
+pub struct GhostWasEaten(pub Entity, pub Transform, pub Entity);
 
-/// Fired when pacman eats a dot.
+/// Fired when pacman eats a dot. Contains the pacman entity which ate the dot.
 #[derive(Event, Reflect)]
-pub struct DotWasEaten;
+pub struct DotWasEaten(pub Entity);
 
-/// Fired when pacman eats an energizer.
+/// Fired when pacman eats an energizer. Contains the pacman entity which ate the energizer.
 #[derive(Event, Reflect, Copy, Clone)]
-pub struct EnergizerWasEaten;
+pub struct EnergizerWasEaten(pub Entity);
 
 /// Event that gets fired when pacman ate a fruit.
 /// Holds the type of fruit and the transform to show a score on the fruits
-/// former position.
+/// former position, followed by the pacman entity which ate the fruit and
+/// the points the fruit was worth.
 #[derive(Event, Reflect)]
-pub struct FruitWasEaten(pub Fruit, pub Transform);
\ No newline at end of file
+pub struct FruitWasEaten(pub Fruit, pub Transform, pub Entity, pub usize);
\ No newline at end of file
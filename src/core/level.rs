@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 #[cfg(debug_assertions)]
 use bevy_inspector_egui::quick::ResourceInspectorPlugin;
 
@@ -18,7 +19,7 @@ impl Plugin for LevelPlugin {
 }
 
 /// The current level which defines the difficulty, the fruit to spawn and more.
-#[derive(Resource, Reflect, Deref, DerefMut, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+#[derive(Resource, Reflect, Serialize, Deserialize, Deref, DerefMut, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
 pub struct Level(pub usize);
 
 impl Level {
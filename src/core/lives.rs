@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 #[cfg(debug_assertions)]
 use bevy_inspector_egui::quick::ResourceInspectorPlugin;
 
@@ -18,19 +19,31 @@ impl Plugin for LivesPlugin {
 }
 
 /// Resource that tells how many lives pacman currently has.
-#[derive(Deref, DerefMut, Reflect, Default, Resource)]
+#[derive(Deref, DerefMut, Reflect, Serialize, Deserialize, Default, Clone, Resource)]
 pub struct Lives(pub usize);
 
 /// Keeps track how many points the player needs to get a new life for pacman.
-#[derive(Deref, DerefMut, Resource)]
-pub struct PointsRequiredForExtraLife(usize);
+#[derive(Deref, DerefMut, Serialize, Deserialize, Clone, Resource)]
+pub struct PointsRequiredForExtraLife {
+    #[deref]
+    points: usize,
+    /// How many points are added to the limit after an extra life was given. Zero means no extra lives at all.
+    threshold: usize,
+}
 
 impl PointsRequiredForExtraLife {
-    pub fn new() -> Self {
-        PointsRequiredForExtraLife(10000)
+    pub fn new(threshold: usize) -> Self {
+        PointsRequiredForExtraLife {
+            points: threshold,
+            threshold,
+        }
+    }
+
+    pub fn extra_lives_enabled(&self) -> bool {
+        self.threshold > 0
     }
 
     pub fn increase_limit(&mut self) {
-        **self += 10000
+        self.points += self.threshold
     }
 }
\ No newline at end of file
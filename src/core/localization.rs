@@ -0,0 +1,234 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::Path;
+
+use bevy::asset::{AssetLoader, AsyncReadExt, LoadContext, LoadState};
+use bevy::asset::io::Reader;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::core::prelude::*;
+
+/// Loads the texts of the language selected in the settings.
+pub(super) struct LocalizationPlugin;
+
+impl Plugin for LocalizationPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_asset::<Localization>()
+            .init_asset_loader::<LocalizationLoader>()
+            .add_systems(OnEnter(Setup(LoadLanguage)), start_loading_language)
+            .add_systems(Update, finish_loading_language.run_if(in_state(Setup(LoadLanguage))))
+        ;
+    }
+}
+
+/// The language which is used if the settings select one which does not exist. Every other language
+/// falls back to it for missing texts.
+pub const DEFAULT_LANGUAGE: &str = "en";
+
+const LANGUAGE_EXTENSION: &str = ".lang.ron";
+
+/// The path of the language with the given code, relative to the assets folder.
+pub fn language_path(code: &str) -> String {
+    format!("languages/{code}{LANGUAGE_EXTENSION}")
+}
+
+/// The codes of every language in the assets folder, in alphabetical order.
+pub fn available_languages() -> Vec<String> {
+    asset_paths_with_extension(LANGUAGE_EXTENSION)
+        .iter()
+        .filter_map(|path| path.strip_prefix("languages/")?.strip_suffix(LANGUAGE_EXTENSION))
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Identifies a text which is shown to the player.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Hash)]
+#[serde(transparent)]
+pub struct TextId(Cow<'static, str>);
+
+impl TextId {
+    pub const READY: TextId = TextId::new("ready");
+    pub const GAME_OVER: TextId = TextId::new("game_over");
+    /// The hint beneath "game over" which tells how to start a new game
+    pub const RESTART: TextId = TextId::new("restart");
+    pub const HIGH_SCORE: TextId = TextId::new("high_score");
+    /// Shown while the game is paused, only in the terminal
+    pub const PAUSED: TextId = TextId::new("paused");
+    /// The label in front of the level number, only in the terminal
+    pub const LEVEL: TextId = TextId::new("level");
+
+    pub const fn new(id: &'static str) -> Self {
+        TextId(Cow::Borrowed(id))
+    }
+
+    /// The label above the score of the player, like "1UP" for "player_one_label".
+    pub fn player_label(player: Player) -> Self {
+        TextId(Cow::Owned(format!("{}_label", player_key(player))))
+    }
+
+    /// The name of the player on the ready screen, like "PLAYER ONE" for "player_one_name".
+    pub fn player_name(player: Player) -> Self {
+        TextId(Cow::Owned(format!("{}_name", player_key(player))))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+fn player_key(player: Player) -> &'static str {
+    match player {
+        Player::One => "player_one",
+        Player::Two => "player_two",
+    }
+}
+
+/// Every text of a language by its [TextId]. Exists as a resource from Setup(CreateSpriteSheets) on.
+///
+/// A language can define its own font, for scripts the font of the theme cannot render. This font
+/// replaces the one of the theme.
+#[derive(Asset, Resource, TypePath, Clone)]
+pub struct Localization {
+    font: Option<Handle<Font>>,
+    texts: HashMap<TextId, String>,
+}
+
+impl Localization {
+    pub fn text(&self, id: &TextId) -> &str {
+        self.texts
+            .get(id)
+            .unwrap_or_else(|| panic!("the language has no text {}", id.as_str()))
+    }
+}
+
+/// The language which is still loading.
+#[derive(Resource)]
+struct LoadingLanguage(Handle<Localization>);
+
+fn start_loading_language(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Settings>,
+) {
+    let code = match available_languages().contains(&settings.language) {
+        true => settings.language.as_str(),
+        false => {
+            warn!("the language {} does not exist, using {DEFAULT_LANGUAGE} instead", settings.language);
+            DEFAULT_LANGUAGE
+        }
+    };
+
+    commands.insert_resource(LoadingLanguage(asset_server.load(language_path(code))));
+}
+
+fn finish_loading_language(
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut theme: ResMut<Theme>,
+    asset_server: Res<AssetServer>,
+    localizations: Res<Assets<Localization>>,
+    loading: Res<LoadingLanguage>,
+) {
+    if let Some(LoadState::Failed(e)) = asset_server.get_load_state(&loading.0) {
+        panic!("the language could not be loaded: {e}")
+    }
+
+    let Some(localization) = localizations.get(&loading.0) else {
+        return;
+    };
+
+    if let Some(font) = &localization.font {
+        theme.font = font.clone();
+    }
+
+    commands.insert_resource(localization.clone());
+    commands.remove_resource::<LoadingLanguage>();
+    next_state.set(Setup(LoadSounds));
+}
+
+/// The entries of a language file, as written by hand.
+#[derive(Deserialize)]
+struct LanguageFile {
+    /// The path of a font which can render the language, if the font of the theme cannot
+    font: Option<String>,
+    texts: HashMap<TextId, String>,
+}
+
+#[derive(Default)]
+struct LocalizationLoader;
+
+impl AssetLoader for LocalizationLoader {
+    type Asset = Localization;
+    type Settings = ();
+    type Error = Box<dyn Error + Send + Sync>;
+
+    async fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader<'_>,
+        _settings: &'a (),
+        load_context: &'a mut LoadContext<'_>,
+    ) -> Result<Localization, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let file: LanguageFile = ron::de::from_bytes(&bytes)?;
+
+        let mut texts = match load_context.path() == Path::new(&language_path(DEFAULT_LANGUAGE)) {
+            true => HashMap::new(),
+            false => load_context.loader().direct().load::<Localization>(language_path(DEFAULT_LANGUAGE)).await?.take().texts,
+        };
+        texts.extend(file.texts);
+
+        Ok(Localization {
+            font: file.font.map(|path| load_context.load(path)),
+            texts,
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["lang.ron"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::fs;
+
+    use serde::Deserialize;
+
+    use crate::core::prelude::*;
+
+    #[derive(Deserialize)]
+    struct LanguageFile {
+        texts: HashMap<String, String>,
+    }
+
+    fn texts_of(code: &str) -> HashMap<String, String> {
+        let content = fs::read_to_string(format!("assets/{}", language_path(code))).unwrap();
+        ron::from_str::<LanguageFile>(&content).unwrap().texts
+    }
+
+    #[test]
+    fn the_default_language_has_the_player_texts() {
+        let texts = texts_of(DEFAULT_LANGUAGE);
+
+        for player in [Player::One, Player::Two] {
+            assert!(texts.contains_key(TextId::player_label(player).as_str()));
+            assert!(texts.contains_key(TextId::player_name(player).as_str()));
+        }
+    }
+
+    #[test]
+    fn no_language_has_texts_the_default_language_does_not_know() {
+        let default_texts = texts_of(DEFAULT_LANGUAGE);
+
+        for code in available_languages() {
+            for key in texts_of(&code).keys() {
+                assert!(default_texts.contains_key(key), "{key} of {code} is unknown");
+            }
+        }
+    }
+}
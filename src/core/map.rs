@@ -16,6 +16,9 @@ impl Plugin for MapPlugin {
             .register_type::<WallType>()
             .register_type::<Rotation>()
             .register_type::<GhostHouseArea>()
+            .register_type::<GhostHouseEntrance>()
+            .register_type::<GhostHouseCenter>()
+            .register_type::<GhostSpawnPoint>()
             .register_type::<Tunnel>()
             .register_type::<TunnelHallway>()
             .register_type::<EnergizerSpawns>()
@@ -55,16 +58,23 @@ impl Default for Tiles {
 }
 
 impl Tiles {
-    pub fn to_vec3(&self, z: f32) -> Vec3 {
+    pub fn to_vec3(self, z: f32) -> Vec3 {
         match self {
             Single { pos } => pos.to_vec3(z),
-            Double { pos_a, pos_b } => Vec3::from_positions([pos_a, pos_b], z)
+            Double { pos_a, pos_b } => Vec3::from_positions([&pos_a, &pos_b], z)
         }
     }
 
-    pub fn to_pos(&self) -> Pos {
+    pub fn positions(&self) -> Vec<Pos> {
         match self {
-            Single { pos } => *pos,
+            Single { pos } => vec![*pos],
+            Double { pos_a, pos_b } => vec![*pos_a, *pos_b]
+        }
+    }
+
+    pub fn to_pos(self) -> Pos {
+        match self {
+            Single { pos } => pos,
             Double { .. } => panic!("can only retrieve the position for single position tiles")
         }
     }
@@ -151,11 +161,39 @@ pub struct FruitSpawn;
 #[reflect(Component)]
 pub struct GhostCorner(pub Ghost);
 
-/// A single tile of the ghost house
+/// A single tile of a ghost house. The tiles of one house form a rectangle of any size, whose border
+/// becomes the walls of the house.
 #[derive(Component, Reflect, Default)]
 #[reflect(Component)]
 pub struct GhostHouseArea {
-    pub rotation: Rotation
+    /// The index of the house this tile belongs to. A map can have more than one ghost house.
+    pub house: usize
+}
+
+/// Marks the tiles in front of the door of a ghost house. The wall next to them becomes the door.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct GhostHouseEntrance {
+    pub house: usize,
+    /// The direction ghosts walk in after they left the house
+    pub direction: Dir,
+}
+
+/// Marks the center of a ghost house. Ghosts pass it when they leave the house or come back after being eaten.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct GhostHouseCenter {
+    pub house: usize
+}
+
+/// Marks the tiles where a ghost spawns and the house it belongs to.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct GhostSpawnPoint {
+    pub ghost: Ghost,
+    pub house: usize,
+    /// The direction the ghost looks in when it spawns
+    pub direction: Dir,
 }
 
 /// Tile where pacman or a ghost can switch to another tunnel with the same index
@@ -171,17 +209,39 @@ pub struct Tunnel {
 #[reflect(Component)]
 pub struct TunnelHallway;
 
-/// Parent component for everything related to the ghost house
-#[derive(Component, Reflect)]
-pub struct GhostHouse;
+/// A ghost house, created from the ghost house markers of the map. Parent of the walls and ghost spawns of the house.
+#[derive(Component, Reflect, Clone, Debug)]
+pub struct GhostHouse {
+    pub index: usize,
+    /// The tiles in front of the door
+    pub entrance_positions: Vec<Pos>,
+    pub entrance_coordinates: Vec3,
+    /// The direction ghosts walk in after they left the house
+    pub leave_direction: Dir,
+    pub center_coordinates: Vec3,
+    /// The direction from the center of the house to its door
+    pub exit_direction: Dir,
+}
+
+impl GhostHouse {
+    /// Where a ghost with the given spawn walks to when it comes back after being eaten. A ghost
+    /// which spawns in front of the house (like Blinky) has no place inside, so it stays in the center.
+    pub fn respawn_coordinates(&self, spawn: &GhostSpawn) -> Vec3 {
+        match spawn.coordinates.xy_equal(&self.entrance_coordinates) {
+            true => self.center_coordinates,
+            false => spawn.coordinates
+        }
+    }
+}
 
 /// Spawn area of a ghost
 #[derive(Component, Reflect, Copy, Clone)]
 pub struct GhostSpawn {
     pub ghost: Ghost,
+    /// The index of the house the ghost lives in
+    pub house: usize,
     pub coordinates: Vec3,
     pub spawn_direction: Dir,
-    pub positions: [Pos; 2],
 }
 
 /// Marks a tile as one way. A one way is used to mark an intersection as a point where
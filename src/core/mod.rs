@@ -2,26 +2,36 @@ use bevy::prelude::*;
 use pad::Position;
 
 use crate::core::edibles::EdiblesPlugin;
+use crate::core::fruit_table::FruitTablePlugin;
 use crate::core::ghost_schedule::GhostSchedulePlugin;
 use crate::core::ghost_state::GhostStatePlugin;
 use crate::core::ghosts::GhostPlugin;
 use crate::core::interactions::InteractionsPlugin;
 use crate::core::level::LevelPlugin;
 use crate::core::lives::LivesPlugin;
+use crate::core::localization::LocalizationPlugin;
 use crate::core::map::MapPlugin;
 use crate::core::pacman::PacmanPlugin;
+use crate::core::players::PlayersPlugin;
+use crate::core::practice::PracticePlugin;
 use crate::core::score::ScorePlugin;
 use crate::core::specs_per_level::SpecsPerLevelPlugin;
 use crate::core::speed::SpeedPlugin;
 use crate::core::target::TargetPlugin;
+use crate::core::accessibility::AccessibilityPlugin;
 use crate::core::animation::AnimationPlugin;
+use crate::core::aseprite::AsepritePlugin;
+use crate::core::audio_bus::AudioBusPlugin;
 use crate::core::direction::Dir;
 use crate::core::game_state::GameStatePlugin;
 use crate::core::music::MusicPlugin;
 use crate::core::position::Pos;
 use crate::core::restart_game::RestartGamePlugin;
+use crate::core::ruleset::RulesetPlugin;
 use crate::core::sound_effect::SoundEffectPlugin;
+use crate::core::settings::SettingsPlugin;
 use crate::core::system_sets::SystemSetsPlugin;
+use crate::core::theme::ThemePlugin;
 
 pub mod position;
 pub mod direction;
@@ -42,11 +52,23 @@ pub mod specs_per_level;
 pub mod speed;
 pub mod ghost_state;
 pub mod animation;
+pub mod aseprite;
 pub mod constants;
 pub mod game_state;
 pub mod system_sets;
 pub mod sound_effect;
 pub mod music;
+pub mod audio_bus;
+pub mod settings;
+pub mod players;
+pub mod save_game;
+pub mod practice;
+pub mod distance_field;
+pub mod theme;
+pub mod accessibility;
+pub mod localization;
+pub mod ruleset;
+pub mod fruit_table;
 pub mod prelude;
 mod restart_game;
 
@@ -78,7 +100,17 @@ impl Plugin for CorePlugin {
             .add_plugins((
                 SoundEffectPlugin,
                 MusicPlugin,
+                AudioBusPlugin,
                 RestartGamePlugin,
+                SettingsPlugin,
+                PlayersPlugin,
+                PracticePlugin,
+                AsepritePlugin,
+                ThemePlugin,
+                AccessibilityPlugin,
+                LocalizationPlugin,
+                RulesetPlugin,
+                FruitTablePlugin,
                 SystemSetsPlugin
             ))
         ;
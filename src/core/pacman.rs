@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 pub(super) struct PacmanPlugin;
 
@@ -6,6 +7,7 @@ impl Plugin for PacmanPlugin {
     fn build(&self, app: &mut App) {
         app
             .register_type::<Pacman>()
+            .register_type::<PacmanController>()
         ;
     }
 }
@@ -13,4 +15,43 @@ impl Plugin for PacmanPlugin {
 /// Marker component for a pacman entity.
 #[derive(Component, Reflect, Default)]
 #[reflect(Component)]
-pub struct Pacman;
\ No newline at end of file
+pub struct Pacman;
+
+/// Decides who steers a pacman: a player with the keyboard or one of the built-in AI agents.
+///
+/// The agents are used to let the game play itself, for example to run regression games or tune the difficulty.
+#[derive(Component, Reflect, Serialize, Deserialize, Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[reflect(Component)]
+pub enum PacmanController {
+    #[default]
+    Keyboard,
+    /// Goes for the nearest dot and ignores the ghosts.
+    Greedy,
+    /// Goes for the nearest dot which can be reached without coming near a ghost.
+    AvoidGhosts,
+    /// Plays a few moves ahead and predicts the ghosts with the same rules they follow in the game.
+    Lookahead,
+}
+
+impl PacmanController {
+    const ALL: [PacmanController; 4] = [
+        PacmanController::Keyboard,
+        PacmanController::Greedy,
+        PacmanController::AvoidGhosts,
+        PacmanController::Lookahead,
+    ];
+
+    pub fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|controller| controller == self).unwrap_or_default();
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    pub fn previous(&self) -> Self {
+        let index = Self::ALL.iter().position(|controller| controller == self).unwrap_or_default();
+        Self::ALL[(index + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    pub fn is_agent(&self) -> bool {
+        *self != PacmanController::Keyboard
+    }
+}
@@ -0,0 +1,104 @@
+use bevy::prelude::*;
+use crate::core::prelude::*;
+
+pub(super) struct PlayersPlugin;
+
+impl Plugin for PlayersPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .register_type::<Player>()
+            .register_type::<ActivePlayer>()
+            .register_type::<CurrentGameMode>()
+        ;
+    }
+}
+
+/// Identifies a player. Every pacman has this component to tell which player controls it.
+#[derive(Component, Reflect, Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Player {
+    #[default]
+    One,
+    Two,
+}
+
+impl Player {
+    pub fn other(&self) -> Self {
+        match self {
+            Player::One => Player::Two,
+            Player::Two => Player::One,
+        }
+    }
+
+    /// The label above the score of the player, like on the arcade screen.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Player::One => "1UP",
+            Player::Two => "2UP",
+        }
+    }
+}
+
+/// The player which currently controls pacman. The resources Score, Lives, Level, EatenDots and
+/// PointsRequiredForExtraLife always belong to this player.
+#[derive(Resource, Reflect, Deref, DerefMut, Copy, Clone, Default, Eq, PartialEq)]
+pub struct ActivePlayer(pub Player);
+
+/// The mode of the current game. Gets taken from the settings when a game starts, so changing the
+/// settings does not affect a running game.
+#[derive(Resource, Reflect, Deref, DerefMut, Copy, Clone, Default, Eq, PartialEq)]
+pub struct CurrentGameMode(pub GameMode);
+
+/// Holds the progress of the player which is not the active one. Only exists if two players play.
+///
+/// In an alternating game, the player waits for their turn. In a co-op game, the player plays at the
+/// same time as the active one, but only their score, lives and extra life limit are used. They share
+/// everything else with the active player.
+#[derive(Resource)]
+pub struct OtherPlayer(pub PlayerProgress);
+
+/// Everything that belongs to one player in an alternating game. When the turn switches, the
+/// progress gets swapped with the resources of the active player.
+pub struct PlayerProgress {
+    pub player: Player,
+    pub score: Score,
+    pub lives: Lives,
+    pub level: Level,
+    pub eaten_dots: EatenDots,
+    pub points_required_for_extra_life: PointsRequiredForExtraLife,
+    /// The translations of the dots which are not eaten yet. None if the player did not play yet, so
+    /// the maze is still full.
+    pub remaining_dots: Option<Vec<Vec3>>,
+    /// The translations of the energizers which are not eaten yet. None if the player did not play yet.
+    pub remaining_energizers: Option<Vec<Vec3>>,
+}
+
+impl PlayerProgress {
+    /// The progress of a player who did not play yet.
+    pub fn new(player: Player, lives: usize, level: usize, num_dots: usize, extra_life_threshold: usize) -> Self {
+        PlayerProgress {
+            player,
+            score: Score(0),
+            lives: Lives(lives),
+            level: Level(level),
+            eaten_dots: EatenDots::new(num_dots),
+            points_required_for_extra_life: PointsRequiredForExtraLife::new(extra_life_threshold),
+            remaining_dots: None,
+            remaining_energizers: None,
+        }
+    }
+
+    pub fn can_play(&self) -> bool {
+        *self.lives > 0
+    }
+
+    /// Tells if the player reached the score for an extra life. If so, the limit for the next extra life gets raised.
+    pub fn reached_extra_life(&mut self) -> bool {
+        let reached = self.points_required_for_extra_life.extra_lives_enabled() && *self.score >= *self.points_required_for_extra_life;
+
+        if reached {
+            self.points_required_for_extra_life.increase_limit();
+        }
+
+        reached
+    }
+}
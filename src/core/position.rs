@@ -62,7 +62,7 @@ impl Pos {
         self.0.euclidean_distance(&other.0)
     }
 
-    pub fn to_vec3(&self, z: f32) -> Vec3 {
+    pub fn to_vec3(self, z: f32) -> Vec3 {
         self.0.to_vec3(FIELD_DIMENSION, z)
     }
 }
\ No newline at end of file
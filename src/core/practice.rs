@@ -0,0 +1,170 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::core::prelude::*;
+
+pub(super) struct PracticePlugin;
+
+impl Plugin for PracticePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .register_type::<PracticeSettings>()
+            .register_type::<MazeSection>()
+            .register_type::<CurrentPractice>()
+        ;
+    }
+}
+
+/// The highest level a practice game can start in. Every level after this one plays the same.
+pub const MAX_PRACTICE_LEVEL: usize = 21;
+
+/// The starting conditions of a practice game, which are part of the settings.
+///
+/// A practice game lets the player start in any level, with as many lives as they like and with parts of
+/// the maze already cleared. Its score does not count for the high score.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(default)]
+pub struct PracticeSettings {
+    /// If true, the next game is a practice game.
+    pub enabled: bool,
+    pub level: usize,
+    pub lives: usize,
+    /// Pacman does not lose lives when a ghost catches him.
+    pub infinite_lives: bool,
+    /// The sections of the maze whose dots are already eaten when the maze gets filled.
+    pub cleared_dots: Vec<MazeSection>,
+    /// The sections of the maze whose energizers are already eaten when the maze gets filled.
+    pub cleared_energizers: Vec<MazeSection>,
+}
+
+impl Default for PracticeSettings {
+    fn default() -> Self {
+        PracticeSettings {
+            enabled: false,
+            level: 1,
+            lives: 3,
+            infinite_lives: false,
+            cleared_dots: vec![],
+            cleared_energizers: vec![],
+        }
+    }
+}
+
+impl PracticeSettings {
+    /// Fix the values a settings file got wrong, so a practice game can start: the level is kept between
+    /// 1 and [MAX_PRACTICE_LEVEL] and pacman starts with at least one life.
+    pub fn validate(&mut self) {
+        let level = self.level.clamp(1, MAX_PRACTICE_LEVEL);
+
+        if level != self.level {
+            warn!("the practice level {} does not exist, starting in level {level} instead", self.level);
+            self.level = level;
+        }
+
+        if self.lives == 0 {
+            warn!("a practice game cannot start without lives, starting with one life instead");
+            self.lives = 1;
+        }
+    }
+
+    /// Clear the dots of the section if they were not cleared, or fill them again if they were.
+    pub fn toggle_cleared_dots(&mut self, section: MazeSection) {
+        toggle(&mut self.cleared_dots, section)
+    }
+
+    /// Clear the energizers of the section if they were not cleared, or fill them again if they were.
+    pub fn toggle_cleared_energizers(&mut self, section: MazeSection) {
+        toggle(&mut self.cleared_energizers, section)
+    }
+
+    /// Tells if the dot at the given translation is already eaten when the maze gets filled.
+    pub fn dot_is_cleared(&self, translation: Vec3, map: &Map) -> bool {
+        self.cleared_dots.contains(&MazeSection::of(translation, map))
+    }
+
+    /// Tells if the energizer at the given translation is already eaten when the maze gets filled.
+    pub fn energizer_is_cleared(&self, translation: Vec3, map: &Map) -> bool {
+        self.cleared_energizers.contains(&MazeSection::of(translation, map))
+    }
+}
+
+fn toggle(sections: &mut Vec<MazeSection>, section: MazeSection) {
+    match sections.iter().position(|s| *s == section) {
+        Some(index) => { sections.remove(index); }
+        None => sections.push(section)
+    }
+}
+
+/// One quarter of the maze. Used to clear parts of the maze in a practice game.
+#[derive(Reflect, Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum MazeSection {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl MazeSection {
+    /// The section the given translation is in.
+    pub fn of(translation: Vec3, map: &Map) -> Self {
+        let pos = Pos::from_vec3(translation);
+        let left = pos.x() < map.width as isize / 2;
+        let top = pos.y() >= map.height as isize / 2;
+
+        match (top, left) {
+            (true, true) => MazeSection::TopLeft,
+            (true, false) => MazeSection::TopRight,
+            (false, true) => MazeSection::BottomLeft,
+            (false, false) => MazeSection::BottomRight,
+        }
+    }
+}
+
+/// Exists while a practice game is played, with the starting conditions the game was started with.
+/// Like the game mode, they get taken from the settings when a game starts, so changing the settings
+/// does not affect a running game.
+#[derive(Resource, Reflect, Deref)]
+pub struct CurrentPractice(pub PracticeSettings);
+
+#[cfg(test)]
+mod tests {
+    use crate::core::prelude::*;
+
+    #[test]
+    fn the_maze_is_split_into_quarters() {
+        let map = Map { width: 28, height: 31 };
+
+        assert_eq!(MazeSection::of(Pos::new(1, 28).to_vec3(0.0), &map), MazeSection::TopLeft);
+        assert_eq!(MazeSection::of(Pos::new(26, 28).to_vec3(0.0), &map), MazeSection::TopRight);
+        assert_eq!(MazeSection::of(Pos::new(13, 8).to_vec3(0.0), &map), MazeSection::BottomLeft);
+        assert_eq!(MazeSection::of(Pos::new(14, 8).to_vec3(0.0), &map), MazeSection::BottomRight);
+    }
+
+    #[test]
+    fn the_level_and_lives_get_fixed_when_they_are_out_of_range() {
+        let mut practice = PracticeSettings {
+            level: 0,
+            lives: 0,
+            ..PracticeSettings::default()
+        };
+        practice.validate();
+
+        assert_eq!((practice.level, practice.lives), (1, 1));
+
+        practice.level = MAX_PRACTICE_LEVEL + 1;
+        practice.validate();
+
+        assert_eq!(practice.level, MAX_PRACTICE_LEVEL);
+    }
+
+    #[test]
+    fn toggling_a_section_twice_fills_it_again() {
+        let mut practice = PracticeSettings::default();
+
+        practice.toggle_cleared_dots(MazeSection::TopLeft);
+        assert_eq!(practice.cleared_dots, vec![MazeSection::TopLeft]);
+
+        practice.toggle_cleared_dots(MazeSection::TopLeft);
+        assert!(practice.cleared_dots.is_empty());
+    }
+}
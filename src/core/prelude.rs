@@ -1,4 +1,6 @@
+pub use crate::core::accessibility::*;
 pub use crate::core::animation::*;
+pub use crate::core::aseprite::*;
 pub use crate::core::constants::*;
 pub use crate::core::direction::*;
 pub use crate::core::direction::Dir::*;
@@ -33,4 +35,14 @@ pub use crate::core::ghost_schedule::*;
 pub use crate::core::score::*;
 pub use crate::core::music::*;
 pub use crate::core::music::CurrentTrack::*;
-pub use crate::core::restart_game::*;
\ No newline at end of file
+pub use crate::core::audio_bus::*;
+pub use crate::core::restart_game::*;
+pub use crate::core::settings::*;
+pub use crate::core::players::*;
+pub use crate::core::save_game::*;
+pub use crate::core::practice::*;
+pub use crate::core::distance_field::*;
+pub use crate::core::theme::*;
+pub use crate::core::localization::*;
+pub use crate::core::ruleset::*;
+pub use crate::core::fruit_table::*;
\ No newline at end of file
@@ -1,23 +1,44 @@
 use bevy::prelude::*;
 use rand::prelude::*;
+use rand::rngs::StdRng;
 
 pub(crate) struct RandomPlugin;
 
 impl Plugin for RandomPlugin {
     fn build(&self, app: &mut App) {
         app
-            .insert_resource(Random)
+            .insert_resource(Random::from_entropy())
         ;
     }
 }
 
 /// Provides randomly chosen numbers to whoever needs them.
+///
+/// Systems take it as ResMut, so two of them never draw at the same time. Every system which draws from it
+/// must be ordered against the others (like with the system sets), otherwise the numbers they get would
+/// depend on the order the systems happen to run in and a seeded game could not be played again.
 #[derive(Resource)]
-pub struct Random;
+pub struct Random {
+    rng: StdRng,
+}
 
 impl Random {
-    pub fn zero_to(&self, n: usize) -> usize {
-        let mut rng = thread_rng();
-        rng.gen_range(0..n)
+    /// Create a random generator which returns different numbers in every game.
+    pub fn from_entropy() -> Self {
+        Random {
+            rng: StdRng::from_entropy()
+        }
+    }
+
+    /// Create a random generator which always returns the same numbers for the same seed.
+    /// With it, a game can be played again exactly the same way.
+    pub fn from_seed(seed: u64) -> Self {
+        Random {
+            rng: StdRng::seed_from_u64(seed)
+        }
     }
-}
\ No newline at end of file
+
+    pub fn zero_to(&mut self, n: usize) -> usize {
+        self.rng.gen_range(0..n)
+    }
+}
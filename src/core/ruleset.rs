@@ -0,0 +1,154 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::core::prelude::*;
+
+pub(super) struct RulesetPlugin;
+
+impl Plugin for RulesetPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .register_type::<Ruleset>()
+            .register_type::<CurrentRuleset>()
+        ;
+    }
+}
+
+/// The fruits of the first seven levels of Ms. Pac-Man. After these, a random one of them appears.
+///
+/// Ms. Pac-Man has other fruits than Pac-Man, so the ones with the most similar points are used instead.
+pub const MS_PACMAN_FRUITS: [Fruit; 7] = [Cherry, Strawberry, Peach, Apple, Grapes, Galaxian, Bell];
+
+/// The rules a game is played with.
+#[derive(Reflect, Serialize, Deserialize, Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Ruleset {
+    /// The rules of the Pac-Man arcade
+    #[default]
+    Arcade,
+    /// The fruit enters through a tunnel, bounces through the maze and leaves through another tunnel.
+    /// The maze changes its color every few levels and Blinky and Pinky wander around randomly in the
+    /// first scatter phase of a level.
+    MsPacman,
+}
+
+impl Ruleset {
+    pub fn other(&self) -> Self {
+        match self {
+            Ruleset::Arcade => Ruleset::MsPacman,
+            Ruleset::MsPacman => Ruleset::Arcade,
+        }
+    }
+
+    /// The fruit which appears in the given level.
+    ///
+    /// Ms. Pac-Man has its own fruits, which are chosen randomly after the seventh level. Everything else comes
+    /// from the fruit table. If the table gives its fruit the points of the arcade, the fruit of Ms. Pac-Man is
+    /// worth its own points instead. Points the table sets by hand are kept.
+    pub fn fruit_for_level(&self, level: &Level, fruit_table: &FruitTable, random: &mut Random) -> FruitLevel {
+        let fruit_level = fruit_table.for_level(level).clone();
+
+        let fruit = match self {
+            Ruleset::Arcade => return fruit_level,
+            Ruleset::MsPacman => match MS_PACMAN_FRUITS.get(level.saturating_sub(1)) {
+                Some(fruit) => *fruit,
+                None => MS_PACMAN_FRUITS[random.zero_to(MS_PACMAN_FRUITS.len())],
+            }
+        };
+
+        let points = match fruit_level.points == fruit_level.fruit.points() {
+            true => fruit.points(),
+            false => fruit_level.points,
+        };
+
+        FruitLevel {
+            fruit,
+            points,
+            ..fruit_level
+        }
+    }
+
+    /// The color of the maze walls in the given level, as RGB bytes.
+    ///
+    /// Ms. Pac-Man changes the maze after the levels 2, 5, 9 and 13 and then every four levels.
+    /// As there is only one maze, only the color changes.
+    pub fn maze_color(&self, level: &Level) -> [u8; 3] {
+        const MS_PACMAN_COLORS: [[u8; 3]; 4] = [
+            [0xFF, 0xB8, 0xAE],
+            [0x47, 0xB8, 0xFF],
+            [0xDE, 0x97, 0x51],
+            [0x21, 0x21, 0xFF],
+        ];
+
+        match (self, **level) {
+            (Ruleset::Arcade, _) => ARCADE_WALL_COLOR,
+            (Ruleset::MsPacman, 0..=2) => MS_PACMAN_COLORS[0],
+            (Ruleset::MsPacman, 3..=5) => MS_PACMAN_COLORS[1],
+            (Ruleset::MsPacman, 6..=9) => MS_PACMAN_COLORS[2],
+            (Ruleset::MsPacman, 10..=13) => MS_PACMAN_COLORS[3],
+            // the last two mazes take turns
+            (Ruleset::MsPacman, level) => MS_PACMAN_COLORS[2 + (level - 14) / 4 % 2],
+        }
+    }
+
+    /// Tells if the given ghost wanders around randomly instead of heading to its corner.
+    pub fn scatters_randomly(&self, ghost: Ghost, first_phase: bool) -> bool {
+        *self == Ruleset::MsPacman && first_phase && matches!(ghost, Blinky | Pinky)
+    }
+}
+
+/// The ruleset of the current game. Gets taken from the settings when a game starts, so changing the
+/// settings does not affect a running game.
+#[derive(Resource, Reflect, Deref, DerefMut, Copy, Clone, Default, Eq, PartialEq)]
+pub struct CurrentRuleset(pub Ruleset);
+
+#[cfg(test)]
+mod tests {
+    use crate::core::prelude::*;
+
+    #[test]
+    fn ms_pacman_picks_a_random_fruit_after_the_seventh_level() {
+        let fruit_table = FruitTable::default();
+        let mut random = Random::from_seed(42);
+        let mut fruit = |level| Ruleset::MsPacman.fruit_for_level(&Level(level), &fruit_table, &mut random).fruit;
+
+        assert_eq!(fruit(1), Cherry);
+        assert_eq!(fruit(7), Bell);
+
+        for level in 8..50 {
+            assert!(MS_PACMAN_FRUITS.contains(&fruit(level)));
+        }
+    }
+
+    #[test]
+    fn ms_pacman_keeps_the_sprite_points_and_lifetime_of_the_fruit_table() {
+        let fruit_table = FruitTable {
+            levels: vec![FruitLevel {
+                sprite: Some("fruit_key".to_string()),
+                points: 1234,
+                lifetime: 3.0,
+                ..FruitLevel::arcade(Key)
+            }],
+        };
+        let fruit = Ruleset::MsPacman.fruit_for_level(&Level(4), &fruit_table, &mut Random::from_seed(42));
+
+        assert_eq!(fruit.fruit, Apple);
+        assert_eq!(fruit.sprite, Some("fruit_key".to_string()));
+        assert_eq!(fruit.points, 1234);
+        assert_eq!(fruit.lifetime, 3.0);
+
+        let arcade_points = Ruleset::MsPacman.fruit_for_level(&Level(4), &FruitTable::default(), &mut Random::from_seed(42));
+        assert_eq!(arcade_points.points, Apple.points());
+    }
+
+    #[test]
+    fn the_ms_pacman_maze_changes_its_color_with_the_level_sets() {
+        let color = |level| Ruleset::MsPacman.maze_color(&Level(level));
+
+        assert_eq!(color(1), color(2));
+        assert_ne!(color(2), color(3));
+        assert_ne!(color(13), color(14));
+        assert_eq!(color(14), color(17));
+        assert_ne!(color(17), color(18));
+        assert_eq!(Ruleset::Arcade.maze_color(&Level(3)), ARCADE_WALL_COLOR);
+    }
+}
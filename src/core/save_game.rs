@@ -0,0 +1,74 @@
+use std::fs;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::core::prelude::*;
+
+/// The file a game in progress gets saved in when the game is closed, relative to the working directory.
+pub const SAVE_PATH: &str = "savegame.ron";
+
+/// A single player game in progress, as it gets written to disk when the game is closed.
+///
+/// When the game starts again, the saved game gets resumed: the level restarts at Game(Ready) with
+/// pacman and the ghosts in their spawns, but everything else is like it was when the game was closed.
+#[derive(Resource, Serialize, Deserialize)]
+pub struct SavedGame {
+    /// The scene of the maze the game was played in. A saved game can only be resumed in the same maze.
+    pub map: String,
+    pub score: Score,
+    pub high_score: HighScore,
+    pub lives: Lives,
+    pub level: Level,
+    pub points_required_for_extra_life: PointsRequiredForExtraLife,
+    pub eaten_dots: EatenDots,
+    /// The translations of the dots which were not eaten yet.
+    pub remaining_dots: Vec<Vec3>,
+    /// The translations of the energizers which were not eaten yet.
+    pub remaining_energizers: Vec<Vec3>,
+    pub ghost_schedule: GhostSchedule,
+    pub ghost_house_gate: GhostHouseGate,
+    /// The rules the game was started with. Games saved before there were rulesets are arcade games.
+    #[serde(default)]
+    pub ruleset: Ruleset,
+}
+
+impl SavedGame {
+    /// Load the saved game from disk. Returns None if there is none, or if it cannot be resumed.
+    pub fn load() -> Option<Self> {
+        let content = fs::read_to_string(SAVE_PATH).ok()?;
+
+        let saved_game = ron::from_str::<SavedGame>(&content)
+            .inspect_err(|e| warn!("the saved game {SAVE_PATH} is invalid and gets ignored: {e}"))
+            .ok()?;
+
+        if saved_game.map != MAP_SCENE_PATH {
+            warn!("the saved game {SAVE_PATH} was played in the maze {}, but the current maze is {MAP_SCENE_PATH}", saved_game.map);
+            return None;
+        }
+
+        Some(saved_game)
+    }
+
+    pub fn save(&self) {
+        let result = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|e| e.to_string())
+            .and_then(|content| fs::write(SAVE_PATH, content).map_err(|e| e.to_string()));
+
+        if let Err(e) = result {
+            error!("failed to save the game to {SAVE_PATH}: {e}")
+        }
+    }
+
+    /// Remove the saved game from disk, so it does not get resumed again.
+    pub fn delete() {
+        if let Err(e) = fs::remove_file(SAVE_PATH) {
+            error!("failed to delete the saved game {SAVE_PATH}: {e}")
+        }
+    }
+}
+
+/// The ghost schedule of a resumed game. It replaces the schedule of the level when the game runs again,
+/// so the ghosts continue the phase they were in when the game was saved.
+#[derive(Resource)]
+pub struct ResumedGhostSchedule(pub GhostSchedule);
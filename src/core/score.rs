@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 pub(super) struct ScorePlugin;
 
@@ -17,7 +18,7 @@ impl Plugin for ScorePlugin {
 }
 
 /// Resource that saves how many points the player has collected so far
-#[derive(Resource, Reflect, Deref, DerefMut)]
+#[derive(Resource, Reflect, Serialize, Deserialize, Deref, DerefMut, Clone)]
 pub struct Score(pub usize);
 
 impl Score {
@@ -27,7 +28,7 @@ impl Score {
 }
 
 /// Stores the current high score of the game.
-#[derive(Resource, Reflect)]
+#[derive(Resource, Reflect, Serialize, Deserialize, Clone)]
 pub struct HighScore {
     /// The actual high score
     pub score: usize,
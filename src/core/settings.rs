@@ -0,0 +1,397 @@
+use std::fs;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::core::prelude::*;
+
+pub(super) struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .insert_resource(Settings::load())
+            .register_type::<Settings>()
+            .register_type::<Difficulty>()
+            .register_type::<GameMode>()
+            .register_type::<KeyBindings>()
+            .register_type::<GhostRouting>()
+        ;
+    }
+}
+
+/// The file the settings are persisted in, relative to the working directory.
+pub const SETTINGS_PATH: &str = "settings.ron";
+
+/// Everything the player can configure. Gets loaded from disk on startup and saved every time it changes.
+///
+/// Missing fields in the settings file are replaced with their default values, so old files keep working
+/// when new settings get added.
+#[derive(Resource, Reflect, Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(default)]
+pub struct Settings {
+    /// Volume of every sound in the game, from 0.0 to 1.0
+    pub master_volume: f32,
+    /// Volume of the background music, from 0.0 to 1.0
+    pub music_volume: f32,
+    /// Volume of the sound effects, from 0.0 to 1.0
+    pub sfx_volume: f32,
+    /// Volume of the notification sounds, from 0.0 to 1.0
+    pub ui_volume: f32,
+    /// Silences every sound, regardless of the bus settings
+    pub muted: bool,
+    pub music_muted: bool,
+    pub sfx_muted: bool,
+    pub ui_muted: bool,
+    pub fullscreen: bool,
+    /// The integer scale the maze gets rendered with. If None, the biggest scale that fits the window is used.
+    pub scale: Option<u32>,
+    /// The lives pacman has when a new game starts
+    pub starting_lives: usize,
+    /// The points required for an extra life. Zero means no extra lives are given.
+    pub extra_life_threshold: usize,
+    pub difficulty: Difficulty,
+    /// The mode of the next game. Changing it does not affect a running game.
+    pub game_mode: GameMode,
+    /// The rules of the next game. Changing them does not affect a running game.
+    pub ruleset: Ruleset,
+    pub controls: KeyBindings,
+    /// The keys of the second pacman in a co-op game
+    pub controls_player_two: KeyBindings,
+    /// Who steers the pacman of player one. The second player always uses the keyboard.
+    pub pacman_controller: PacmanController,
+    pub practice: PracticeSettings,
+    /// How eaten ghosts find their way back to the ghost house. With [GhostRouting::ShortestPath], Clyde and the
+    /// tie-breaks of chasing ghosts use the real path distance too.
+    pub eaten_ghost_routing: GhostRouting,
+    /// The name of the theme in the themes folder which defines how the game looks. Takes effect
+    /// with the next start of the game.
+    pub theme: String,
+    /// The code of the language in the languages folder all texts are shown in, like "en". Takes effect
+    /// with the next start of the game.
+    pub language: String,
+    pub accessibility: AccessibilitySettings,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            master_volume: 1.0,
+            music_volume: 1.0,
+            sfx_volume: 1.0,
+            ui_volume: 1.0,
+            muted: false,
+            music_muted: false,
+            sfx_muted: false,
+            ui_muted: false,
+            fullscreen: false,
+            scale: None,
+            starting_lives: 3,
+            extra_life_threshold: 10000,
+            difficulty: Difficulty::Normal,
+            game_mode: GameMode::SinglePlayer,
+            ruleset: Ruleset::Arcade,
+            controls: KeyBindings::default(),
+            controls_player_two: KeyBindings::player_two(),
+            pacman_controller: PacmanController::Keyboard,
+            practice: PracticeSettings::default(),
+            eaten_ghost_routing: GhostRouting::Arcade,
+            theme: DEFAULT_THEME.to_string(),
+            language: DEFAULT_LANGUAGE.to_string(),
+            accessibility: AccessibilitySettings::default(),
+        }
+    }
+}
+
+impl Settings {
+    /// Load the settings from disk. If the file does not exist or cannot be parsed, the default settings are used.
+    /// Practice values out of range get fixed, see [PracticeSettings::validate].
+    pub fn load() -> Self {
+        let Ok(content) = fs::read_to_string(SETTINGS_PATH) else {
+            return Settings::default();
+        };
+
+        let mut settings: Settings = ron::from_str(&content).unwrap_or_else(|e| {
+            warn!("the settings file {SETTINGS_PATH} is invalid, using default settings instead: {e}");
+            Settings::default()
+        });
+        settings.practice.validate();
+        settings
+    }
+
+    /// The level the next game starts in.
+    pub fn starting_level(&self) -> usize {
+        match self.practice.enabled {
+            true => self.practice.level,
+            false => 1
+        }
+    }
+
+    /// The lives every player has when the next game starts.
+    pub fn lives_at_start(&self) -> usize {
+        match self.practice.enabled {
+            true => self.practice.lives,
+            false => self.starting_lives
+        }
+    }
+
+    /// The volume a sound on the given bus should be played with, including the master volume and every mute.
+    pub fn bus_volume(&self, bus: AudioBus) -> f32 {
+        if self.muted || self.is_bus_muted(bus) {
+            return 0.0;
+        }
+
+        self.master_volume * self.volume_of(bus)
+    }
+
+    /// The volume of the bus itself, without the master volume and mutes applied.
+    pub fn volume_of(&self, bus: AudioBus) -> f32 {
+        match bus {
+            AudioBus::Music => self.music_volume,
+            AudioBus::Sfx => self.sfx_volume,
+            AudioBus::Ui => self.ui_volume,
+        }
+    }
+
+    pub fn volume_of_mut(&mut self, bus: AudioBus) -> &mut f32 {
+        match bus {
+            AudioBus::Music => &mut self.music_volume,
+            AudioBus::Sfx => &mut self.sfx_volume,
+            AudioBus::Ui => &mut self.ui_volume,
+        }
+    }
+
+    /// The key bindings configured for the given player.
+    pub fn controls_of(&self, player: Player) -> &KeyBindings {
+        match player {
+            Player::One => &self.controls,
+            Player::Two => &self.controls_player_two,
+        }
+    }
+
+    /// Who steers the pacman of the given player.
+    pub fn controller_of(&self, player: Player) -> PacmanController {
+        match player {
+            Player::One => self.pacman_controller,
+            Player::Two => PacmanController::Keyboard,
+        }
+    }
+
+    pub fn controls_of_mut(&mut self, player: Player) -> &mut KeyBindings {
+        match player {
+            Player::One => &mut self.controls,
+            Player::Two => &mut self.controls_player_two,
+        }
+    }
+
+    pub fn is_bus_muted(&self, bus: AudioBus) -> bool {
+        match bus {
+            AudioBus::Music => self.music_muted,
+            AudioBus::Sfx => self.sfx_muted,
+            AudioBus::Ui => self.ui_muted,
+        }
+    }
+
+    pub fn toggle_bus_mute(&mut self, bus: AudioBus) {
+        let muted = match bus {
+            AudioBus::Music => &mut self.music_muted,
+            AudioBus::Sfx => &mut self.sfx_muted,
+            AudioBus::Ui => &mut self.ui_muted,
+        };
+
+        *muted = !*muted;
+    }
+
+    pub fn save(&self) {
+        let result = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|e| e.to_string())
+            .and_then(|content| fs::write(SETTINGS_PATH, content).map_err(|e| e.to_string()));
+
+        if let Err(e) = result {
+            error!("failed to save the settings to {SETTINGS_PATH}: {e}")
+        }
+    }
+}
+
+/// Presets which make the ghosts slower or faster and the energizers last longer or shorter.
+#[derive(Reflect, Serialize, Deserialize, Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub fn next(&self) -> Self {
+        match self {
+            Difficulty::Easy => Difficulty::Normal,
+            Difficulty::Normal => Difficulty::Hard,
+            Difficulty::Hard => Difficulty::Easy,
+        }
+    }
+
+    pub fn previous(&self) -> Self {
+        match self {
+            Difficulty::Easy => Difficulty::Hard,
+            Difficulty::Normal => Difficulty::Easy,
+            Difficulty::Hard => Difficulty::Normal,
+        }
+    }
+}
+
+/// How a ghost picks the next tile on its way to a goal.
+#[derive(Reflect, Serialize, Deserialize, Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum GhostRouting {
+    /// Take the neighbour which is closest to the goal as the crow flies, like in the arcade.
+    /// Works fine in the original maze, but can run in circles or take long detours in custom ones.
+    #[default]
+    Arcade,
+    /// Take the neighbour with the shortest path through the maze to the goal.
+    ShortestPath,
+}
+
+/// How many players play the game and how they share it.
+#[derive(Reflect, Serialize, Deserialize, Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum GameMode {
+    #[default]
+    SinglePlayer,
+    /// Two players take turns, like in the arcade. The turn switches every time pacman dies.
+    Alternating,
+    /// Two players steer two pacmen at the same time and share the dots. The lives are either shared
+    /// or every player has their own.
+    Coop { shared_lives: bool },
+    /// Player one steers pacman, while player two steers the given ghost and scores by catching pacman.
+    Versus { ghost: Ghost },
+}
+
+impl GameMode {
+    const ALL: [GameMode; 8] = [
+        GameMode::SinglePlayer,
+        GameMode::Alternating,
+        GameMode::Coop { shared_lives: true },
+        GameMode::Coop { shared_lives: false },
+        GameMode::Versus { ghost: Blinky },
+        GameMode::Versus { ghost: Pinky },
+        GameMode::Versus { ghost: Inky },
+        GameMode::Versus { ghost: Clyde },
+    ];
+
+    pub fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|mode| mode == self).unwrap_or_default();
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    pub fn previous(&self) -> Self {
+        let index = Self::ALL.iter().position(|mode| mode == self).unwrap_or_default();
+        Self::ALL[(index + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    /// Tells if two players play in this mode.
+    pub fn has_two_players(&self) -> bool {
+        !matches!(self, GameMode::SinglePlayer)
+    }
+
+    /// Tells if both players steer a pacman at the same time.
+    pub fn is_coop(&self) -> bool {
+        matches!(self, GameMode::Coop { .. })
+    }
+
+    /// Tells if both players play at the same time, so they have to share the keyboard.
+    pub fn plays_simultaneously(&self) -> bool {
+        matches!(self, GameMode::Coop { .. } | GameMode::Versus { .. })
+    }
+
+    /// Return the ghost player two controls, if this is a versus game.
+    pub fn player_controlled_ghost(&self) -> Option<Ghost> {
+        match self {
+            GameMode::Versus { ghost } => Some(*ghost),
+            _ => None
+        }
+    }
+}
+
+/// The keys which move pacman in a specific direction. Every direction can have multiple keys.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(default)]
+pub struct KeyBindings {
+    pub up: Vec<KeyCode>,
+    pub down: Vec<KeyCode>,
+    pub left: Vec<KeyCode>,
+    pub right: Vec<KeyCode>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        KeyBindings {
+            up: vec![KeyCode::KeyW, KeyCode::ArrowUp],
+            down: vec![KeyCode::KeyS, KeyCode::ArrowDown],
+            left: vec![KeyCode::KeyA, KeyCode::ArrowLeft],
+            right: vec![KeyCode::KeyD, KeyCode::ArrowRight],
+        }
+    }
+}
+
+impl KeyBindings {
+    /// The default keys of the second pacman in a co-op game.
+    pub fn player_two() -> Self {
+        KeyBindings {
+            up: vec![KeyCode::ArrowUp],
+            down: vec![KeyCode::ArrowDown],
+            left: vec![KeyCode::ArrowLeft],
+            right: vec![KeyCode::ArrowRight],
+        }
+    }
+
+    /// Return these bindings without every key which is also bound in the other bindings. Used in a co-op game,
+    /// so the keys of the second player do not move the first pacman.
+    pub fn without(&self, other: &KeyBindings) -> KeyBindings {
+        let other_keys = [Up, Down, Left, Right]
+            .into_iter()
+            .flat_map(|dir| other.keys_for(dir).iter().copied())
+            .collect::<Vec<_>>();
+        let filter = |keys: &Vec<KeyCode>| keys
+            .iter()
+            .copied()
+            .filter(|key| !other_keys.contains(key))
+            .collect();
+
+        KeyBindings {
+            up: filter(&self.up),
+            down: filter(&self.down),
+            left: filter(&self.left),
+            right: filter(&self.right),
+        }
+    }
+
+    pub fn keys_for(&self, dir: Dir) -> &Vec<KeyCode> {
+        match dir {
+            Up => &self.up,
+            Down => &self.down,
+            Left => &self.left,
+            Right => &self.right,
+        }
+    }
+
+    /// Replace the first key bound to the given direction. The other keys remain as alternatives.
+    pub fn rebind(&mut self, dir: Dir, key: KeyCode) {
+        let keys = match dir {
+            Up => &mut self.up,
+            Down => &mut self.down,
+            Left => &mut self.left,
+            Right => &mut self.right,
+        };
+
+        match keys.first_mut() {
+            Some(first) => *first = key,
+            None => keys.push(key)
+        }
+    }
+
+    /// Return the direction whose keys are currently pressed, if any.
+    pub fn pressed_direction(&self, keyboard_input: &ButtonInput<KeyCode>) -> Option<Dir> {
+        [Left, Right, Up, Down]
+            .into_iter()
+            .find(|dir| keyboard_input.any_pressed(self.keys_for(*dir).iter().copied()))
+    }
+}
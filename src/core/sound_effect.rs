@@ -1,28 +1,73 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::error::Error;
 use std::time::Duration;
+use bevy::asset::{AssetLoader, AsyncReadExt, LoadContext, LoadState};
+use bevy::asset::io::Reader;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::core::prelude::*;
 
 pub(super) struct SoundEffectPlugin;
 
 impl Plugin for SoundEffectPlugin {
     fn build(&self, app: &mut App) {
         app
+            .add_event::<PlaySound>()
+            .init_asset::<SoundRegistry>()
+            .init_asset_loader::<SoundRegistryLoader>()
             .register_type::<SoundEffect>()
+            .register_type::<SoundId>()
+            .add_systems(OnEnter(Setup(LoadSounds)), start_loading_sound_registry)
+            .add_systems(Update, finish_loading_sound_registry.run_if(in_state(Setup(LoadSounds))))
         ;
     }
 }
 
+/// The registry which is used for every sound in the game. Replace this file to mod the sounds.
+pub const SOUND_REGISTRY_PATH: &str = "sounds/default.sounds.ron";
+
+/// Identifies a sound in the [SoundRegistry]. The ids of the sounds the game plays on its own are
+/// available as constants.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Hash)]
+#[serde(transparent)]
+pub struct SoundId(Cow<'static, str>);
+
+impl SoundId {
+    pub const START: SoundId = SoundId::new("start");
+    pub const WAKA: SoundId = SoundId::new("waka");
+    pub const FRUIT_EATEN: SoundId = SoundId::new("fruit_eaten");
+    pub const GHOST_EATEN: SoundId = SoundId::new("ghost_eaten");
+    pub const DYING: SoundId = SoundId::new("dying");
+    pub const HIGH_SCORE: SoundId = SoundId::new("high_score");
+
+    pub const fn new(id: &'static str) -> Self {
+        SoundId(Cow::Borrowed(id))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Fire this to play a sound. How the sound gets played is defined by its entry in the [SoundRegistry].
+#[derive(Event, Clone, Debug)]
+pub struct PlaySound(pub SoundId);
+
 /// Marker for a sound effect that gets played when certain things happen, like pacman
 /// eating dots. Has a timer on it to despawn it later.
 #[derive(Component, Reflect)]
 pub struct SoundEffect {
-    timer: Timer
+    pub id: SoundId,
+    timer: Timer,
 }
 
 impl SoundEffect {
-    /// Create a new sound effect with a timer of one second.
-    pub fn new(duration_secs: u64) -> Self {
+    /// Create a new sound effect which lives for the given duration.
+    pub fn new(id: SoundId, duration: Duration) -> Self {
         SoundEffect {
-            timer: Timer::new(Duration::from_secs(duration_secs), TimerMode::Once)
+            id,
+            timer: Timer::new(duration, TimerMode::Once)
         }
     }
 
@@ -33,4 +78,164 @@ impl SoundEffect {
     pub fn finished(&self) -> bool {
         self.timer.finished()
     }
-}
\ No newline at end of file
+
+    /// How far the sound has played, to find the oldest instance of a sound.
+    pub fn elapsed(&self) -> Duration {
+        self.timer.elapsed()
+    }
+}
+
+/// Maps every [SoundId] to its audio file and the rules how it gets played. Exists as a resource
+/// from Setup(CreateSpriteSheets) on.
+#[derive(Asset, TypePath, Resource, Clone)]
+pub struct SoundRegistry {
+    /// How many sound effects can play at the same time. If more sounds are requested, the ones with
+    /// the lowest priority get dropped.
+    pub max_sounds: usize,
+    sounds: HashMap<SoundId, SoundSpec>,
+}
+
+impl SoundRegistry {
+    pub fn get(&self, id: &SoundId) -> Option<&SoundSpec> {
+        self.sounds.get(id)
+    }
+}
+
+/// Describes how a sound gets played.
+#[derive(Clone)]
+pub struct SoundSpec {
+    pub source: Handle<AudioSource>,
+    pub bus: AudioBus,
+    /// How long the sound is considered playing. The sound effect gets despawned after this time.
+    pub duration: Duration,
+    /// How many instances of this sound can play at the same time.
+    pub max_concurrent: usize,
+    pub retrigger: Retrigger,
+    /// Sounds with a higher priority replace sounds with a lower one when too many sounds are playing.
+    pub priority: u8,
+}
+
+/// What happens if a sound should be played, but already plays the maximum times.
+#[derive(Deserialize, Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Retrigger {
+    /// The new sound does not play.
+    #[default]
+    Drop,
+    /// The oldest instance is stopped and the new sound starts.
+    Restart,
+    /// The new sound plays as soon as an instance finished. At most one sound gets queued,
+    /// so rapid retriggers (like pacman eating dots) do not pile up.
+    Queue,
+}
+
+/// The sound registry which is still loading.
+#[derive(Resource)]
+struct LoadingSoundRegistry(Handle<SoundRegistry>);
+
+fn start_loading_sound_registry(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    commands.insert_resource(LoadingSoundRegistry(asset_server.load(SOUND_REGISTRY_PATH)));
+}
+
+fn finish_loading_sound_registry(
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<GameState>>,
+    asset_server: Res<AssetServer>,
+    registries: Res<Assets<SoundRegistry>>,
+    loading: Res<LoadingSoundRegistry>,
+) {
+    if let Some(LoadState::Failed(e)) = asset_server.get_load_state(&loading.0) {
+        panic!("the sound registry could not be loaded: {e}")
+    }
+
+    let Some(registry) = registries.get(&loading.0) else {
+        return;
+    };
+
+    commands.insert_resource(registry.clone());
+    commands.remove_resource::<LoadingSoundRegistry>();
+    next_state.set(Setup(CreateSpriteSheets));
+}
+
+/// The entries of a sound registry file, as written by hand.
+#[derive(Deserialize)]
+struct SoundRegistryFile {
+    max_sounds: usize,
+    sounds: HashMap<SoundId, SoundSpecFile>,
+}
+
+#[derive(Deserialize)]
+struct SoundSpecFile {
+    path: String,
+    bus: AudioBus,
+    duration_secs: f32,
+    #[serde(default = "one")]
+    max_concurrent: usize,
+    #[serde(default)]
+    retrigger: Retrigger,
+    #[serde(default)]
+    priority: u8,
+}
+
+fn one() -> usize {
+    1
+}
+
+#[derive(Default)]
+struct SoundRegistryLoader;
+
+impl AssetLoader for SoundRegistryLoader {
+    type Asset = SoundRegistry;
+    type Settings = ();
+    type Error = Box<dyn Error + Send + Sync>;
+
+    async fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader<'_>,
+        _settings: &'a (),
+        load_context: &'a mut LoadContext<'_>,
+    ) -> Result<SoundRegistry, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let file: SoundRegistryFile = ron::de::from_bytes(&bytes)?;
+
+        let sounds = file.sounds
+            .into_iter()
+            .map(|(id, spec)| (id, SoundSpec {
+                source: load_context.load(spec.path),
+                bus: spec.bus,
+                duration: Duration::from_secs_f32(spec.duration_secs),
+                max_concurrent: spec.max_concurrent,
+                retrigger: spec.retrigger,
+                priority: spec.priority,
+            }))
+            .collect();
+
+        Ok(SoundRegistry {
+            max_sounds: file.max_sounds,
+            sounds,
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["sounds.ron"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::prelude::*;
+    use crate::scenario::Scenario;
+
+    #[test]
+    fn the_sound_registry_is_loaded_before_the_game_starts() {
+        let scenario = Scenario::new();
+        let registry = scenario.resource::<SoundRegistry>();
+
+        for id in [SoundId::START, SoundId::WAKA, SoundId::FRUIT_EATEN, SoundId::GHOST_EATEN, SoundId::DYING, SoundId::HIGH_SCORE] {
+            assert!(registry.get(&id).is_some(), "the sound {} is not in the registry", id.as_str());
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use bevy::prelude::*;
 use crate::core::prelude::*;
 use std::collections::HashMap;
-use crate::core::prelude::{Fruit, Level};
+use crate::core::prelude::Level;
 
 pub(super) struct SpecsPerLevelPlugin;
 
@@ -24,7 +24,6 @@ impl Default for SpecsPerLevel {
         SpecsPerLevel::from_levels_and_specs(
             [
                 (1, Spec {
-                    fruit_to_spawn: Cherry,
                     pacman_normal_speed_modifier: 0.8,
                     pacman_frightened_speed_modifier: 0.9,
                     ghost_normal_speed_modifier: 0.75,
@@ -37,7 +36,6 @@ impl Default for SpecsPerLevel {
                     frightened_time: 6.0,
                 }),
                 (2, Spec {
-                    fruit_to_spawn: Strawberry,
                     pacman_normal_speed_modifier: 0.9,
                     pacman_frightened_speed_modifier: 0.95,
                     ghost_normal_speed_modifier: 0.85,
@@ -50,7 +48,6 @@ impl Default for SpecsPerLevel {
                     frightened_time: 5.0,
                 }),
                 (3, Spec {
-                    fruit_to_spawn: Peach,
                     pacman_normal_speed_modifier: 0.9,
                     pacman_frightened_speed_modifier: 0.95,
                     ghost_normal_speed_modifier: 0.85,
@@ -63,7 +60,6 @@ impl Default for SpecsPerLevel {
                     frightened_time: 4.0,
                 }),
                 (4, Spec {
-                    fruit_to_spawn: Peach,
                     pacman_normal_speed_modifier: 0.9,
                     ghost_normal_speed_modifier: 0.85,
                     ghost_tunnel_speed_modifier: 0.45,
@@ -76,7 +72,6 @@ impl Default for SpecsPerLevel {
                     frightened_time: 3.0,
                 }),
                 (5, Spec {
-                    fruit_to_spawn: Apple,
                     pacman_normal_speed_modifier: 1.0,
                     ghost_normal_speed_modifier: 0.95,
                     ghost_tunnel_speed_modifier: 0.5,
@@ -89,7 +84,6 @@ impl Default for SpecsPerLevel {
                     frightened_time: 2.0,
                 }),
                 (6, Spec {
-                    fruit_to_spawn: Apple,
                     pacman_normal_speed_modifier: 1.0,
                     ghost_normal_speed_modifier: 0.95,
                     ghost_tunnel_speed_modifier: 0.5,
@@ -102,7 +96,6 @@ impl Default for SpecsPerLevel {
                     frightened_time: 5.0,
                 }),
                 (7, Spec {
-                    fruit_to_spawn: Grapes,
                     pacman_normal_speed_modifier: 1.0,
                     ghost_normal_speed_modifier: 0.95,
                     ghost_tunnel_speed_modifier: 0.5,
@@ -115,7 +108,6 @@ impl Default for SpecsPerLevel {
                     frightened_time: 2.0,
                 }),
                 (8, Spec {
-                    fruit_to_spawn: Grapes,
                     pacman_normal_speed_modifier: 1.0,
                     ghost_normal_speed_modifier: 0.95,
                     ghost_tunnel_speed_modifier: 0.5,
@@ -128,7 +120,6 @@ impl Default for SpecsPerLevel {
                     frightened_time: 2.0,
                 }),
                 (9, Spec {
-                    fruit_to_spawn: Galaxian,
                     pacman_normal_speed_modifier: 1.0,
                     ghost_normal_speed_modifier: 0.95,
                     ghost_tunnel_speed_modifier: 0.5,
@@ -141,7 +132,6 @@ impl Default for SpecsPerLevel {
                     frightened_time: 1.0,
                 }),
                 (10, Spec {
-                    fruit_to_spawn: Galaxian,
                     pacman_normal_speed_modifier: 1.0,
                     ghost_normal_speed_modifier: 0.95,
                     ghost_tunnel_speed_modifier: 0.5,
@@ -154,7 +144,6 @@ impl Default for SpecsPerLevel {
                     frightened_time: 5.0,
                 }),
                 (11, Spec {
-                    fruit_to_spawn: Bell,
                     pacman_normal_speed_modifier: 1.0,
                     ghost_normal_speed_modifier: 0.95,
                     ghost_tunnel_speed_modifier: 0.5,
@@ -167,7 +156,6 @@ impl Default for SpecsPerLevel {
                     frightened_time: 2.0,
                 }),
                 (12, Spec {
-                    fruit_to_spawn: Bell,
                     pacman_normal_speed_modifier: 1.0,
                     ghost_normal_speed_modifier: 0.95,
                     ghost_tunnel_speed_modifier: 0.5,
@@ -180,7 +168,6 @@ impl Default for SpecsPerLevel {
                     frightened_time: 1.0,
                 }),
                 (13, Spec {
-                    fruit_to_spawn: Key,
                     pacman_normal_speed_modifier: 1.0,
                     ghost_normal_speed_modifier: 0.95,
                     ghost_tunnel_speed_modifier: 0.5,
@@ -193,7 +180,6 @@ impl Default for SpecsPerLevel {
                     frightened_time: 1.0,
                 }),
                 (14, Spec {
-                    fruit_to_spawn: Key,
                     pacman_normal_speed_modifier: 1.0,
                     ghost_normal_speed_modifier: 0.95,
                     ghost_tunnel_speed_modifier: 0.5,
@@ -206,7 +192,6 @@ impl Default for SpecsPerLevel {
                     frightened_time: 3.0,
                 }),
                 (15, Spec {
-                    fruit_to_spawn: Key,
                     pacman_normal_speed_modifier: 1.0,
                     ghost_normal_speed_modifier: 0.95,
                     ghost_tunnel_speed_modifier: 0.5,
@@ -219,7 +204,6 @@ impl Default for SpecsPerLevel {
                     frightened_time: 1.0,
                 }),
                 (16, Spec {
-                    fruit_to_spawn: Key,
                     pacman_normal_speed_modifier: 1.0,
                     ghost_normal_speed_modifier: 0.95,
                     ghost_tunnel_speed_modifier: 0.5,
@@ -232,7 +216,6 @@ impl Default for SpecsPerLevel {
                     frightened_time: 1.0,
                 }),
                 (17, Spec {
-                    fruit_to_spawn: Key,
                     pacman_normal_speed_modifier: 1.0,
                     ghost_normal_speed_modifier: 0.95,
                     ghost_tunnel_speed_modifier: 0.5,
@@ -245,7 +228,6 @@ impl Default for SpecsPerLevel {
                     frightened_time: 0.0,
                 }),
                 (18, Spec {
-                    fruit_to_spawn: Key,
                     pacman_normal_speed_modifier: 1.0,
                     ghost_normal_speed_modifier: 0.95,
                     ghost_tunnel_speed_modifier: 0.5,
@@ -258,7 +240,6 @@ impl Default for SpecsPerLevel {
                     frightened_time: 1.0,
                 }),
                 (19, Spec {
-                    fruit_to_spawn: Key,
                     pacman_normal_speed_modifier: 1.0,
                     ghost_normal_speed_modifier: 0.95,
                     ghost_tunnel_speed_modifier: 0.5,
@@ -271,7 +252,6 @@ impl Default for SpecsPerLevel {
                     frightened_time: 0.0,
                 }),
                 (20, Spec {
-                    fruit_to_spawn: Key,
                     pacman_normal_speed_modifier: 1.0,
                     ghost_normal_speed_modifier: 0.95,
                     ghost_tunnel_speed_modifier: 0.5,
@@ -285,7 +265,6 @@ impl Default for SpecsPerLevel {
                 })
             ],
             Spec {
-                fruit_to_spawn: Key,
                 pacman_normal_speed_modifier: 0.9,
                 pacman_frightened_speed_modifier: 0.9,
                 ghost_normal_speed_modifier: 0.95,
@@ -315,13 +294,42 @@ impl SpecsPerLevel {
     pub fn get_for(&self, level: &Level) -> &Spec {
         self.level_to_spec.get(level).unwrap_or(&self.default)
     }
+
+    /// Create the specs for the given difficulty. Normal returns the specs of the arcade game.
+    pub fn for_difficulty(difficulty: Difficulty) -> Self {
+        let mut specs = SpecsPerLevel::default();
+
+        for spec in specs.level_to_spec.values_mut().chain([&mut specs.default]) {
+            *spec = spec.with_difficulty(difficulty);
+        }
+
+        specs
+    }
+}
+
+impl Spec {
+    /// Easy makes the ghosts slower and the energizers last longer (even in levels where they normally
+    /// don't frighten the ghosts at all). Hard does the opposite.
+    fn with_difficulty(mut self, difficulty: Difficulty) -> Self {
+        let (ghost_speed_factor, frightened_time_factor, min_frightened_time) = match difficulty {
+            Difficulty::Easy => (0.9, 1.5, 1.0),
+            Difficulty::Normal => return self,
+            Difficulty::Hard => (1.05, 0.5, 0.0),
+        };
+
+        self.ghost_normal_speed_modifier *= ghost_speed_factor;
+        self.ghost_tunnel_speed_modifier *= ghost_speed_factor;
+        self.ghost_frightened_speed_modifier *= ghost_speed_factor;
+        self.elroy_1_speed_modifier *= ghost_speed_factor;
+        self.elroy_2_speed_modifier *= ghost_speed_factor;
+        self.frightened_time = (self.frightened_time * frightened_time_factor).max(min_frightened_time);
+        self
+    }
 }
 
 /// The difficulty specifications for a level
 #[derive(Reflect, Copy, Clone, Debug, Default, PartialEq)]
 pub struct Spec {
-    /// What fruit to spawn
-    pub fruit_to_spawn: Fruit,
     /// Modifier for pacmans speed if no energizer is active
     pub pacman_normal_speed_modifier: f32,
     /// Modifier for pacmans speed if an energizer is active
@@ -354,7 +362,6 @@ mod tests {
         let specs_per_level = SpecsPerLevel::from_levels_and_specs(
             [
                 (1, Spec {
-                    fruit_to_spawn: Cherry,
                     pacman_normal_speed_modifier: 1.0,
                     pacman_frightened_speed_modifier: 1.0,
                     ghost_normal_speed_modifier: 1.0,
@@ -367,7 +374,6 @@ mod tests {
                     frightened_time: 1.0,
                 }),
                 (2, Spec {
-                    fruit_to_spawn: Apple,
                     pacman_normal_speed_modifier: 2.0,
                     pacman_frightened_speed_modifier: 2.0,
                     ghost_normal_speed_modifier: 2.0,
@@ -392,7 +398,7 @@ mod tests {
         let spec = Spec::default();
 
         let specs_per_level = SpecsPerLevel::from_levels_and_specs(
-            [(1, spec.clone())],
+            [(1, spec)],
             Spec::default()
         );
 
@@ -404,7 +410,6 @@ mod tests {
     #[test]
     fn it_returns_a_default_spec_when_none_is_registered_for_the_given_level() {
         let spec = Spec {
-            fruit_to_spawn: Cherry,
             pacman_normal_speed_modifier: 1.0,
             pacman_frightened_speed_modifier: 1.0,
             ghost_normal_speed_modifier: 1.0,
@@ -418,7 +423,7 @@ mod tests {
         };
 
         let specs_per_level = SpecsPerLevel::from_levels_and_specs(
-            [(1, spec.clone())],
+            [(1, spec)],
             Spec::default()
         );
 
@@ -426,4 +431,23 @@ mod tests {
 
         assert_eq!(retrieved_spec, &specs_per_level.default)
     }
+
+    #[test]
+    fn normal_difficulty_keeps_the_arcade_specs() {
+        let arcade = SpecsPerLevel::default();
+        let normal = SpecsPerLevel::for_difficulty(Difficulty::Normal);
+
+        assert_eq!(arcade.get_for(&Level(1)), normal.get_for(&Level(1)));
+        assert_eq!(arcade.get_for(&Level(42)), normal.get_for(&Level(42)));
+    }
+
+    #[test]
+    fn easy_difficulty_slows_the_ghosts_and_extends_the_frightened_time() {
+        let arcade = SpecsPerLevel::default();
+        let easy = SpecsPerLevel::for_difficulty(Difficulty::Easy);
+
+        assert!(easy.get_for(&Level(1)).ghost_normal_speed_modifier < arcade.get_for(&Level(1)).ghost_normal_speed_modifier);
+        assert!(easy.get_for(&Level(1)).frightened_time > arcade.get_for(&Level(1)).frightened_time);
+        assert!(easy.get_for(&Level(42)).frightened_time > 0.0);
+    }
 }
\ No newline at end of file
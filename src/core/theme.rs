@@ -0,0 +1,361 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::error::Error;
+
+use bevy::asset::{AssetLoader, AsyncReadExt, LoadContext, LoadState};
+use bevy::asset::io::Reader;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::core::prelude::*;
+
+/// Loads the theme selected in the settings, which defines how the game looks.
+pub(super) struct ThemePlugin;
+
+impl Plugin for ThemePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_asset::<Theme>()
+            .init_asset_loader::<ThemeLoader>()
+            .add_systems(OnEnter(Setup(LoadTheme)), start_loading_theme)
+            .add_systems(Update, finish_loading_theme.run_if(in_state(Setup(LoadTheme))))
+        ;
+    }
+}
+
+/// The theme which is used if the settings select none or a theme which does not exist.
+pub const DEFAULT_THEME: &str = "default";
+
+const THEME_EXTENSION: &str = ".theme.ron";
+
+/// The path of the theme with the given name, relative to the assets folder.
+pub fn theme_path(name: &str) -> String {
+    format!("themes/{name}{THEME_EXTENSION}")
+}
+
+/// The names of every theme in the assets folder, in alphabetical order.
+pub fn available_themes() -> Vec<String> {
+    asset_paths_with_extension(THEME_EXTENSION)
+        .iter()
+        .filter_map(|path| path.strip_prefix("themes/")?.strip_suffix(THEME_EXTENSION))
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Identifies an image or an animation in a [Theme]. The ids of everything the game draws are
+/// available as constants or functions.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Hash)]
+#[serde(transparent)]
+pub struct SpriteId(Cow<'static, str>);
+
+impl SpriteId {
+    pub const DOT: SpriteId = SpriteId::new("dot");
+    pub const ENERGIZER: SpriteId = SpriteId::new("energizer");
+    pub const PACMAN_LIFE: SpriteId = SpriteId::new("pacman_life");
+    pub const PACMAN_DYING: SpriteId = SpriteId::new("pacman_dying");
+    pub const FRIGHTENED: SpriteId = SpriteId::new("frightened");
+    pub const FRIGHTENED_BLINKING: SpriteId = SpriteId::new("frightened_blinking");
+    pub const OUTER_WALL: SpriteId = SpriteId::new("outer_wall");
+    pub const OUTER_WALL_CORNER: SpriteId = SpriteId::new("outer_wall_corner");
+    pub const INNER_WALL: SpriteId = SpriteId::new("inner_wall");
+    pub const INNER_WALL_CORNER: SpriteId = SpriteId::new("inner_wall_corner");
+    pub const GHOST_HOUSE_WALL: SpriteId = SpriteId::new("ghost_house_wall");
+    pub const GHOST_HOUSE_WALL_CORNER: SpriteId = SpriteId::new("ghost_house_wall_corner");
+    pub const GHOST_HOUSE_ENTRANCE: SpriteId = SpriteId::new("ghost_house_entrance");
+
+    pub const fn new(id: &'static str) -> Self {
+        SpriteId(Cow::Borrowed(id))
+    }
+
+    /// The body of the ghost while it looks in the given direction, like "blinky_up".
+    pub fn ghost(ghost: Ghost, direction: Dir) -> Self {
+        let ghost = format!("{ghost:?}").to_lowercase();
+        SpriteId(Cow::Owned(format!("{ghost}_{}", direction_name(direction))))
+    }
+
+    /// The eyes of an eaten ghost looking in the given direction, like "eaten_up".
+    pub fn eaten_ghost(direction: Dir) -> Self {
+        SpriteId(Cow::Owned(format!("eaten_{}", direction_name(direction))))
+    }
+
+    /// Pacman eating while he walks in the given direction, like "pacman_walking_up".
+    pub fn pacman_walking(direction: Dir) -> Self {
+        SpriteId(Cow::Owned(format!("pacman_walking_{}", direction_name(direction))))
+    }
+
+    /// A sprite which is named in a file, like a map.
+    pub fn named(name: &str) -> Self {
+        SpriteId(Cow::Owned(name.to_string()))
+    }
+
+    /// The fruit, like "fruit_cherry".
+    pub fn fruit(fruit: Fruit) -> Self {
+        let fruit = format!("{fruit:?}").to_lowercase();
+        SpriteId(Cow::Owned(format!("fruit_{fruit}")))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+fn direction_name(direction: Dir) -> &'static str {
+    match direction {
+        Up => "up",
+        Down => "down",
+        Left => "left",
+        Right => "right",
+    }
+}
+
+/// Identifies a color in a [Theme].
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Hash)]
+#[serde(transparent)]
+pub struct ColorId(Cow<'static, str>);
+
+impl ColorId {
+    /// Behind the maze and in the tunnels
+    pub const BACKGROUND: ColorId = ColorId::new("background");
+    /// The scores and their labels
+    pub const TEXT: ColorId = ColorId::new("text");
+    pub const READY_TEXT: ColorId = ColorId::new("ready_text");
+    /// The name of the player whose turn it is in an alternating game
+    pub const PLAYER_TEXT: ColorId = ColorId::new("player_text");
+    pub const GAME_OVER_TEXT: ColorId = ColorId::new("game_over_text");
+    /// The points shown where a ghost was eaten
+    pub const GHOST_POINTS: ColorId = ColorId::new("ghost_points");
+    /// The points shown where a fruit was eaten
+    pub const FRUIT_POINTS: ColorId = ColorId::new("fruit_points");
+    pub const MENU_BACKGROUND: ColorId = ColorId::new("menu_background");
+    pub const MENU_TEXT: ColorId = ColorId::new("menu_text");
+    /// The title and the selected entry of the settings menu
+    pub const MENU_SELECTED: ColorId = ColorId::new("menu_selected");
+
+    pub const fn new(id: &'static str) -> Self {
+        ColorId(Cow::Borrowed(id))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Maps every [SpriteId] to an image or an animation, every [ColorId] to a color and defines the font.
+/// Add a new theme file to the themes folder to reskin the game.
+///
+/// A theme can extend another one and only replace some of its entries. Exists as a resource from
+/// Setup(LoadLanguage) on.
+#[derive(Asset, Resource, TypePath, Clone)]
+pub struct Theme {
+    pub font: Handle<Font>,
+    images: HashMap<SpriteId, Handle<Image>>,
+    /// The keys of the [AnimationSheets] of every animated sprite
+    animations: HashMap<SpriteId, String>,
+    colors: HashMap<ColorId, Color>,
+}
+
+impl Theme {
+    pub fn image(&self, id: &SpriteId) -> Handle<Image> {
+        self.images
+            .get(id)
+            .unwrap_or_else(|| panic!("the theme has no image {}", id.as_str()))
+            .clone()
+    }
+
+    pub fn has_image(&self, id: &SpriteId) -> bool {
+        self.images.contains_key(id)
+    }
+
+    /// The animations of the given sprite.
+    pub fn animations<'a>(&self, animation_sheets: &'a AnimationSheets, id: &SpriteId) -> &'a AsepriteAnimations {
+        let key = self.animations
+            .get(id)
+            .unwrap_or_else(|| panic!("the theme has no animation {}", id.as_str()));
+
+        animation_sheets.get_sheet(key)
+    }
+
+    pub fn color(&self, id: &ColorId) -> Color {
+        *self.colors
+            .get(id)
+            .unwrap_or_else(|| panic!("the theme has no color {}", id.as_str()))
+    }
+
+    /// A text style with the font of the theme and the given color.
+    pub fn text_style(&self, color: &ColorId) -> TextStyle {
+        TextStyle {
+            font: self.font.clone(),
+            font_size: FONT_SIZE,
+            color: self.color(color),
+        }
+    }
+}
+
+/// The theme which is still loading.
+#[derive(Resource)]
+struct LoadingTheme {
+    name: String,
+    handle: Handle<Theme>,
+}
+
+fn start_loading_theme(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Settings>,
+) {
+    let name = match available_themes().contains(&settings.theme) {
+        true => settings.theme.as_str(),
+        false => {
+            warn!("the theme {} does not exist, using the {DEFAULT_THEME} theme instead", settings.theme);
+            DEFAULT_THEME
+        }
+    };
+
+    commands.insert_resource(LoadingTheme {
+        name: name.to_string(),
+        handle: asset_server.load(theme_path(name)),
+    });
+}
+
+fn finish_loading_theme(
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut clear_color: Option<ResMut<ClearColor>>,
+    asset_server: Res<AssetServer>,
+    themes: Res<Assets<Theme>>,
+    loading: Res<LoadingTheme>,
+) {
+    if let Some(LoadState::Failed(e)) = asset_server.get_load_state(&loading.handle) {
+        if loading.name == DEFAULT_THEME {
+            panic!("the {DEFAULT_THEME} theme could not be loaded: {e}")
+        }
+
+        warn!("the theme {} could not be loaded, using the {DEFAULT_THEME} theme instead: {e}", loading.name);
+        commands.insert_resource(LoadingTheme {
+            name: DEFAULT_THEME.to_string(),
+            handle: asset_server.load(theme_path(DEFAULT_THEME)),
+        });
+        return;
+    }
+
+    let Some(theme) = themes.get(&loading.handle) else {
+        return;
+    };
+
+    if let Some(clear_color) = clear_color.as_mut() {
+        clear_color.0 = theme.color(&ColorId::BACKGROUND);
+    }
+
+    commands.insert_resource(theme.clone());
+    commands.remove_resource::<LoadingTheme>();
+    next_state.set(Setup(LoadLanguage));
+}
+
+/// The entries of a theme file, as written by hand. Every entry is optional if the theme extends another one.
+#[derive(Deserialize)]
+struct ThemeFile {
+    /// The name of the theme which provides every entry this one does not define
+    extends: Option<String>,
+    font: Option<String>,
+    #[serde(default)]
+    images: HashMap<SpriteId, String>,
+    #[serde(default)]
+    animations: HashMap<SpriteId, String>,
+    /// Colors as hex strings, like "FFB8FF" or "000000E6"
+    #[serde(default)]
+    colors: HashMap<ColorId, String>,
+}
+
+impl ThemeFile {
+    /// Optional entries can be written without `Some(...)`, like `font: "fonts/font.ttf"`.
+    fn parse(bytes: &[u8]) -> Result<Self, ron::error::SpannedError> {
+        ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_bytes(bytes)
+    }
+}
+
+#[derive(Default)]
+struct ThemeLoader;
+
+impl AssetLoader for ThemeLoader {
+    type Asset = Theme;
+    type Settings = ();
+    type Error = Box<dyn Error + Send + Sync>;
+
+    async fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader<'_>,
+        _settings: &'a (),
+        load_context: &'a mut LoadContext<'_>,
+    ) -> Result<Theme, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let file = ThemeFile::parse(&bytes)?;
+
+        let base = match &file.extends {
+            Some(name) => Some(load_context.loader().direct().load::<Theme>(theme_path(name)).await?.take()),
+            None => None,
+        };
+
+        let font = match (file.font, &base) {
+            (Some(path), _) => load_context.load(path),
+            (None, Some(base)) => base.font.clone(),
+            (None, None) => return Err("a theme which extends no other theme needs a font".into()),
+        };
+
+        let mut theme = base.unwrap_or_else(|| Theme {
+            font: font.clone(),
+            images: HashMap::new(),
+            animations: HashMap::new(),
+            colors: HashMap::new(),
+        });
+        theme.font = font;
+
+        for (id, path) in file.images {
+            theme.images.insert(id, load_context.load(path));
+        }
+
+        theme.animations.extend(file.animations);
+
+        for (id, hex) in file.colors {
+            let color = Srgba::hex(&hex).map_err(|e| format!("the color {} is invalid: {e}", id.as_str()))?;
+            theme.colors.insert(id, Color::Srgba(color));
+        }
+
+        Ok(theme)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["theme.ron"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::core::prelude::*;
+    use super::ThemeFile;
+
+    #[test]
+    fn the_sprite_ids_match_the_names_in_the_theme_files() {
+        assert_eq!(SpriteId::ghost(Blinky, Up).as_str(), "blinky_up");
+        assert_eq!(SpriteId::eaten_ghost(Left).as_str(), "eaten_left");
+        assert_eq!(SpriteId::pacman_walking(Down).as_str(), "pacman_walking_down");
+        assert_eq!(SpriteId::fruit(Galaxian).as_str(), "fruit_galaxian");
+    }
+
+    #[test]
+    fn the_default_theme_is_available() {
+        assert!(available_themes().contains(&DEFAULT_THEME.to_string()));
+    }
+
+    #[test]
+    fn every_theme_file_can_be_read() {
+        for name in available_themes() {
+            let content = fs::read(format!("assets/{}", theme_path(&name))).unwrap();
+            assert!(ThemeFile::parse(&content).is_ok(), "the theme {name} cannot be read");
+        }
+    }
+}
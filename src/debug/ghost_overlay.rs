@@ -0,0 +1,225 @@
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+
+use crate::core::prelude::*;
+use crate::game::target::{calculate_chase_target, inky_pivot, CLYDE_FLEE_DISTANCE};
+
+/// Draws what the ghosts are up to on top of the maze. Gets toggled with F3.
+///
+/// - A line from every ghost to the tile it chases, or to its corner while it scatters. Inky's line starts
+///   at Blinky and goes through the field in front of pacman, because this is how Inky's target is calculated.
+/// - A small square on the next tile every ghost walks to.
+/// - A circle around Clyde. When pacman is inside, Clyde flees to his corner.
+/// - The scatter corners, one ways, tunnels and tunnel hallways.
+/// - A label above every ghost with its state and speed. The ghost waiting in the ghost house also shows
+///   the counter of the gate and the seconds until the release timer sets it free.
+pub(super) struct GhostOverlayPlugin;
+
+impl Plugin for GhostOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(
+                Update,
+                (
+                    toggle_overlay,
+                    (
+                        draw_map_tiles,
+                        draw_ghost_targets,
+                        update_labels,
+                    ).run_if(resource_exists::<GhostOverlay>),
+                ).chain().run_if(in_game)
+            )
+        ;
+    }
+}
+
+/// Exists while the overlay is shown.
+#[derive(Resource)]
+struct GhostOverlay;
+
+/// A text which follows the given ghost entity.
+#[derive(Component)]
+struct GhostLabel(Entity);
+
+const LABEL_FONT_SIZE: f32 = FONT_SIZE * 0.5;
+const ONE_WAY_COLOR: Color = Color::WHITE;
+const TUNNEL_COLOR: Color = Color::srgb(0.0, 1.0, 0.0);
+const HALLWAY_COLOR: Color = Color::srgb(0.0, 0.5, 0.0);
+
+fn ghost_color(ghost: &Ghost) -> Color {
+    match ghost {
+        Blinky => Color::srgb(1.0, 0.0, 0.0),
+        Pinky => Color::srgb(1.0, 0.72, 1.0),
+        Inky => Color::srgb(0.0, 1.0, 1.0),
+        Clyde => Color::srgb(1.0, 0.72, 0.32),
+    }
+}
+
+fn toggle_overlay(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    overlay: Option<Res<GhostOverlay>>,
+    label_query: Query<Entity, With<GhostLabel>>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::F3) {
+        return;
+    }
+
+    match overlay {
+        Some(_) => {
+            commands.remove_resource::<GhostOverlay>();
+            for e in &label_query {
+                commands.entity(e).despawn();
+            }
+        }
+        None => commands.insert_resource(GhostOverlay)
+    }
+}
+
+fn draw_map_tiles(
+    mut gizmos: Gizmos,
+    corner_query: Query<(&GhostCorner, &Tiles)>,
+    one_way_query: Query<&Tiles, With<OneWay>>,
+    tunnel_query: Query<&Tiles, With<Tunnel>>,
+    hallway_query: Query<&Tiles, With<TunnelHallway>>,
+) {
+    for (corner, tiles) in &corner_query {
+        outline_tile(&mut gizmos, tiles.to_vec3(0.0), 1.0, ghost_color(corner));
+    }
+
+    for tiles in &one_way_query {
+        outline_tile(&mut gizmos, tiles.to_vec3(0.0), 1.0, ONE_WAY_COLOR);
+    }
+
+    for tiles in &tunnel_query {
+        outline_tile(&mut gizmos, tiles.to_vec3(0.0), 1.0, TUNNEL_COLOR);
+    }
+
+    for tiles in &hallway_query {
+        outline_tile(&mut gizmos, tiles.to_vec3(0.0), 1.0, HALLWAY_COLOR);
+    }
+}
+
+/// Draw the targets with the same rules the ghosts use, see [calculate_chase_target].
+fn draw_ghost_targets(
+    mut gizmos: Gizmos,
+    corner_query: Query<(&GhostCorner, &Tiles)>,
+    pacman_query: Query<(&Transform, &Dir), With<Pacman>>,
+    ghost_query: Query<(&Ghost, &Transform, &GhostState, &Target)>,
+) {
+    let blinky_position = ghost_query
+        .iter()
+        .find(|(ghost, ..)| **ghost == Blinky)
+        .map(|(_, transform, ..)| Pos::from_vec3(transform.translation));
+
+    for (ghost, transform, state, target) in &ghost_query {
+        let color = ghost_color(ghost);
+        let coordinates = transform.translation;
+
+        if target.is_set() {
+            outline_tile(&mut gizmos, target.get(), 0.5, color);
+        }
+
+        if *ghost == Clyde {
+            gizmos.circle_2d(coordinates.truncate(), CLYDE_FLEE_DISTANCE, color);
+        }
+
+        let nearest_pacman = pacman_query
+            .iter()
+            .min_by(|(a, _), (b, _)| a.translation.distance(coordinates).total_cmp(&b.translation.distance(coordinates)));
+        let corner = corner_query
+            .iter()
+            .find(|(corner, _)| ***corner == *ghost)
+            .map(|(_, tiles)| tiles.to_pos());
+
+        let (Some((pacman_transform, pacman_direction)), Some(blinky_position), Some(corner)) = (nearest_pacman, blinky_position, corner) else {
+            continue;
+        };
+
+        let pacman_position = Pos::from_vec3(pacman_transform.translation);
+        let goal = match state {
+            Chase => calculate_chase_target(*ghost, coordinates, pacman_position, *pacman_direction, blinky_position, corner),
+            Scatter => corner,
+            _ => continue
+        };
+        let goal_coordinates = goal.to_vec3(0.0);
+
+        outline_tile(&mut gizmos, goal_coordinates, 1.0, color);
+        gizmos.line_2d(coordinates.truncate(), goal_coordinates.truncate(), color);
+
+        if *ghost == Inky && *state == Chase {
+            let pivot = inky_pivot(pacman_position, *pacman_direction).to_vec3(0.0);
+            outline_tile(&mut gizmos, pivot, 0.5, color);
+            gizmos.line_2d(blinky_position.to_vec3(0.0).truncate(), goal_coordinates.truncate(), color);
+        }
+    }
+}
+
+/// Keep a label above every ghost. Ghosts get despawned and spawned again during the game, so the labels
+/// follow them.
+fn update_labels(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    ghost_house_gate: Option<Res<GhostHouseGate>>,
+    ghost_query: Query<(Entity, &Ghost, &Transform, &GhostState, &Speed)>,
+    mut label_query: Query<(Entity, &GhostLabel, &mut Transform, &mut Text), Without<Ghost>>,
+) {
+    let mut labeled_ghosts = HashSet::new();
+
+    for (label_entity, label, mut label_transform, mut text) in &mut label_query {
+        let Ok((_, ghost, transform, state, speed)) = ghost_query.get(label.0) else {
+            commands.entity(label_entity).despawn();
+            continue;
+        };
+
+        labeled_ghosts.insert(label.0);
+        label_transform.translation = label_coordinates(transform);
+        text.sections[0].value = label_text(ghost, state, speed, &ghost_house_gate);
+    }
+
+    for (entity, ghost, transform, state, speed) in &ghost_query {
+        if labeled_ghosts.contains(&entity) {
+            continue;
+        }
+
+        commands.spawn((
+            Name::new("GhostLabel"),
+            GhostLabel(entity),
+            Text2dBundle {
+                text: Text::from_section(
+                    label_text(ghost, state, speed, &ghost_house_gate),
+                    TextStyle {
+                        font: asset_server.load(FONT),
+                        font_size: LABEL_FONT_SIZE,
+                        color: ghost_color(ghost),
+                    },
+                ).with_justify(JustifyText::Center),
+                transform: Transform::from_translation(label_coordinates(transform)),
+                ..default()
+            },
+        ));
+    }
+}
+
+fn label_coordinates(ghost_transform: &Transform) -> Vec3 {
+    let mut coordinates = ghost_transform.translation + Vec3::new(0.0, 1.5 * FIELD_SIZE, 0.0);
+    coordinates.z = TEXT_Z;
+    coordinates
+}
+
+fn label_text(ghost: &Ghost, state: &GhostState, speed: &Speed, ghost_house_gate: &Option<Res<GhostHouseGate>>) -> String {
+    let mut text = format!("{state:?} {:.0}", **speed).to_uppercase();
+
+    if let Some(gate) = ghost_house_gate.as_ref().filter(|gate| gate.waiting_ghost() == Some(*ghost)) {
+        let (value, limit) = gate.counter_of(ghost);
+        text.push_str(&format!("\nGATE {value}/{limit} {:.1}S", gate.remaining_release_time()));
+    }
+
+    text
+}
+
+/// Outline the tile at the given coordinates. The factor scales the outline, so tiles which are marked
+/// more than once can be told apart.
+fn outline_tile(gizmos: &mut Gizmos, coordinates: Vec3, factor: f32, color: Color) {
+    gizmos.rect_2d(coordinates.truncate(), 0.0, Vec2::splat(FIELD_SIZE * factor), color);
+}
@@ -2,6 +2,14 @@ use bevy::prelude::*;
 #[cfg(debug_assertions)]
 use crate::core::prelude::*;
 
+#[cfg(debug_assertions)]
+mod ghost_overlay;
+
+/// Tools for debugging the game, only available in debug builds.
+///
+/// - Space freezes and unfreezes the time
+/// - C removes every dot, energizer and fruit, so the level ends
+/// - F3 shows an overlay with the targets, states and speeds of the ghosts
 pub struct DebugPlugin;
 
 impl Plugin for DebugPlugin {
@@ -10,7 +18,10 @@ impl Plugin for DebugPlugin {
         use bevy_inspector_egui::quick::WorldInspectorPlugin;
 
         app
-            .add_plugins(WorldInspectorPlugin::new())
+            .add_plugins((
+                WorldInspectorPlugin::new(),
+                ghost_overlay::GhostOverlayPlugin,
+            ))
             .add_systems(
                 Update,
                 (
@@ -0,0 +1,252 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::core::prelude::*;
+use crate::env::observation::ObservationQueries;
+use crate::headless::{create_simulated_app, current_state, run_until_game_started};
+
+pub use crate::core::direction::Dir;
+pub use crate::core::ghost_state::GhostState;
+pub use crate::core::ghosts::Ghost;
+pub use crate::core::settings::Difficulty;
+pub use crate::env::observation::*;
+
+mod observation;
+
+/// An environment to train agents against the game, in the style of a reinforcement learning gym.
+///
+/// The game runs headless, without a window or a GPU. Its time is simulated, so every tick advances the game by the
+/// same duration, no matter how fast the machine is. Together with a seed for the random numbers, a game played
+/// with the same actions always ends the same way.
+///
+/// ```no_run
+/// use pacman::env::{Action, EnvConfig, PacmanEnv};
+///
+/// let mut env = PacmanEnv::new(EnvConfig::default());
+/// let mut observation = env.reset(42);
+///
+/// loop {
+///     let (next_observation, reward, done, info) = env.step(Action::Left);
+///     observation = next_observation;
+///
+///     if done {
+///         println!("the game ended with {} points", info.score);
+///         break;
+///     }
+/// }
+/// ```
+pub struct PacmanEnv {
+    config: EnvConfig,
+    simulation: Option<Simulation>,
+}
+
+/// Configures a [PacmanEnv].
+#[derive(Clone, Debug)]
+pub struct EnvConfig {
+    /// How much game time passes in one tick. Every tick is one update of the game.
+    pub tick_duration: Duration,
+    /// How many ticks one step lasts. The action of the step is held for all of them.
+    pub ticks_per_step: usize,
+    /// The reward (usually negative) which is given when pacman loses a life. The other rewards are the points scored.
+    pub death_reward: f32,
+    /// The game is cut off after this many steps, even if pacman has lives left. None plays until the game is over.
+    pub max_steps: Option<usize>,
+    pub starting_lives: usize,
+    pub difficulty: Difficulty,
+}
+
+impl Default for EnvConfig {
+    fn default() -> Self {
+        EnvConfig {
+            tick_duration: Duration::from_secs_f64(1.0 / 60.0),
+            ticks_per_step: 4,
+            death_reward: -500.0,
+            max_steps: None,
+            starting_lives: 3,
+            difficulty: Difficulty::Normal,
+        }
+    }
+}
+
+/// What the agent does in one step. Pacman keeps moving in his direction until he hits a wall, so an agent
+/// only has to act when pacman should turn.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Action {
+    /// Press no key and let pacman move on.
+    #[default]
+    Keep,
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Action {
+    /// Every action, to map the index of a discrete action space to an action.
+    pub const ALL: [Action; 5] = [Action::Keep, Action::Up, Action::Down, Action::Left, Action::Right];
+
+    pub fn direction(&self) -> Option<Dir> {
+        match self {
+            Action::Keep => None,
+            Action::Up => Some(Up),
+            Action::Down => Some(Down),
+            Action::Left => Some(Left),
+            Action::Right => Some(Right),
+        }
+    }
+}
+
+/// Additional information about the game after a step.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct StepInfo {
+    pub score: usize,
+    pub lives: usize,
+    pub level: usize,
+    /// How many steps were made since the last reset.
+    pub steps: usize,
+    /// Tells if the game was cut off because it reached the maximum number of steps, not because it was over.
+    pub truncated: bool,
+}
+
+/// One running game.
+struct Simulation {
+    app: App,
+    queries: ObservationQueries,
+    steps: usize,
+}
+
+impl PacmanEnv {
+    pub fn new(config: EnvConfig) -> Self {
+        PacmanEnv {
+            config,
+            simulation: None,
+        }
+    }
+
+    /// Start a new game and return the first observation. The seed decides every random choice in the game.
+    ///
+    /// Every reset loads the game from scratch, so nothing from a former game remains.
+    pub fn reset(&mut self, seed: u64) -> Observation {
+        let mut app = self.create_app(seed);
+        run_until_game_started(&mut app);
+
+        let mut queries = ObservationQueries::new(app.world_mut());
+        let observation = queries.observe(app.world_mut());
+
+        self.simulation = Some(Simulation {
+            app,
+            queries,
+            steps: 0,
+        });
+
+        observation
+    }
+
+    /// Play one step with the given action and return the new observation, the reward, whether the game is
+    /// over and some additional information.
+    ///
+    /// Panics if the environment was never reset.
+    pub fn step(&mut self, action: Action) -> (Observation, f32, bool, StepInfo) {
+        let simulation = self.simulation.as_mut().expect("the environment must be reset before the first step");
+        let world = simulation.app.world_mut();
+
+        let score_before = **world.resource::<Score>();
+        let mut deaths = 0;
+        press_key_for(world, action);
+
+        for _ in 0..self.config.ticks_per_step {
+            let state_before = current_state(simulation.app.world());
+            simulation.app.update();
+            let state_after = current_state(simulation.app.world());
+
+            if state_after != state_before && state_after == Game(PacmanHit) {
+                deaths += 1;
+            }
+
+            if state_after == Game(GameOver) {
+                break;
+            }
+        }
+
+        simulation.steps += 1;
+
+        let world = simulation.app.world_mut();
+        let score = **world.resource::<Score>();
+        let game_over = current_state(world) == Game(GameOver);
+        let truncated = !game_over && self.config.max_steps.is_some_and(|max| simulation.steps >= max);
+
+        let reward = score.saturating_sub(score_before) as f32 + deaths as f32 * self.config.death_reward;
+        let info = StepInfo {
+            score,
+            lives: **world.resource::<Lives>(),
+            level: **world.resource::<Level>(),
+            steps: simulation.steps,
+            truncated,
+        };
+
+        (simulation.queries.observe(world), reward, game_over || truncated, info)
+    }
+
+    /// Return the current observation without advancing the game.
+    ///
+    /// Panics if the environment was never reset.
+    pub fn observe(&mut self) -> Observation {
+        let simulation = self.simulation.as_mut().expect("the environment must be reset before observing it");
+        simulation.queries.observe(simulation.app.world_mut())
+    }
+
+    fn create_app(&self, seed: u64) -> App {
+        let settings = Settings {
+            starting_lives: self.config.starting_lives,
+            difficulty: self.config.difficulty,
+            game_mode: GameMode::SinglePlayer,
+            muted: true,
+            ..default()
+        };
+
+        create_simulated_app(seed, self.config.tick_duration, settings, ())
+    }
+}
+
+/// Hold the key player one has bound to the direction of the action, or no key at all.
+fn press_key_for(world: &mut World, action: Action) {
+    let key = action
+        .direction()
+        .and_then(|dir| world.resource::<Settings>().controls.keys_for(dir).first().copied());
+
+    let mut keyboard_input = world.resource_mut::<ButtonInput<KeyCode>>();
+    keyboard_input.release_all();
+
+    if let Some(key) = key {
+        keyboard_input.press(key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::env::{Action, EnvConfig, Observation, PacmanEnv};
+
+    /// Play the seeded game with actions which change every few steps and return every observation.
+    fn play(seed: u64) -> Vec<Observation> {
+        let mut env = PacmanEnv::new(EnvConfig { max_steps: Some(400), ..EnvConfig::default() });
+        let mut observations = vec![env.reset(seed)];
+
+        for step in 0.. {
+            let action = Action::ALL[(step / 12) % Action::ALL.len()];
+            let (observation, _, done, _) = env.step(action);
+            observations.push(observation);
+
+            if done {
+                break;
+            }
+        }
+
+        observations
+    }
+
+    #[test]
+    fn the_same_seed_and_actions_lead_to_the_same_game() {
+        assert_eq!(play(7), play(7));
+    }
+}
@@ -0,0 +1,178 @@
+use bevy::prelude::*;
+
+use crate::core::prelude::*;
+
+/// The number of values [Observation::to_channels] creates for every tile.
+pub const NUM_CHANNELS: usize = 9;
+
+/// The state of the maze, as a grid of tiles. The tile (0, 0) is the bottom left corner of the maze.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Observation {
+    pub width: usize,
+    pub height: usize,
+    /// All tiles, row by row, starting with the bottom row.
+    pub tiles: Vec<Tile>,
+}
+
+/// Everything which is on one tile of the maze.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Tile {
+    pub wall: bool,
+    pub dot: bool,
+    pub energizer: bool,
+    pub fruit: bool,
+    pub pacman: bool,
+    /// The state of every ghost on this tile, in the order Blinky, Pinky, Inky and Clyde.
+    pub ghosts: [Option<GhostState>; 4],
+}
+
+impl Observation {
+    /// Return the tile at the given position. Panics if the position is outside the maze.
+    pub fn tile(&self, x: usize, y: usize) -> &Tile {
+        assert!(x < self.width && y < self.height, "the tile ({x}, {y}) is outside the maze");
+        &self.tiles[y * self.width + x]
+    }
+
+    /// Encode the observation as numbers, like a neural network expects them. The values are ordered by
+    /// channel, then by row (starting at the bottom) and then by column.
+    ///
+    /// The channels are walls, dots, energizers, fruit, pacman and one channel for every ghost. The first five
+    /// are 1 if the thing is on the tile and 0 otherwise. The ghost channels are 0 if the ghost is not on the tile,
+    /// otherwise they tell the state of the ghost (1 for scatter, 2 for chase, 3 for frightened, 4 for eaten and 5
+    /// for spawned).
+    pub fn to_channels(&self) -> Vec<u8> {
+        let mut channels = vec![0; NUM_CHANNELS * self.tiles.len()];
+
+        for (i, tile) in self.tiles.iter().enumerate() {
+            let values = [
+                tile.wall as u8,
+                tile.dot as u8,
+                tile.energizer as u8,
+                tile.fruit as u8,
+                tile.pacman as u8,
+                tile.ghosts[0].map(state_value).unwrap_or_default(),
+                tile.ghosts[1].map(state_value).unwrap_or_default(),
+                tile.ghosts[2].map(state_value).unwrap_or_default(),
+                tile.ghosts[3].map(state_value).unwrap_or_default(),
+            ];
+
+            for (channel, value) in values.into_iter().enumerate() {
+                channels[channel * self.tiles.len() + i] = value;
+            }
+        }
+
+        channels
+    }
+}
+
+fn state_value(state: GhostState) -> u8 {
+    match state {
+        Scatter => 1,
+        Chase => 2,
+        Frightened => 3,
+        Eaten => 4,
+        Spawned => 5,
+    }
+}
+
+fn ghost_index(ghost: &Ghost) -> usize {
+    match ghost {
+        Blinky => 0,
+        Pinky => 1,
+        Inky => 2,
+        Clyde => 3,
+    }
+}
+
+/// The queries to create observations, created once per game. The walls never change, so they are only
+/// collected once.
+pub(super) struct ObservationQueries {
+    width: usize,
+    height: usize,
+    walls: Vec<Pos>,
+    dots: QueryState<&'static Transform, With<Dot>>,
+    energizers: QueryState<&'static Transform, With<Energizer>>,
+    fruits: QueryState<&'static Transform, With<Fruit>>,
+    pacmen: QueryState<&'static Transform, With<Pacman>>,
+    ghosts: QueryState<(&'static Transform, &'static Ghost, &'static GhostState)>,
+}
+
+impl ObservationQueries {
+    pub(super) fn new(world: &mut World) -> Self {
+        let map = world.query::<&Map>().single(world);
+        let (width, height) = (map.width, map.height);
+        let walls = world
+            .query_filtered::<&Transform, With<Wall>>()
+            .iter(world)
+            .map(|transform| Pos::from_vec3(transform.translation))
+            .collect();
+
+        ObservationQueries {
+            width,
+            height,
+            walls,
+            dots: world.query_filtered(),
+            energizers: world.query_filtered(),
+            fruits: world.query_filtered(),
+            pacmen: world.query_filtered(),
+            ghosts: world.query(),
+        }
+    }
+
+    pub(super) fn observe(&mut self, world: &World) -> Observation {
+        let mut observation = Observation {
+            width: self.width,
+            height: self.height,
+            tiles: vec![Tile::default(); self.width * self.height],
+        };
+
+        for pos in &self.walls {
+            if let Some(tile) = tile_at(&mut observation, *pos) {
+                tile.wall = true
+            }
+        }
+
+        for transform in self.dots.iter(world) {
+            if let Some(tile) = tile_at(&mut observation, Pos::from_vec3(transform.translation)) {
+                tile.dot = true
+            }
+        }
+
+        for transform in self.energizers.iter(world) {
+            if let Some(tile) = tile_at(&mut observation, Pos::from_vec3(transform.translation)) {
+                tile.energizer = true
+            }
+        }
+
+        for transform in self.fruits.iter(world) {
+            if let Some(tile) = tile_at(&mut observation, Pos::from_vec3(transform.translation)) {
+                tile.fruit = true
+            }
+        }
+
+        for transform in self.pacmen.iter(world) {
+            if let Some(tile) = tile_at(&mut observation, Pos::from_vec3(transform.translation)) {
+                tile.pacman = true
+            }
+        }
+
+        for (transform, ghost, state) in self.ghosts.iter(world) {
+            if let Some(tile) = tile_at(&mut observation, Pos::from_vec3(transform.translation)) {
+                tile.ghosts[ghost_index(ghost)] = Some(*state)
+            }
+        }
+
+        observation
+    }
+}
+
+/// Return the tile at the given position, if it is inside the maze. Pacman and the ghosts leave the maze
+/// for a moment when they move through a tunnel.
+fn tile_at(observation: &mut Observation, pos: Pos) -> Option<&mut Tile> {
+    if pos.x() < 0 || pos.y() < 0 || pos.x() as usize >= observation.width || pos.y() as usize >= observation.height {
+        return None;
+    }
+
+    let index = pos.y() as usize * observation.width + pos.x() as usize;
+    observation.tiles.get_mut(index)
+}
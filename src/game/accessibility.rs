@@ -0,0 +1,143 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+
+use crate::core::prelude::*;
+
+/// Applies the accessibility settings: recolors the ghost textures, marks the ghosts with
+/// their initials and lets frightened ghosts pulse when the energizer is running out.
+pub(super) struct AccessibilityPlugin;
+
+impl Plugin for AccessibilityPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(OnEnter(Setup(CreateSpriteSheets)), recolor_textures)
+            .add_systems(
+                Update,
+                (
+                    spawn_ghost_markers,
+                    pulse_frightened_ghosts,
+                ).run_if(in_game),
+            )
+        ;
+    }
+}
+
+/// How long a frightened ghost keeps one size while it pulses.
+const FRIGHTENED_PULSE_TIME: Duration = Duration::from_millis(200);
+/// The size of a pulsing ghost relative to its normal size.
+const FRIGHTENED_PULSE_SCALE: f32 = 1.25;
+
+/// The sprites which show the maze walls in [ARCADE_WALL_COLOR].
+pub(super) const WALL_SPRITES: [SpriteId; 6] = [
+    SpriteId::OUTER_WALL,
+    SpriteId::OUTER_WALL_CORNER,
+    SpriteId::INNER_WALL,
+    SpriteId::INNER_WALL_CORNER,
+    SpriteId::GHOST_HOUSE_WALL,
+    SpriteId::GHOST_HOUSE_WALL_CORNER,
+];
+
+/// Replace the arcade colors in the textures of the ghosts. The textures get changed in place, so this
+/// only happens once while the game starts. The walls get their color when a round starts, as the
+/// ruleset can change it with the level.
+fn recolor_textures(
+    settings: Res<Settings>,
+    theme: Res<Theme>,
+    animation_sheets: Res<AnimationSheets>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let accessibility = &settings.accessibility;
+
+    for ghost in [Blinky, Pinky, Inky, Clyde] {
+        let from = GhostPalette::Arcade.body_color(ghost);
+        let to = accessibility.ghost_palette.body_color(ghost);
+
+        if from == to {
+            continue;
+        }
+
+        let sprites = [Up, Down, Left, Right].map(|direction| SpriteId::ghost(ghost, direction));
+        recolor_sprites(&sprites, from, to, &theme, &animation_sheets, &mut images);
+    }
+}
+
+/// Replace the color `from` with `to` in every frame of the given sprites. Only pixels with exactly this
+/// color change, so sprites of a theme which uses other colors stay as they are. Returns true if any
+/// pixel had the color.
+pub(super) fn recolor_sprites(
+    sprites: &[SpriteId],
+    from: [u8; 3],
+    to: [u8; 3],
+    theme: &Theme,
+    animation_sheets: &AnimationSheets,
+    images: &mut Assets<Image>,
+) -> bool {
+    // frames can be part of several animations, but must only be recolored once
+    let handles = sprites
+        .iter()
+        .flat_map(|sprite| theme.animations(animation_sheets, sprite).images())
+        .map(|handle| handle.id())
+        .collect::<HashSet<_>>();
+
+    let mut recolored = false;
+
+    for handle in handles {
+        if let Some(image) = images.get_mut(handle) {
+            recolored |= replace_color(&mut image.data, from, to);
+        }
+    }
+
+    recolored
+}
+
+fn spawn_ghost_markers(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    theme: Res<Theme>,
+    query: Query<(Entity, &Ghost), Added<Ghost>>,
+) {
+    if !settings.accessibility.ghost_markers {
+        return;
+    }
+
+    for (entity, ghost) in &query {
+        let marker = commands.spawn((
+            Name::new("GhostMarker"),
+            Text2dBundle {
+                text: Text::from_section(ghost_marker(*ghost), theme.text_style(&ColorId::TEXT)),
+                transform: Transform::from_xyz(0.0, GHOST_DIMENSION * 0.75, 1.0),
+                ..default()
+            }
+        )).id();
+
+        commands.entity(entity).add_child(marker);
+    }
+}
+
+/// Let frightened ghosts grow and shrink while the energizer is running out, so the warning does not
+/// depend on telling the colors of the blinking animation apart. Only the ghosts which were grown get
+/// shrunk again, so the scale of every other ghost stays untouched.
+fn pulse_frightened_ghosts(
+    mut grown_ghosts: Local<HashSet<Entity>>,
+    settings: Res<Settings>,
+    clocks: Res<AnimationClocks>,
+    energizer_timer: Option<Res<EnergizerTimer>>,
+    mut query: Query<(Entity, &GhostState, &mut Transform), With<Ghost>>,
+) {
+    let running_out = energizer_timer.is_some_and(|timer| timer.is_running_out());
+
+    for (entity, state, mut transform) in &mut query {
+        let pulsing = settings.accessibility.frightened_warning
+            && running_out
+            && *state == Frightened
+            && clocks.intervals(GHOST_BODY_CLOCK, FRIGHTENED_PULSE_TIME) % 2 == 1;
+
+        if pulsing && grown_ghosts.insert(entity) {
+            transform.scale = Vec3::new(FRIGHTENED_PULSE_SCALE, FRIGHTENED_PULSE_SCALE, 1.0);
+        } else if !pulsing && grown_ghosts.remove(&entity) {
+            transform.scale = Vec3::ONE;
+        }
+    }
+}
@@ -19,9 +19,13 @@ impl Plugin for AnimateWallsPlugin {
     }
 }
 
+/// The flash starts from the beginning, so every wall is in phase.
 fn set_animation_to_blinking(
+    mut clocks: ResMut<AnimationClocks>,
     mut query: Query<&mut Animations, With<Wall>>
 ) {
+    clocks.reset(MAZE_FLASH_CLOCK);
+
     for mut animations in &mut query {
         animations.change_animation_to("blinking")
     }
@@ -0,0 +1,62 @@
+use bevy::audio::{AudioSink, Volume};
+use bevy::prelude::*;
+use crate::core::prelude::*;
+
+/// Applies the bus volumes from the settings to every playing or queued sound.
+///
+/// The background tracks are not handled here, as their volume is controlled by the music mixer.
+pub(super) struct AudioBusPlugin;
+
+impl Plugin for AudioBusPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(Update, (
+                toggle_mute,
+                init_new_sounds,
+                update_sound_volumes.run_if(resource_changed::<Settings>),
+            ).chain())
+        ;
+    }
+}
+
+/// The volume a sound was spawned with, before the bus volume was applied.
+#[derive(Component, Copy, Clone)]
+struct BaseVolume(f32);
+
+/// Mute or unmute every sound with M.
+fn toggle_mute(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<Settings>,
+) {
+    if keyboard_input.just_pressed(KeyCode::KeyM) {
+        settings.muted = !settings.muted;
+    }
+}
+
+/// Remember the original volume of new sounds and apply their bus volume. The sinks are created later
+/// from the playback settings, so the sounds start with the correct volume.
+fn init_new_sounds(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    mut query: Query<(Entity, &AudioBus, &mut PlaybackSettings), (Added<AudioBus>, Without<BackgroundTrack>)>,
+) {
+    for (entity, bus, mut playback_settings) in &mut query {
+        let base_volume = BaseVolume(playback_settings.volume.get());
+        playback_settings.volume = Volume::new(base_volume.0 * settings.bus_volume(*bus));
+        commands.entity(entity).insert(base_volume);
+    }
+}
+
+fn update_sound_volumes(
+    settings: Res<Settings>,
+    mut query: Query<(&AudioBus, &BaseVolume, &mut PlaybackSettings, Option<&AudioSink>)>,
+) {
+    for (bus, base_volume, mut playback_settings, sink_opt) in &mut query {
+        let volume = base_volume.0 * settings.bus_volume(*bus);
+        playback_settings.volume = Volume::new(volume);
+
+        if let Some(sink) = sink_opt {
+            sink.set_volume(volume);
+        }
+    }
+}
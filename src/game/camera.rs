@@ -1,4 +1,6 @@
 use bevy::prelude::*;
+use bevy::render::camera::{ScalingMode, Viewport};
+use bevy::window::PrimaryWindow;
 use crate::core::prelude::*;
 
 pub(super) struct CameraPlugin;
@@ -7,26 +9,124 @@ impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
         app
             .add_systems(OnEnter(Game(Start)), spawn_camera)
+            .add_systems(Update, (
+                fit_camera_to_window.run_if(in_game),
+                toggle_fullscreen
+            ))
             .add_systems(OnExit(Game(GameOver)), despawn_camera)
         ;
     }
 }
 
+/// The area (in world coordinates) the camera must show. This is the maze together with
+/// the rows for the HUD above and beneath it.
+#[derive(Component, Deref)]
+pub struct VisibleArea(Rect);
+
 fn spawn_camera(
     mut commands: Commands,
-    map_query: Query<&Map>,
+    tiles_query: Query<&Tiles>,
 ) {
-    let map = map_query.single();
+    let area = get_visible_area(&tiles_query);
 
     commands.spawn((
         Name::new("GameCamera"),
         Camera2dBundle {
-            transform: Transform::from_translation(Vec3::new((map.width as f32 * FIELD_SIZE) / 2.0, (map.height as f32 * FIELD_SIZE) / 2.0, 1000.0)),
+            transform: Transform::from_translation(area.center().extend(1000.0)),
+            projection: OrthographicProjection {
+                scaling_mode: ScalingMode::Fixed {
+                    width: area.width(),
+                    height: area.height(),
+                },
+                ..Camera2dBundle::default().projection
+            },
             ..default()
         },
+        VisibleArea(area),
     ));
 }
 
+/// Return the bounds of every tile in the map, extended by the HUD rows.
+///
+/// The bounds are calculated from the tiles instead of the map dimensions, so maps of any size
+/// and with any origin fit automatically.
+fn get_visible_area(tiles_query: &Query<&Tiles>) -> Rect {
+    let maze = tiles_query
+        .iter()
+        .flat_map(|tiles| match *tiles {
+            Tiles::Single { pos } => vec![pos],
+            Tiles::Double { pos_a, pos_b } => vec![pos_a, pos_b],
+        })
+        .map(|pos| Rect::from_center_size(pos.to_vec3(0.0).truncate(), FIELD_DIMENSION))
+        .reduce(|acc, rect| acc.union(rect))
+        .expect("the map should have at least one tile");
+
+    Rect::new(
+        maze.min.x,
+        maze.min.y - HUD_BOTTOM_ROWS as f32 * FIELD_SIZE,
+        maze.max.x,
+        maze.max.y + HUD_TOP_ROWS as f32 * FIELD_SIZE,
+    )
+}
+
+/// Letterbox the camera viewport into the window, so the visible area is rendered at the biggest
+/// integer multiple of its native size that still fits (or the scale from the settings, if it fits). The UI gets
+/// scaled by the same factor, so every UI value can be given in native pixels.
+///
+/// If the window is too small to render the native size, the scale becomes fractional.
+fn fit_camera_to_window(
+    settings: Res<Settings>,
+    mut ui_scale: ResMut<UiScale>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut cameras: Query<(&mut Camera, &VisibleArea)>,
+) {
+    let Ok(window) = windows.get_single() else { return; };
+    let window_size = Vec2::new(window.physical_width() as f32, window.physical_height() as f32);
+
+    if window_size.x < 1.0 || window_size.y < 1.0 {
+        return;
+    }
+
+    for (mut camera, area) in &mut cameras {
+        let fit = (window_size / area.size()).min_element();
+        let scale = match settings.scale {
+            Some(scale) if scale >= 1 && scale as f32 <= fit => scale as f32,
+            _ if fit >= 1.0 => fit.floor(),
+            _ => fit
+        };
+        let viewport_size = (area.size() * scale).min(window_size).as_uvec2().max(UVec2::ONE);
+        let viewport_position = (window_size.as_uvec2() - viewport_size) / 2;
+        let new_ui_scale = scale / window.scale_factor();
+
+        let viewport_changed = match camera.viewport {
+            Some(ref viewport) => viewport.physical_size != viewport_size || viewport.physical_position != viewport_position,
+            None => true
+        };
+
+        if viewport_changed {
+            camera.viewport = Some(Viewport {
+                physical_position: viewport_position,
+                physical_size: viewport_size,
+                ..default()
+            });
+        }
+
+        if ui_scale.0 != new_ui_scale {
+            ui_scale.0 = new_ui_scale;
+        }
+    }
+}
+
+/// Switch between windowed and borderless fullscreen mode with F11.
+fn toggle_fullscreen(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<Settings>,
+) {
+    if keyboard_input.just_pressed(KeyCode::F11) {
+        settings.fullscreen = !settings.fullscreen;
+    }
+}
+
 fn despawn_camera(
     mut commands: Commands,
     query: Query<Entity, With<Camera>>
@@ -34,4 +134,4 @@ fn despawn_camera(
     for e in &query {
         commands.entity(e).despawn();
     }
-}
\ No newline at end of file
+}
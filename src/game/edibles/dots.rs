@@ -1,4 +1,3 @@
-use std::time::Duration;
 use bevy::prelude::*;
 
 use crate::core::prelude::*;
@@ -39,10 +38,28 @@ impl Plugin for DotPlugin {
     }
 }
 
+/// Fill the maze with dots. In a practice game, the cleared sections stay empty.
 fn spawn_dots(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    theme: Res<Theme>,
+    practice: Option<Res<CurrentPractice>>,
+    map_query: Query<&Map>,
     spawn_query: Query<&Tiles, With<DotSpawn>>,
+) {
+    let map = map_query.single();
+    let translations = spawn_query
+        .iter()
+        .map(|tiles| tiles.to_vec3(DOT_Z))
+        .filter(|translation| !practice.as_ref().is_some_and(|practice| practice.dot_is_cleared(*translation, map)));
+
+    spawn_dots_at(&mut commands, &theme, translations)
+}
+
+/// Spawn a dot at every given translation.
+pub(in crate::game) fn spawn_dots_at(
+    commands: &mut Commands,
+    theme: &Theme,
+    translations: impl IntoIterator<Item=Vec3>,
 ) {
     let dots = commands.spawn((
         Name::new("Dots"),
@@ -50,16 +67,16 @@ fn spawn_dots(
         SpatialBundle::default()
     )).id();
 
-    for tiles in &spawn_query {
+    for translation in translations {
         commands.entity(dots).with_children(|parent| {
             parent.spawn((
                 SpriteBundle {
-                    texture: asset_server.load("textures/dot.png"),
+                    texture: theme.image(&SpriteId::DOT),
                     sprite: Sprite {
                         custom_size: Some(Vec2::splat(DOT_DIMENSION)),
                         ..default()
                     },
-                    transform: Transform::from_translation(tiles.to_vec3(DOT_Z)),
+                    transform: Transform::from_translation(translation),
                     ..Default::default()
                 },
                 Dot,
@@ -72,76 +89,52 @@ fn spawn_dots(
 
 fn create_eaten_dots(
     mut commands: Commands,
-    dot_spawn_query: Query<&DotSpawn>,
+    practice: Option<Res<CurrentPractice>>,
+    map_query: Query<&Map>,
+    dot_spawn_query: Query<&Tiles, With<DotSpawn>>,
 ) {
     let num_dots = dot_spawn_query.iter().count();
-    commands.insert_resource(EatenDots::new(num_dots))
+    let mut eaten_dots = EatenDots::new(num_dots);
+    eaten_dots.reset_to(num_cleared_dots(&practice, map_query.single(), &dot_spawn_query));
+    commands.insert_resource(eaten_dots)
 }
 
 fn reset_eaten_dots(
-    mut eaten_dots: ResMut<EatenDots>
+    mut eaten_dots: ResMut<EatenDots>,
+    practice: Option<Res<CurrentPractice>>,
+    map_query: Query<&Map>,
+    dot_spawn_query: Query<&Tiles, With<DotSpawn>>,
 ) {
-    eaten_dots.reset()
+    eaten_dots.reset_to(num_cleared_dots(&practice, map_query.single(), &dot_spawn_query))
+}
+
+/// The dots of the sections a practice game clears count as eaten, so the fruits and Blinky's
+/// speed-ups come as early as if pacman ate them.
+fn num_cleared_dots(
+    practice: &Option<Res<CurrentPractice>>,
+    map: &Map,
+    dot_spawn_query: &Query<&Tiles, With<DotSpawn>>,
+) -> usize {
+    let Some(practice) = practice else {
+        return 0;
+    };
+
+    dot_spawn_query
+        .iter()
+        .filter(|tiles| practice.dot_is_cleared(tiles.to_vec3(DOT_Z), map))
+        .count()
 }
 
 /// Play the famous waka waka when a dot was eaten.
 ///
-/// This code sucks, but I have no other way to do it. The problem is: If I would
-/// just play the waka every time a dot was eaten, the sound would overlap. I have no
-/// information if the sound finished playing, so I use a custom timer, which is set
-/// to the time of the track (0.3 seconds). Another waka can play when the timer finished.
-///
-/// But this leads to another problem: The waka makes a pause if another dot was eaten while
-/// the timer is still active. So I cache a waka if the dot was eaten while the timer is active.
-/// When the timer finishes and a waka is cached, it is instantly played and the timer gets reset.
-/// (This might lead to an additional waka playing, but more waka waka = more fun)
+/// The waka would overlap if it played for every dot, so the registry queues it instead. If
+/// a dot gets eaten while the waka plays, the next one plays right after.
 fn play_waka_when_dot_was_eaten(
-    mut commands: Commands,
-    time: Res<Time>,
-    mut waka_timer: Local<Option<Timer>>,
-    mut cached: Local<bool>,
-    asset_server: Res<AssetServer>,
     mut event_reader: EventReader<DotWasEaten>,
+    mut event_writer: EventWriter<PlaySound>,
 ) {
-    if let Some(ref mut timer) = *waka_timer {
-        timer.tick(time.delta());
-
-        if timer.finished() {
-            if *cached {
-                timer.reset();
-
-                commands.spawn((
-                    Name::new("WakaSound"),
-                    SoundEffect::new(1),
-                    AudioBundle {
-                        source: asset_server.load("sounds/waka.ogg"),
-                        ..default()
-                    }
-                ));
-
-                *cached = false;
-            } else {
-                *waka_timer = None
-            }
-        }
-    }
-
     for _ in event_reader.read() {
-        match *waka_timer {
-            Some(_) => *cached = true,
-            None => {
-                *waka_timer = Some(Timer::new(Duration::from_secs_f32(0.3), TimerMode::Once));
-
-                commands.spawn((
-                    Name::new("WakaSound"),
-                    SoundEffect::new(1),
-                    AudioBundle {
-                        source: asset_server.load("sounds/waka.ogg"),
-                        ..default()
-                    }
-                ));
-            }
-        };
+        event_writer.send(PlaySound(SoundId::WAKA));
     }
 }
 
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use bevy::prelude::*;
 use crate::core::prelude::*;
 
@@ -48,10 +50,28 @@ impl Plugin for EnergizerPlugin {
     }
 }
 
+/// Put the energizers in the maze. In a practice game, the cleared sections stay empty.
 fn spawn_energizer(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    theme: Res<Theme>,
+    practice: Option<Res<CurrentPractice>>,
+    map_query: Query<&Map>,
     spawners: Query<&Tiles, With<EnergizerSpawn>>,
+) {
+    let map = map_query.single();
+    let translations = spawners
+        .iter()
+        .map(|tiles| tiles.to_vec3(ENERGIZER_Z))
+        .filter(|translation| !practice.as_ref().is_some_and(|practice| practice.energizer_is_cleared(*translation, map)));
+
+    spawn_energizers_at(&mut commands, &theme, translations)
+}
+
+/// Spawn an energizer at every given translation.
+pub(in crate::game) fn spawn_energizers_at(
+    commands: &mut Commands,
+    theme: &Theme,
+    translations: impl IntoIterator<Item=Vec3>,
 ) {
     let energizers = commands.spawn((
         Name::new("Energizers"),
@@ -59,16 +79,16 @@ fn spawn_energizer(
         SpatialBundle::default()
     )).id();
 
-    for tiles in &spawners {
+    for translation in translations {
         commands.entity(energizers).with_children(|parent| {
             parent.spawn((
                 SpriteBundle {
-                    texture: asset_server.load("textures/energizer.png"),
+                    texture: theme.image(&SpriteId::ENERGIZER),
                     sprite: Sprite {
                         custom_size: Some(Vec2::splat(ENERGIZER_DIMENSION)),
                         ..default()
                     },
-                    transform: Transform::from_translation(tiles.to_vec3(ENERGIZER_Z)),
+                    transform: Transform::from_translation(translation),
                     ..Default::default()
                 },
                 Energizer,
@@ -122,29 +142,41 @@ fn despawn_energizers(
     }
 }
 
-#[derive(Deref, DerefMut)]
-struct EnergizerAnimationTimer(Timer);
-
-impl Default for EnergizerAnimationTimer {
-    fn default() -> Self {
-        EnergizerAnimationTimer(Timer::from_seconds(0.15, TimerMode::Repeating))
-    }
-}
+/// How long the energizers are visible or hidden while blinking.
+const ENERGIZER_BLINK_TIME: Duration = Duration::from_millis(150);
 
 /// Let energizers blink like in the real game
 fn animate_energizers(
-    time: Res<Time>,
-    mut timer: Local<EnergizerAnimationTimer>,
+    clocks: Res<AnimationClocks>,
     mut query: Query<&mut Visibility, With<Energizers>>,
 ) {
-    timer.tick(time.delta());
-
-    if timer.just_finished() {
-        for mut vis in &mut query {
-            *vis = match *vis {
-                Visibility::Visible => Visibility::Hidden,
-                _ => Visibility::Visible
-            }
-        }
+    let visibility = match clocks.intervals(ENERGIZER_BLINK_CLOCK, ENERGIZER_BLINK_TIME) % 2 {
+        0 => Visibility::Visible,
+        _ => Visibility::Hidden,
+    };
+
+    for mut vis in &mut query {
+        vis.set_if_neq(visibility);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::prelude::*;
+    use crate::scenario::Scenario;
+
+    #[test]
+    fn the_ghosts_are_frightened_for_the_time_of_the_level() {
+        let mut scenario = Scenario::new();
+        scenario
+            .level(4)
+            .place_pacman(Pos::new(1, 27), Up)
+            .hold(Up)
+            .advance_until(60, |s| s.count::<EnergizerWasEaten>() > 0)
+            .advance(1);
+
+        let frightened_time = scenario.resource::<SpecsPerLevel>().get_for(&Level(4)).frightened_time;
+        let remaining = scenario.resource::<EnergizerTimer>().remaining();
+        assert!(remaining <= frightened_time && remaining > frightened_time - 0.1);
     }
-}
\ No newline at end of file
+}
@@ -1,4 +1,9 @@
+use std::collections::VecDeque;
+use std::f32::consts::PI;
+
+use bevy::ecs::system::EntityCommands;
 use bevy::prelude::*;
+use bevy::sprite::Anchor;
 use crate::core::prelude::*;
 
 pub struct FruitPlugin;
@@ -15,7 +20,8 @@ impl Plugin for FruitPlugin {
                     despawn_fruit_if_timer_exceeded,
                     play_fruit_eaten_sound_when_fruit_was_eaten
                         .in_set(ProcessIntersectionsWithPacman),
-                    reset_fruit_despawn_timer_when_level_changed
+                    reset_fruit_despawn_timer_when_level_changed,
+                    move_fruit,
                 )
                     .run_if(in_state(Game(Running))),
             )
@@ -35,43 +41,159 @@ impl Plugin for FruitPlugin {
     }
 }
 
-/// Spawn a fruit for the current level when a specific amount of dots
-/// was eaten.
+/// How fast a fruit walks through the maze in the Ms. Pac-Man ruleset.
+const MOVING_FRUIT_SPEED: f32 = PACMAN_BASE_SPEED * 0.5;
+/// How high a walking fruit bounces, relative to its size.
+const MOVING_FRUIT_BOUNCE: f32 = 0.25;
+
+/// A fruit which walks through the maze on its own, like in Ms. Pac-Man. It enters through a tunnel,
+/// passes the fruit spawn and leaves through another tunnel.
+#[derive(Component)]
+struct MovingFruit {
+    /// The tiles the fruit still has to walk to, in order
+    path: VecDeque<Pos>,
+    /// How far the fruit has walked, which sets the height of its bounce
+    walked: f32,
+}
+
+/// Spawn a fruit for the current level when the fruit table of the map
+/// says that enough dots were eaten.
 fn spawn_fruit_when_dot_limit_reached(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    theme: Res<Theme>,
     level: Res<Level>,
+    ruleset: Res<CurrentRuleset>,
+    mut random: ResMut<Random>,
     eaten_dots: Res<EatenDots>,
-    specs_per_level: Res<SpecsPerLevel>,
+    walkable_tiles: Option<Res<WalkableTiles>>,
     mut event_reader: EventReader<DotWasEaten>,
+    fruit_tables: Query<&FruitTable>,
     spawners: Query<&Tiles, With<FruitSpawn>>,
+    tunnels: Query<&Tiles, With<Tunnel>>,
 ) {
-    let num_eaten_dots = eaten_dots.get_eaten();
+    let fruit_table = fruit_tables.single();
 
     for _ in event_reader.read() {
-        if let 70 | 170 = num_eaten_dots {
-            for tiles in &spawners {
-                let fruit = specs_per_level.get_for(&level).fruit_to_spawn;
-                commands.spawn((
-                    Name::new("Fruit"),
-                    SpriteBundle {
-                        texture: get_texture_for_fruit(&fruit, &asset_server),
-                        sprite: Sprite {
-                            custom_size: Some(Vec2::splat(FRUIT_DIMENSION)),
-                            ..default()
-                        },
-                        transform: Transform::from_translation(tiles.to_vec3(FRUIT_Z)),
-                        ..Default::default()
-                    },
-                    fruit,
-                    Edible
-                ));
+        if fruit_table.for_level(&level).appears_at(&eaten_dots) {
+            let fruit = ruleset.fruit_for_level(&level, fruit_table, &mut random);
+
+            match **ruleset {
+                Ruleset::Arcade => {
+                    for tiles in &spawners {
+                        spawn_fruit(&mut commands, &theme, &fruit, tiles.to_vec3(FRUIT_Z));
+                    }
+                    commands.insert_resource(FruitDespawnTimer::new(fruit.lifetime));
+                }
+                Ruleset::MsPacman => {
+                    let Some(walkable_tiles) = walkable_tiles.as_deref() else {
+                        warn!("the maze has no walkable tiles, so no fruit can walk through it");
+                        continue;
+                    };
+                    let tunnels = tunnels.iter().map(|tiles| tiles.to_pos()).collect::<Vec<_>>();
+
+                    for tiles in &spawners {
+                        let Some(path) = moving_fruit_path(walkable_tiles, &mut random, &tunnels, tiles) else {
+                            warn!("no fruit can walk through the maze, as it has no tunnel or the fruit spawn cannot be reached from one");
+                            continue;
+                        };
+
+                        spawn_fruit(&mut commands, &theme, &fruit, path[0].to_vec3(FRUIT_Z))
+                            .insert(MovingFruit { path, walked: 0.0 });
+                    }
+                }
             }
-            commands.insert_resource(FruitDespawnTimer::new());
         }
     }
 }
 
+fn spawn_fruit<'a>(
+    commands: &'a mut Commands,
+    theme: &Theme,
+    fruit: &FruitLevel,
+    coordinates: Vec3,
+) -> EntityCommands<'a> {
+    commands.spawn((
+        Name::new("Fruit"),
+        SpriteBundle {
+            texture: theme.image(&fruit.sprite()),
+            sprite: Sprite {
+                custom_size: Some(Vec2::splat(FRUIT_DIMENSION)),
+                ..default()
+            },
+            transform: Transform::from_translation(coordinates),
+            ..Default::default()
+        },
+        fruit.fruit,
+        FruitPoints(fruit.points),
+        Edible
+    ))
+}
+
+/// The way of a walking fruit: from a random tunnel over the fruit spawn to another random tunnel.
+/// The first tile is the one the fruit spawns on. None if the maze has no tunnel or the fruit spawn
+/// cannot be reached.
+fn moving_fruit_path(
+    walkable_tiles: &WalkableTiles,
+    random: &mut Random,
+    tunnels: &[Pos],
+    spawner: &Tiles,
+) -> Option<VecDeque<Pos>> {
+    if tunnels.is_empty() {
+        return None;
+    }
+
+    let entry = tunnels[random.zero_to(tunnels.len())];
+    let exits = tunnels.iter().filter(|tunnel| **tunnel != entry).copied().collect::<Vec<_>>();
+    let exit = match exits.is_empty() {
+        true => entry,
+        false => exits[random.zero_to(exits.len())],
+    };
+    let spawn = Pos::from_vec3(spawner.to_vec3(FRUIT_Z));
+
+    let mut path = VecDeque::from([entry]);
+    path.extend(walkable_tiles.path(entry, spawn)?);
+    path.extend(walkable_tiles.path(spawn, exit)?);
+    Some(path)
+}
+
+/// Let walking fruits follow their path and bounce while they walk. A fruit disappears when it
+/// reaches the end of its path. A step to a tile which is no neighbour leads through a tunnel.
+fn move_fruit(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut MovingFruit, &mut Transform, &mut Sprite)>,
+) {
+    for (entity, mut fruit, mut transform, mut sprite) in &mut query {
+        let mut distance = MOVING_FRUIT_SPEED * time.delta_seconds();
+        fruit.walked += distance;
+
+        while distance > 0.0 {
+            let Some(next) = fruit.path.front().copied() else {
+                commands.entity(entity).despawn();
+                break;
+            };
+            let target = next.to_vec3(transform.translation.z);
+            let offset = target - transform.translation;
+
+            if Pos::from_vec3(transform.translation).distance(&next) > 1.0 {
+                transform.translation = target;
+                fruit.path.pop_front();
+            } else if offset.length() <= distance {
+                transform.translation = target;
+                distance -= offset.length();
+                fruit.path.pop_front();
+            } else {
+                transform.translation += offset.normalize() * distance;
+                distance = 0.0;
+            }
+        }
+
+        // the anchor moves the picture, not the fruit, so pacman still eats it on the tile it walks on
+        let bounce = (fruit.walked / FIELD_SIZE * PI).sin().abs() * MOVING_FRUIT_BOUNCE;
+        sprite.anchor = Anchor::Custom(Vec2::new(0.0, -bounce));
+    }
+}
+
 /// Update the despawn timer with delta time.
 fn update_despawn_timer(
     time: Res<Time>,
@@ -120,18 +242,51 @@ fn despawn_fruit_and_timer(
 }
 
 fn play_fruit_eaten_sound_when_fruit_was_eaten(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
     mut event_reader: EventReader<FruitWasEaten>,
+    mut event_writer: EventWriter<PlaySound>,
 ) {
     for _ in event_reader.read() {
-        commands.spawn((
-            Name::new("FruitEatenSound"),
-            SoundEffect::new(1),
-            AudioBundle {
-                source: asset_server.load("sounds/fruit_eaten.ogg"),
-                ..default()
+        event_writer.send(PlaySound(SoundId::FRUIT_EATEN));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::prelude::*;
+    use crate::scenario::Scenario;
+
+    #[test]
+    fn the_fruit_of_ms_pacman_walks_from_a_tunnel_over_the_fruit_spawn_to_a_tunnel() {
+        let tunnels = [Pos::new(0, 17), Pos::new(27, 17)];
+        let fruit_spawn = [Pos::new(13, 14), Pos::new(14, 14)];
+        let mut scenario = Scenario::with_settings(Settings {
+            ruleset: Ruleset::MsPacman,
+            muted: true,
+            ..Settings::default()
+        });
+        scenario.resource_mut::<EatenDots>().reset_to(69);
+        // the ghosts stay where they start, so pacman survives the walk of the fruit
+        scenario
+            .freeze_ghosts()
+            .place_pacman(Pos::new(5, 26), Right);
+
+        let mut fruit_tiles = Vec::<Pos>::new();
+
+        for _ in 0..60 * 30 {
+            scenario.advance(1);
+
+            match scenario.entity_tile::<Fruit>() {
+                Some(tile) if fruit_tiles.last() != Some(&tile) => fruit_tiles.push(tile),
+                None if !fruit_tiles.is_empty() => break,
+                _ => {}
             }
-        ));
+        }
+
+        assert!(tunnels.contains(&fruit_tiles[0]));
+        assert!(fruit_tiles.iter().any(|tile| fruit_spawn.contains(tile)));
+        assert!(tunnels.contains(fruit_tiles.last().unwrap()));
+        assert_eq!(scenario.count_entities::<Fruit>(), 0);
+        assert_eq!(scenario.count::<FruitWasEaten>(), 0);
+        assert_eq!(scenario.state(), Game(Running));
     }
-}
\ No newline at end of file
+}
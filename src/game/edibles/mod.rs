@@ -30,4 +30,42 @@ fn check_if_all_edibles_eaten(
     if query.iter().count() == 0 {
         event_writer.send(EAllEdiblesEaten);
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::prelude::*;
+    use crate::scenario::Scenario;
+
+    #[test]
+    fn the_level_ends_when_the_last_dot_gets_eaten() {
+        let mut scenario = Scenario::new();
+        scenario
+            .remove_edibles_except(&[Pos::new(5, 26)])
+            .place_pacman(Pos::new(3, 26), Right)
+            .hold(Right)
+            .advance_until(60, |s| s.count::<EAllEdiblesEaten>() > 0)
+            .advance_until(5, |s| s.state() == Game(LevelTransition));
+
+        assert_eq!(scenario.count::<DotWasEaten>(), 1);
+    }
+
+    #[test]
+    fn two_pacmen_on_one_dot_eat_it_only_once() {
+        let mut scenario = Scenario::with_settings(Settings {
+            game_mode: GameMode::Coop { shared_lives: false },
+            muted: true,
+            ..Settings::default()
+        });
+        // the pacmen may already have eaten some dots on their way out of the start
+        let dots_before = scenario.count::<DotWasEaten>();
+        let eaten_before = scenario.resource::<EatenDots>().get_eaten();
+        scenario
+            .remove_edibles_except(&[Pos::new(5, 26), Pos::new(20, 26)])
+            .place_pacman(Pos::new(5, 26), Right)
+            .advance(1);
+
+        assert_eq!(scenario.count::<DotWasEaten>(), dots_before + 1);
+        assert_eq!(scenario.resource::<EatenDots>().get_eaten(), eaten_before + 1);
+    }
+}
@@ -35,19 +35,25 @@ fn update_state(
     current_state: Res<State<GameState>>,
     mut next_state: ResMut<NextState<GameState>>,
     lives: Res<Lives>,
+    other_player: Option<Res<OtherPlayer>>,
     state_timer: Option<Res<StateTimer>>,
     pacman_hit_events: EventReader<PacmanWasHit>,
     edibles_eaten_events: EventReader<EAllEdiblesEaten>,
     ghost_eaten_events: EventReader<GhostWasEaten>,
     game_restartet_events: EventReader<GameWasRestarted>,
+    animation_finished_events: EventReader<AnimationFinished>,
+    saved_game: Option<Res<SavedGame>>,
+    pacman_query: Query<(), With<Pacman>>,
 ) {
     match current_state.get() {
+        // a resumed game skips the start and restarts the level right away
+        Game(Start) if saved_game.is_some() => next_state.set(Game(Ready)),
         Game(Start) => switch_when_timer_finished(&mut commands, &state_timer, &mut next_state, 2.0, Game(Ready)),
         Game(Ready) => switch_when_timer_finished(&mut commands, &state_timer, &mut next_state, 2.5, Game(Running)),
         Game(Running) => switch_states_based_on_events(&mut next_state, pacman_hit_events, edibles_eaten_events, ghost_eaten_events),
         Game(PacmanHit) => switch_when_timer_finished(&mut commands, &state_timer, &mut next_state, 1.0, Game(PacmanDying)),
-        Game(PacmanDying) => switch_when_timer_finished(&mut commands, &state_timer, &mut next_state, 1.5, Game(PacmanDead)),
-        Game(PacmanDead) => switch_to_ready_or_game_over(&mut commands, &state_timer, &lives, &mut next_state),
+        Game(PacmanDying) => switch_when_pacman_died(&mut commands, &state_timer, &mut next_state, 3.0, animation_finished_events, &pacman_query),
+        Game(PacmanDead) => switch_to_ready_or_game_over(&mut commands, &state_timer, &lives, &other_player, &mut next_state),
         Game(GameOver) => switch_to_start_after_game_over(&mut next_state, game_restartet_events),
         Game(LevelTransition) => switch_when_timer_finished(&mut commands, &state_timer, &mut next_state, 3.0, Game(Ready)),
         Game(GhostEatenPause) => switch_when_timer_finished(&mut commands, &state_timer, &mut next_state, 1.0, Game(Running)),
@@ -71,17 +77,44 @@ fn switch_when_timer_finished(
     }
 }
 
+/// Pacman is dead when his dying animation is over. If the animation never finishes (like a theme
+/// without a dying animation), pacman is dead when the timer runs out.
+///
+/// The timer deliberately guesses how long dying takes again. It is only a fallback, so a missing or
+/// broken animation cannot keep the game in this state forever.
+fn switch_when_pacman_died(
+    commands: &mut Commands,
+    state_timer: &Option<Res<StateTimer>>,
+    game_state: &mut NextState<GameState>,
+    time: f32,
+    mut animation_finished_events: EventReader<AnimationFinished>,
+    pacman_query: &Query<(), With<Pacman>>,
+) {
+    let animation_finished = animation_finished_events.read().any(|event| event.name == "dying" && pacman_query.contains(event.entity));
+    let timer_finished = state_timer.as_ref().is_some_and(|timer| timer.finished());
+
+    if animation_finished || timer_finished {
+        commands.remove_resource::<StateTimer>();
+        game_state.set(Game(PacmanDead))
+    } else if state_timer.is_none() {
+        commands.insert_resource(StateTimer(Timer::from_seconds(time, TimerMode::Once)))
+    }
+}
+
+/// Continue the game if the active player or the other player (in a two player game) has lives left.
 fn switch_to_ready_or_game_over(
     commands: &mut Commands,
     state_timer: &Option<Res<StateTimer>>,
     lives: &Lives,
+    other_player: &Option<Res<OtherPlayer>>,
     game_state: &mut NextState<GameState>,
 ) {
     match state_timer {
         Some(timer) => if timer.finished() {
             commands.remove_resource::<StateTimer>();
+            let other_player_can_play = other_player.as_ref().is_some_and(|other| other.0.can_play());
 
-            if **lives > 0 {
+            if **lives > 0 || other_player_can_play {
                 game_state.set(Game(Ready))
             } else {
                 game_state.set(Game(GameOver))
@@ -109,7 +142,6 @@ fn switch_states_based_on_events(
 
     if ghost_eaten_events.read().count() > 0 {
         game_state.set(Game(GhostEatenPause));
-        return;
     }
 }
 
@@ -129,4 +161,23 @@ fn update_state_timer(
     if let Some(mut timer) = state_timer {
         timer.tick(time.delta());
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::prelude::*;
+    use crate::scenario::Scenario;
+
+    #[test]
+    fn pacman_is_dead_when_his_dying_animation_is_over() {
+        let mut scenario = Scenario::new();
+        scenario
+            .pacman_runs_into(Blinky)
+            .advance_until(200, |s| s.state() == Game(PacmanDying));
+
+        assert_eq!(scenario.count::<AnimationFinished>(), 0);
+
+        scenario.advance_until(200, |s| s.state() == Game(PacmanDead));
+        assert_eq!(scenario.count::<AnimationFinished>(), 1);
+    }
+}
@@ -57,3 +57,17 @@ fn switch_to_global_counter_when_pacman_got_killed(
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use crate::core::prelude::*;
+    use crate::scenario::Scenario;
+
+    #[test]
+    fn pinky_leaves_the_house_at_once_while_inky_waits() {
+        let mut scenario = Scenario::new();
+        scenario.advance_until(3 * 60, |s| s.ghost_state(Pinky) != Spawned);
+
+        assert_eq!(scenario.ghost_state(Inky), Spawned);
+        assert!(scenario.ghost_tile(Pinky).y() > scenario.ghost_tile(Inky).y());
+    }
+}
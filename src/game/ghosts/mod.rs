@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use crate::game::ghosts::movement::MovePlugin;
+use crate::game::ghosts::player_controlled::PlayerControlledPlugin;
 use crate::game::ghosts::spawn::spawn_ghosts;
 use crate::game::ghosts::textures::{start_ghost_animation, update_ghost_appearance};
 
@@ -7,6 +8,7 @@ use crate::core::prelude::*;
 use crate::game::move_through_tunnel::GhostPassedTunnel;
 
 pub mod movement;
+pub mod player_controlled;
 pub mod spawn;
 pub(crate) mod textures;
 
@@ -15,7 +17,7 @@ pub(in crate::game) struct GhostPlugin;
 impl Plugin for GhostPlugin {
     fn build(&self, app: &mut App) {
         app
-            .add_plugins(MovePlugin)
+            .add_plugins((MovePlugin, PlayerControlledPlugin))
             .add_systems(
                 OnEnter(Game(Ready)),
                 spawn_ghosts,
@@ -77,7 +79,7 @@ fn despawn_ghosts(
     query: Query<Entity, With<Ghost>>,
 ) {
     for entity in query.iter() {
-        commands.entity(entity).despawn();
+        commands.entity(entity).despawn_recursive();
     }
 }
 
@@ -110,18 +112,10 @@ fn set_currently_eaten_ghost_visible(
 }
 
 fn play_ghost_eaten_sound_when_ghost_was_eaten(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
     mut event_reader: EventReader<GhostWasEaten>,
+    mut event_writer: EventWriter<PlaySound>,
 ) {
     if event_reader.read().count() > 0 {
-        commands.spawn((
-            Name::new("GhostEatenSound"),
-            SoundEffect::new(1),
-            AudioBundle {
-                source: asset_server.load("sounds/ghost_eaten.ogg"),
-                ..default()
-            }
-        ));
+        event_writer.send(PlaySound(SoundId::GHOST_EATEN));
     }
 }
\ No newline at end of file
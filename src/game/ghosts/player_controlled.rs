@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+use bevy::time::common_conditions::paused;
+use crate::core::prelude::*;
+
+/// Lets player two steer a ghost in a versus game. The ghost still follows the rules of its state,
+/// so the player only decides where it turns while chasing or scattering.
+pub struct PlayerControlledPlugin;
+
+impl Plugin for PlayerControlledPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(
+                Update,
+                buffer_player_input_for_ghost
+                    .before(SetTarget)
+                    .run_if(in_state(Game(Running)))
+                    .run_if(not(paused)),
+            )
+        ;
+    }
+}
+
+/// Remember the direction player two pressed last. The ghost turns to it at the next intersection
+/// where it is allowed to.
+fn buffer_player_input_for_ghost(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    settings: Res<Settings>,
+    mut query: Query<&mut PlayerControlledGhost>,
+) {
+    let Some(dir) = settings.controls_player_two.pressed_direction(&keyboard_input) else {
+        return;
+    };
+
+    for mut player_controlled in &mut query {
+        player_controlled.wished_direction = Some(dir);
+    }
+}
@@ -1,32 +1,36 @@
 use bevy::prelude::*;
-use bevy_sprite_sheet::SpriteSheets;
 use crate::game::ghosts::textures::create_animations_for_ghost;
 use crate::core::prelude::*;
 
 pub fn spawn_ghosts(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    sprite_sheets: Res<SpriteSheets>,
+    theme: Res<Theme>,
+    animation_sheets: Res<AnimationSheets>,
     level: Res<Level>,
     specs_per_level: Res<SpecsPerLevel>,
+    game_mode: Res<CurrentGameMode>,
     spawn_query: Query<&GhostSpawn>,
 ) {
     for spawn in &spawn_query {
-        spawn_ghost(&mut commands, spawn, &asset_server, &sprite_sheets, &level, &specs_per_level);
+        let ghost = spawn_ghost(&mut commands, spawn, &theme, &animation_sheets, &level, &specs_per_level);
+
+        if game_mode.player_controlled_ghost() == Some(spawn.ghost) {
+            commands.entity(ghost).insert(PlayerControlledGhost::default());
+        }
     }
 }
 
 fn spawn_ghost(
     commands: &mut Commands,
     spawn: &GhostSpawn,
-    asset_server: &AssetServer,
-    sprite_sheets: &SpriteSheets,
+    theme: &Theme,
+    animation_sheets: &AnimationSheets,
     level: &Level,
     specs_per_level: &SpecsPerLevel,
-) {
+) -> Entity {
     let spawn_direction = spawn.spawn_direction;
     let spawn_coordinates = spawn.coordinates;
-    let mut animations = create_animations_for_ghost(&spawn.ghost, asset_server, sprite_sheets);
+    let mut animations = create_animations_for_ghost(&spawn.ghost, theme, animation_sheets);
     animations.change_animation_to(match spawn.spawn_direction {
         Up => "normal_up",
         Down => "normal_down",
@@ -53,5 +57,5 @@ fn spawn_ghost(
         Target::new(),
         Spawned,
         animations
-    ));
+    )).id()
 }
\ No newline at end of file
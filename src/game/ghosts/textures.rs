@@ -1,5 +1,4 @@
 use bevy::prelude::*;
-use bevy_sprite_sheet::{SpriteSheet, SpriteSheets};
 use crate::core::prelude::*;
 
 pub(crate) fn update_ghost_appearance(
@@ -10,7 +9,7 @@ pub(crate) fn update_ghost_appearance(
         match state {
             Frightened => match energizer_timer {
                 // animate a frightened ghost differently if the energizer timer is almost ending
-                Some(ref timer) if timer.remaining() < 2.0 => animations.change_animation_to("frightened_blinking"),
+                Some(ref timer) if timer.is_running_out() => animations.change_animation_to("frightened_blinking"),
                 _ => animations.change_animation_to("frightened"),
             },
             Eaten => {
@@ -31,62 +30,25 @@ pub(crate) fn update_ghost_appearance(
     }
 }
 
-pub(crate) fn create_animations_for_ghost(ghost: &Ghost, asset_server: &AssetServer, sprite_sheets: &SpriteSheets) -> Animations {
-    match *ghost {
-        Blinky => create_animations_for(asset_server, sprite_sheets, ["textures/ghost/blinky_up", "textures/ghost/blinky_down", "textures/ghost/blinky_left", "textures/ghost/blinky_right"]),
-        Pinky => create_animations_for(asset_server, sprite_sheets, ["textures/ghost/pinky_up", "textures/ghost/pinky_down", "textures/ghost/pinky_left", "textures/ghost/pinky_right"]),
-        Inky => create_animations_for(asset_server, sprite_sheets, ["textures/ghost/inky_up", "textures/ghost/inky_down", "textures/ghost/inky_left", "textures/ghost/inky_right"]),
-        Clyde => create_animations_for(asset_server, sprite_sheets, ["textures/ghost/clyde_up", "textures/ghost/clyde_down", "textures/ghost/clyde_left", "textures/ghost/clyde_right"]),
-    }
-}
+pub(crate) fn create_animations_for_ghost(ghost: &Ghost, theme: &Theme, animation_sheets: &AnimationSheets) -> Animations {
+    let normal_animation = |direction| theme.animations(animation_sheets, &SpriteId::ghost(*ghost, direction)).untagged();
+    let eaten_animation = |direction| Animation::from_texture(theme.image(&SpriteId::eaten_ghost(direction)));
 
-fn create_animations_for(
-    asset_server: &AssetServer,
-    sprite_sheets: &SpriteSheets,
-    normal_animation_keys: [&'static str; 4]
-) -> Animations {
     Animations::new(
         [
-            ("normal_up", create_normal_animation(sprite_sheets.get_sheet(normal_animation_keys[0]))),
-            ("normal_down", create_normal_animation(sprite_sheets.get_sheet(normal_animation_keys[1]))),
-            ("normal_left", create_normal_animation(sprite_sheets.get_sheet(normal_animation_keys[2]))),
-            ("normal_right", create_normal_animation(sprite_sheets.get_sheet(normal_animation_keys[3]))),
-            ("eaten_up", create_eaten_animation(asset_server, "textures/ghost/eaten_up.png")),
-            ("eaten_down", create_eaten_animation(asset_server, "textures/ghost/eaten_down.png")),
-            ("eaten_left", create_eaten_animation(asset_server, "textures/ghost/eaten_left.png")),
-            ("eaten_right", create_eaten_animation(asset_server, "textures/ghost/eaten_right.png")),
-            ("frightened", create_frightened_animation(sprite_sheets.get_sheet("textures/ghost/frightened"))),
-            ("frightened_blinking", create_frightened_blinking_animation(sprite_sheets.get_sheet("textures/ghost/frightened_blinking"))),
+            ("normal_up", normal_animation(Up)),
+            ("normal_down", normal_animation(Down)),
+            ("normal_left", normal_animation(Left)),
+            ("normal_right", normal_animation(Right)),
+            ("eaten_up", eaten_animation(Up)),
+            ("eaten_down", eaten_animation(Down)),
+            ("eaten_left", eaten_animation(Left)),
+            ("eaten_right", eaten_animation(Right)),
+            ("frightened", theme.animations(animation_sheets, &SpriteId::FRIGHTENED).untagged()),
+            ("frightened_blinking", theme.animations(animation_sheets, &SpriteId::FRIGHTENED_BLINKING).untagged()),
         ],
         "normal_left")
-}
-
-fn create_normal_animation(sprite_sheet: &SpriteSheet) -> Animation {
-    Animation::from_textures(
-        0.5,
-        true,
-        sprite_sheet.images_at(0..2),
-    )
-}
-
-fn create_eaten_animation(asset_server: &AssetServer, key: &'static str) -> Animation {
-    Animation::from_texture(asset_server.load(key))
-}
-
-fn create_frightened_animation(sprite_sheet: &SpriteSheet) -> Animation {
-    Animation::from_textures(
-        0.5,
-        true,
-        sprite_sheet.images_at(0..2)
-    )
-}
-
-fn create_frightened_blinking_animation(sprite_sheet: &SpriteSheet) -> Animation {
-    Animation::from_textures(
-        0.5,
-        true,
-        sprite_sheet.images_at(0..4)
-    )
+        .with_clock(GHOST_BODY_CLOCK)
 }
 
 /// The ghosts start with stopped animations. Restart them here
@@ -25,24 +25,21 @@ fn pacman_hits_ghost(
     mut commands: Commands,
     mut killed_event_writer: EventWriter<PacmanWasHit>,
     mut eat_event_writer: EventWriter<GhostWasEaten>,
-    pacman_query: Query<&Transform, With<Pacman>>,
+    pacman_query: Query<(Entity, &Transform), With<Pacman>>,
     ghost_query: Query<(Entity, &Transform, &GhostState), With<Ghost>>,
 ) {
-    for pacman_transform in &pacman_query {
-        for (entity, ghost_transform, state) in &ghost_query {
-            let pacman_pos = Pos::from_vec3(pacman_transform.translation);
-            let ghost_pos = Pos::from_vec3(ghost_transform.translation);
+    for (entity, ghost_transform, state) in &ghost_query {
+        let ghost_pos = Pos::from_vec3(ghost_transform.translation);
 
-            if pacman_pos == ghost_pos {
-                if let Scatter | Chase = state {
-                    killed_event_writer.send(PacmanWasHit);
-                }
-
-                if let Frightened = state {
-                    eat_event_writer.send(GhostWasEaten(entity, *ghost_transform));
-                    commands.insert_resource(CurrentlyEatenGhost(entity))
-                }
-            }
+        match state {
+            Scatter | Chase => for pacman in pacmen_on(ghost_pos, &pacman_query) {
+                killed_event_writer.send(PacmanWasHit(pacman, entity));
+            },
+            Frightened => if let Some(&pacman) = pacmen_on(ghost_pos, &pacman_query).first() {
+                eat_event_writer.send(GhostWasEaten(entity, *ghost_transform, pacman));
+                commands.insert_resource(CurrentlyEatenGhost(entity))
+            },
+            _ => ()
         }
     }
 }
@@ -51,19 +48,16 @@ fn pacman_eat_dot(
     mut commands: Commands,
     mut event_writer: EventWriter<DotWasEaten>,
     mut eaten_dots: ResMut<EatenDots>,
-    pacman_positions: Query<&Transform, With<Pacman>>,
+    pacman_positions: Query<(Entity, &Transform), With<Pacman>>,
     dot_positions: Query<(Entity, &Transform), With<Dot>>,
 ) {
-    for pacman_tf in &pacman_positions {
-        for (entity, dot_tf) in &dot_positions {
-            let pacman_pos = Pos::from_vec3(pacman_tf.translation);
-            let dot_pos = Pos::from_vec3(dot_tf.translation);
+    for (entity, dot_tf) in &dot_positions {
+        let dot_pos = Pos::from_vec3(dot_tf.translation);
 
-            if pacman_pos == dot_pos {
-                commands.entity(entity).despawn();
-                eaten_dots.increment();
-                event_writer.send(DotWasEaten);
-            }
+        if let Some(&pacman) = pacmen_on(dot_pos, &pacman_positions).first() {
+            commands.entity(entity).despawn();
+            eaten_dots.increment();
+            event_writer.send(DotWasEaten(pacman));
         }
     }
 }
@@ -71,18 +65,15 @@ fn pacman_eat_dot(
 fn pacman_eat_energizer(
     mut commands: Commands,
     mut event_writer: EventWriter<EnergizerWasEaten>,
-    pacman_positions: Query<&Transform, With<Pacman>>,
+    pacman_positions: Query<(Entity, &Transform), With<Pacman>>,
     energizer_positions: Query<(Entity, &Transform), With<Energizer>>,
 ) {
-    for pacman_transform in &pacman_positions {
-        for (energizer_entity, energizer_transform) in &energizer_positions {
-            let energizer_pos = Pos::from_vec3(energizer_transform.translation);
-            let pacman_pos = Pos::from_vec3(pacman_transform.translation);
+    for (energizer_entity, energizer_transform) in &energizer_positions {
+        let energizer_pos = Pos::from_vec3(energizer_transform.translation);
 
-            if energizer_pos == pacman_pos {
-                commands.entity(energizer_entity).despawn();
-                event_writer.send(EnergizerWasEaten);
-            }
+        if let Some(&pacman) = pacmen_on(energizer_pos, &pacman_positions).first() {
+            commands.entity(energizer_entity).despawn();
+            event_writer.send(EnergizerWasEaten(pacman));
         }
     }
 }
@@ -91,19 +82,25 @@ fn pacman_eat_energizer(
 fn eat_fruit_when_pacman_touches_it(
     mut commands: Commands,
     mut event_writer: EventWriter<FruitWasEaten>,
-    pacman_query: Query<&Transform, With<Pacman>>,
-    fruit_query: Query<(Entity, &Fruit, &Transform)>,
+    pacman_query: Query<(Entity, &Transform), With<Pacman>>,
+    fruit_query: Query<(Entity, &Fruit, &FruitPoints, &Transform)>,
 ) {
-    for pacman_tf in &pacman_query {
-        for (entity, fruit, fruit_tf) in &fruit_query {
-            let pacman_pos = Pos::from_vec3(pacman_tf.translation);
-            let fruit_pos = Pos::from_vec3(fruit_tf.translation);
+    for (entity, fruit, points, fruit_tf) in &fruit_query {
+        let fruit_pos = Pos::from_vec3(fruit_tf.translation);
 
-            if pacman_pos == fruit_pos {
-                commands.entity(entity).despawn();
-                commands.remove_resource::<FruitDespawnTimer>();
-                event_writer.send(FruitWasEaten(*fruit, *fruit_tf));
-            }
+        if let Some(&pacman) = pacmen_on(fruit_pos, &pacman_query).first() {
+            commands.entity(entity).despawn();
+            commands.remove_resource::<FruitDespawnTimer>();
+            event_writer.send(FruitWasEaten(*fruit, *fruit_tf, pacman, **points));
         }
     }
-}
\ No newline at end of file
+}
+
+/// All pacmen on the given tile. If two pacmen share a tile, only the first one gets to eat what lies there.
+fn pacmen_on(pos: Pos, pacman_query: &Query<(Entity, &Transform), With<Pacman>>) -> Vec<Entity> {
+    pacman_query
+        .iter()
+        .filter(move |(_, tf)| Pos::from_vec3(tf.translation) == pos)
+        .map(|(pacman, _)| pacman)
+        .collect()
+}
@@ -7,6 +7,7 @@ impl Plugin for LevelPlugin {
     fn build(&self, app: &mut App) {
         app
             .insert_resource(Level(1))
+            .add_systems(Startup, reset_level)
             .add_systems(
                 OnExit(Game(LevelTransition)),
                 increase_level
@@ -25,8 +26,10 @@ fn increase_level(
     level.increase();
 }
 
+/// Reset the level to the one the next game starts in. This is the first one, unless a practice game starts.
 fn reset_level(
-    mut level: ResMut<Level>
+    settings: Res<Settings>,
+    mut level: ResMut<Level>,
 ) {
-    level.0 = 1;
+    level.0 = settings.starting_level();
 }
\ No newline at end of file
@@ -6,8 +6,9 @@ pub(super) struct LivesPlugin;
 impl Plugin for LivesPlugin {
     fn build(&self, app: &mut App) {
         app
-            .insert_resource(Lives(3))
-            .insert_resource(PointsRequiredForExtraLife::new())
+            .init_resource::<Lives>()
+            .insert_resource(PointsRequiredForExtraLife::new(0))
+            .add_systems(Startup, reset_lives)
             .add_systems(
                 Update,
                 (
@@ -23,29 +24,76 @@ impl Plugin for LivesPlugin {
     }
 }
 
+/// Remove a life from the player whose pacman was hit. In a co-op game with shared lives, the
+/// life is always removed from the shared lives. A practice game can have infinite lives.
 fn remove_life_when_pacman_dies(
     mut event_reader: EventReader<PacmanWasHit>,
-    mut lives: ResMut<Lives>,
+    lives: ResMut<Lives>,
+    game_mode: Res<CurrentGameMode>,
+    active_player: Res<ActivePlayer>,
+    other_player: Option<ResMut<OtherPlayer>>,
+    practice: Option<Res<CurrentPractice>>,
+    pacman_query: Query<&Player, With<Pacman>>,
 ) {
-    if event_reader.read().count() > 0 && **lives > 0 {
+    // when both pacmen are hit at the same time, only one life gets lost
+    let Some(PacmanWasHit(pacman, _)) = event_reader.read().next() else {
+        return;
+    };
+
+    if practice.is_some_and(|practice| practice.infinite_lives) {
+        return;
+    }
+
+    let player = pacman_query.get(*pacman).copied().unwrap_or(**active_player);
+    let shared_lives = matches!(**game_mode, GameMode::Coop { shared_lives: true });
+
+    let lives = match other_player {
+        Some(other_player) if player != **active_player && !shared_lives => &mut other_player.into_inner().0.lives,
+        _ => lives.into_inner()
+    };
+
+    if **lives > 0 {
         **lives -= 1;
     }
 }
 
+/// Give the active player an extra life if they reached the required score. In a co-op game, the other player
+/// can earn extra lives at the same time.
 fn add_life_if_player_reaches_specific_score(
     score: Res<Score>,
+    game_mode: Res<CurrentGameMode>,
     mut lives: ResMut<Lives>,
     mut points_required_for_extra_life: ResMut<PointsRequiredForExtraLife>,
+    other_player: Option<ResMut<OtherPlayer>>,
 ) {
-    if **score >= **points_required_for_extra_life {
+    if points_required_for_extra_life.extra_lives_enabled() && **score >= **points_required_for_extra_life {
         **lives += 1;
         points_required_for_extra_life.increase_limit();
     }
+
+    let Some(mut other_player) = other_player else {
+        return;
+    };
+
+    let GameMode::Coop { shared_lives } = **game_mode else {
+        return;
+    };
+
+    if other_player.0.reached_extra_life() {
+        match shared_lives {
+            true => **lives += 1,
+            false => *other_player.0.lives += 1,
+        }
+    }
 }
 
+/// Reset the lives and the extra life limit to the values from the settings.
 fn reset_lives(
-    mut lives: ResMut<Lives>
+    settings: Res<Settings>,
+    mut lives: ResMut<Lives>,
+    mut points_required_for_extra_life: ResMut<PointsRequiredForExtraLife>,
 ) {
-    lives.0 = 3;
+    lives.0 = settings.lives_at_start();
+    *points_required_for_extra_life = PointsRequiredForExtraLife::new(settings.extra_life_threshold);
 }
 
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use crate::game::accessibility::AccessibilityPlugin;
 use crate::game::edibles::EdiblePlugin;
 use crate::game::ghost_house_gate::GhostHouseGatePlugin;
 use crate::game::ghosts::GhostPlugin;
@@ -6,15 +7,20 @@ use crate::game::interactions::InteractionsPlugin;
 use crate::game::level::LevelPlugin;
 use crate::game::lives::LivesPlugin;
 use crate::game::animate_walls::AnimateWallsPlugin;
+use crate::game::audio_bus::AudioBusPlugin;
 use crate::game::pacman::PacmanPlugin;
+use crate::game::players::PlayersPlugin;
+use crate::game::practice::PracticePlugin;
 use crate::core::random::RandomPlugin;
 use crate::game::camera::CameraPlugin;
 use crate::game::game_state_transition::GameStateTransitionPlugin;
 use crate::game::move_through_tunnel::MoveThroughTunnelPlugin;
 use crate::game::music::MusicPlugin;
 use crate::game::restart_game::RestartGamePlugin;
+use crate::game::ruleset::RulesetPlugin;
 use crate::game::schedule::SchedulePlugin;
 use crate::game::score::ScorePlugin;
+use crate::game::settings::SettingsPlugin;
 use crate::game::sound_effect::SoundEffectPlugin;
 use crate::game::specs_per_level::SpecsPerLevelPlugin;
 use crate::game::speed::SpeedPlugin;
@@ -40,9 +46,16 @@ mod move_through_tunnel;
 pub mod game_state_transition;
 pub mod sound_effect;
 pub mod music;
+mod audio_bus;
 pub mod camera;
 mod restart_game;
+mod settings;
+mod players;
+mod practice;
+pub mod save_game;
 mod ui;
+mod accessibility;
+mod ruleset;
 
 /// Contains the entire gameplay logic for pacman.
 pub struct GamePlugin;
@@ -69,11 +82,17 @@ impl Plugin for GamePlugin {
             ))
             .add_plugins((
                 CameraPlugin,
+                PlayersPlugin,
+                PracticePlugin,
                 MoveThroughTunnelPlugin,
                 GameStateTransitionPlugin,
                 SoundEffectPlugin,
                 MusicPlugin,
+                AudioBusPlugin,
                 RestartGamePlugin,
+                SettingsPlugin,
+                AccessibilityPlugin,
+                RulesetPlugin,
                 UIPlugin
             ))
         ;
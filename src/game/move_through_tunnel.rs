@@ -68,4 +68,23 @@ fn move_ghost_trough_tunnel(
             }
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::prelude::*;
+    use crate::scenario::Scenario;
+
+    #[test]
+    fn pacman_comes_out_of_the_other_tunnel() {
+        let mut scenario = Scenario::new();
+        scenario
+            .place_pacman(Pos::new(2, 17), Left)
+            .hold(Left)
+            .advance(1)
+            .release()
+            .advance_until(60, |s| s.pacman_tile().x() > 20);
+
+        assert_eq!(scenario.pacman_direction(), Left);
+    }
+}
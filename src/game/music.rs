@@ -1,7 +1,6 @@
 use bevy::audio::{AudioSink, Volume};
 use bevy::prelude::*;
 use crate::core::prelude::*;
-use CurrentTrack::*;
 
 pub struct MusicPlugin;
 
@@ -35,17 +34,9 @@ impl Plugin for MusicPlugin {
 }
 
 fn play_start_sound(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    mut event_writer: EventWriter<PlaySound>,
 ) {
-    commands.spawn((
-        Name::new("StartSound"),
-        SoundEffect::new(5),
-        AudioBundle {
-            source: asset_server.load("sounds/start.ogg"),
-            ..default()
-        }
-    ));
+    event_writer.send(PlaySound(SoundId::START));
 }
 
 /// Starts every background track at the same time with volume of 0.
@@ -67,6 +58,7 @@ fn start_background_track(
     commands.spawn((
         Name::new(path),
         BackgroundTrack,
+        AudioBus::Music,
         marker,
         AudioBundle {
             source: loaded_assets.load(path),
@@ -113,11 +105,12 @@ fn update_background_music(
 
 fn play_track(
     background_music: Res<BackgroundMusic>,
+    settings: Res<Settings>,
     siren_tracks: Query<&AudioSink, With<SirenBackground>>,
     frightened_tracks: Query<&AudioSink, With<FrightenedBackground>>,
     eaten_tracks: Query<&AudioSink, With<EatenBackground>>,
 ) {
-    if !background_music.is_changed() {
+    if !background_music.is_changed() && !settings.is_changed() {
         return;
     }
 
@@ -126,7 +119,7 @@ fn play_track(
         frightened_tracks.get_single(),
         eaten_tracks.get_single()
     ) {
-        (Ok(siren), Ok(frightened), Ok(eaten)) => Mixer::new(siren, frightened, eaten),
+        (Ok(siren), Ok(frightened), Ok(eaten)) => Mixer::new(siren, frightened, eaten, settings.bus_volume(AudioBus::Music)),
         _ => return
     };
 
@@ -145,15 +138,18 @@ fn play_track(
     }
 }
 
+/// Plays one of the background tracks and silences the others. The playing track
+/// gets the volume of the music bus.
 struct Mixer<'a> {
     siren_track: &'a AudioSink,
     frightened_track: &'a AudioSink,
     eaten_track: &'a AudioSink,
+    volume: f32,
 }
 
 impl<'a> Mixer<'a> {
-    pub fn new(siren_track: &'a AudioSink, frightened_track: &'a AudioSink, eaten_track: &'a AudioSink) -> Self {
-        Self { siren_track, frightened_track, eaten_track }
+    pub fn new(siren_track: &'a AudioSink, frightened_track: &'a AudioSink, eaten_track: &'a AudioSink, volume: f32) -> Self {
+        Self { siren_track, frightened_track, eaten_track, volume }
     }
 
     fn play_siren_1(self) {
@@ -173,7 +169,7 @@ impl<'a> Mixer<'a> {
     }
 
     fn play_siren(self, speed: f32) {
-        self.siren_track.set_volume(1.0);
+        self.siren_track.set_volume(self.volume);
         self.siren_track.set_speed(speed);
         self.frightened_track.set_volume(0.0);
         self.eaten_track.set_volume(0.0);
@@ -181,14 +177,14 @@ impl<'a> Mixer<'a> {
 
     fn play_frightened(self) {
         self.siren_track.set_volume(0.0);
-        self.frightened_track.set_volume(1.0);
+        self.frightened_track.set_volume(self.volume);
         self.eaten_track.set_volume(0.0);
     }
 
     fn play_eaten(self) {
         self.siren_track.set_volume(0.0);
         self.frightened_track.set_volume(0.0);
-        self.eaten_track.set_volume(1.0);
+        self.eaten_track.set_volume(self.volume);
     }
 
     fn mute_all(self) {
@@ -0,0 +1,179 @@
+use crate::core::prelude::*;
+use crate::game::pacman::agents::{Maze, PredictedGhost};
+use crate::game::target::{calculate_chase_target, get_nearest_neighbour_to};
+
+/// How many fields the agent plays ahead.
+const DEPTH: usize = 10;
+
+/// Points lost when pacman gets caught. Far more than any path can score, so dying is always the worst option.
+const DEATH_PENALTY: f32 = 100_000.0;
+
+/// Points which come later count a little less, so pacman eats the near dots first.
+const DISCOUNT: f32 = 0.95;
+
+/// One possible future of the game, one field at a time. The search plays every future in the same
+/// prediction and undoes each move when it goes back, so it allocates nothing per field.
+struct Prediction {
+    pacman_position: Pos,
+    pacman_direction: Dir,
+    ghosts: Vec<PredictedGhost>,
+    /// The edibles already eaten in this prediction.
+    eaten: Vec<Pos>,
+    /// The ghosts as they were before every move on the way to the current field, to undo the moves.
+    ghost_history: Vec<PredictedGhost>,
+}
+
+/// Try every way pacman can go for the next few fields and return the direction of the best one.
+///
+/// The ghosts are predicted with the same rules they follow in the game: they chase pacman with their own
+/// technique or head for their corner. Frightened ghosts move randomly, so they are expected to stay where they are.
+/// Pacman and the ghosts are assumed to move one field at the same time.
+pub(super) fn decide(maze: &Maze, pacman_position: Pos, pacman_direction: Dir) -> Option<Dir> {
+    let mut prediction = Prediction {
+        pacman_position,
+        pacman_direction,
+        ghosts: maze.ghosts.clone(),
+        eaten: Vec::with_capacity(DEPTH + 1),
+        ghost_history: Vec::with_capacity(maze.ghosts.len() * (DEPTH + 1)),
+    };
+
+    // pacman may turn around on the first field, but not while playing ahead, as this only wastes moves
+    maze.pacman_moves(pacman_position)
+        .map(|(pos, dir)| (dir, evaluate(maze, &mut prediction, pos, dir, DEPTH)))
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(dir, _)| dir)
+}
+
+/// Return the (discounted) points pacman scores when he moves to the given field and plays on from there.
+/// The prediction is the same as before when this returns.
+fn evaluate(maze: &Maze, prediction: &mut Prediction, pos: Pos, dir: Dir, depth: usize) -> f32 {
+    let previous_position = prediction.pacman_position;
+    let previous_direction = prediction.pacman_direction;
+    let history_len = prediction.ghost_history.len();
+    let eaten_len = prediction.eaten.len();
+
+    prediction.ghost_history.extend_from_slice(&prediction.ghosts);
+    prediction.pacman_position = pos;
+    prediction.pacman_direction = dir;
+
+    let points = play_on(maze, prediction, previous_position, history_len, depth);
+
+    prediction.pacman_position = previous_position;
+    prediction.pacman_direction = previous_direction;
+    prediction.ghosts.copy_from_slice(&prediction.ghost_history[history_len..]);
+    prediction.ghost_history.truncate(history_len);
+    prediction.eaten.truncate(eaten_len);
+
+    points
+}
+
+/// Move the ghosts after pacman moved and evaluate the futures from his new field. The ghosts before the
+/// move start at the given index of the ghost history.
+fn play_on(maze: &Maze, prediction: &mut Prediction, previous_position: Pos, history_len: usize, depth: usize) -> f32 {
+    let pos = prediction.pacman_position;
+    let dir = prediction.pacman_direction;
+
+    if is_caught(prediction) {
+        return -DEATH_PENALTY;
+    }
+
+    move_ghosts(maze, prediction);
+
+    let pacman_walked_through_ghost = prediction.ghosts
+        .iter()
+        .zip(&prediction.ghost_history[history_len..])
+        .any(|(ghost, before)| ghost.is_dangerous() && before.position == pos && ghost.position == previous_position);
+
+    if pacman_walked_through_ghost || is_caught(prediction) {
+        return -DEATH_PENALTY;
+    }
+
+    let points = eat(maze, prediction);
+
+    if depth == 0 {
+        return points - distance_to_nearest_edible(maze, prediction);
+    }
+
+    let opposite_dir = dir.opposite();
+    let dead_end = maze.pacman_moves(pos).all(|(_, d)| d == opposite_dir);
+    let mut best_future = f32::MIN;
+
+    for (next_pos, next_dir) in maze.pacman_moves(pos) {
+        if next_dir != opposite_dir || dead_end {
+            best_future = best_future.max(evaluate(maze, prediction, next_pos, next_dir, depth - 1));
+        }
+    }
+
+    points + DISCOUNT * best_future
+}
+
+/// Tells if a dangerous ghost is on pacmans field.
+fn is_caught(prediction: &Prediction) -> bool {
+    prediction.ghosts
+        .iter()
+        .any(|ghost| ghost.is_dangerous() && ghost.position == prediction.pacman_position)
+}
+
+/// Move every dangerous ghost one field towards its target.
+fn move_ghosts(maze: &Maze, prediction: &mut Prediction) {
+    let blinky_position = prediction.ghosts
+        .iter()
+        .find(|ghost| ghost.ghost == Blinky)
+        .map(|ghost| ghost.position)
+        .unwrap_or(prediction.pacman_position);
+
+    for ghost in prediction.ghosts.iter_mut().filter(|ghost| ghost.is_dangerous()) {
+        let Some(corner) = maze.corners.get(&ghost.ghost).copied() else {
+            continue;
+        };
+
+        let target = match ghost.state {
+            Chase => calculate_chase_target(
+                ghost.ghost,
+                ghost.position.to_vec3(0.0),
+                prediction.pacman_position,
+                prediction.pacman_direction,
+                blinky_position,
+                corner,
+            ),
+            _ => corner,
+        };
+
+        let (position, direction) = get_nearest_neighbour_to(ghost.position, ghost.direction, target, &maze.walls, &maze.one_ways);
+        ghost.position = position;
+        ghost.direction = direction;
+    }
+}
+
+/// Let pacman eat whatever is on his field and return the points for it.
+fn eat(maze: &Maze, prediction: &mut Prediction) -> f32 {
+    let pos = prediction.pacman_position;
+    let mut points = 0;
+
+    if !prediction.eaten.contains(&pos) {
+        if let Some(edible_points) = maze.edibles.get(&pos) {
+            points += edible_points;
+            prediction.eaten.push(pos);
+        }
+    }
+
+    for ghost in prediction.ghosts.iter_mut().filter(|ghost| ghost.state == Frightened && ghost.position == pos) {
+        ghost.state = Eaten;
+        points += POINTS_PER_GHOST;
+    }
+
+    points as f32
+}
+
+/// The manhattan distance to the nearest edible which is not eaten yet. Leads pacman to the next dots
+/// when none are within the search depth.
+fn distance_to_nearest_edible(maze: &Maze, prediction: &Prediction) -> f32 {
+    let pos = prediction.pacman_position;
+
+    maze.edibles
+        .keys()
+        .filter(|edible| !prediction.eaten.contains(edible))
+        .map(|edible| (edible.x() - pos.x()).abs() + (edible.y() - pos.y()).abs())
+        .min()
+        .unwrap_or_default() as f32
+}
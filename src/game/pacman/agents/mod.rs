@@ -0,0 +1,278 @@
+use std::collections::VecDeque;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+
+use crate::core::prelude::*;
+use crate::game::pacman::movement::InputBuffer;
+
+mod lookahead;
+
+/// How many fields a ghost can reach before pacman comes too close to it. Used by the ghost avoiding agent.
+const DANGER_DISTANCE: usize = 3;
+
+/// Let the AI agents decide where their pacman goes next.
+///
+/// An agent decides once every time its pacman enters a new field (or runs into a wall) and writes the
+/// direction into the input buffer, like a buffered key press. Turning at the right moment is left to
+/// [set_direction_based_on_keyboard_input](super::movement::set_direction_based_on_keyboard_input), so
+/// agents move exactly like a player.
+pub(in crate::game) fn set_direction_based_on_agent(
+    mut last_decisions: Local<HashMap<Entity, Pos>>,
+    maze_queries: MazeQueries,
+    mut pacman_query: Query<(Entity, &Transform, &Dir, &PacmanController, &mut InputBuffer), With<Pacman>>,
+) {
+    last_decisions.retain(|entity, _| pacman_query.contains(*entity));
+
+    if pacman_query.iter().all(|(_, _, _, controller, _)| !controller.is_agent()) {
+        return;
+    }
+
+    let maze = maze_queries.snapshot();
+
+    for (entity, transform, dir, controller, mut input_buffer) in &mut pacman_query {
+        if !controller.is_agent() {
+            continue;
+        }
+
+        let position = Pos::from_vec3(transform.translation);
+        let blocked = maze.is_blocked(position.neighbour_in_direction(*dir));
+
+        if last_decisions.get(&entity) == Some(&position) && !blocked {
+            continue;
+        }
+
+        let decision = match controller {
+            PacmanController::Keyboard => None,
+            PacmanController::Greedy => maze.path_to_nearest_edible(position, &HashSet::new()),
+            PacmanController::AvoidGhosts => avoid_ghosts(&maze, position),
+            PacmanController::Lookahead => lookahead::decide(&maze, position, *dir),
+        };
+
+        last_decisions.insert(entity, position);
+
+        if let Some(dir) = decision {
+            input_buffer.0 = Some(dir);
+        }
+    }
+}
+
+/// Go for the nearest edible which can be reached without passing a ghost. If every way is dangerous,
+/// flee from the nearest ghost.
+fn avoid_ghosts(maze: &Maze, position: Pos) -> Option<Dir> {
+    let danger_zone = maze.danger_zone();
+
+    maze.path_to_nearest_edible(position, &danger_zone).or_else(|| maze.flee(position))
+}
+
+#[derive(SystemParam)]
+pub(in crate::game) struct MazeQueries<'w, 's> {
+    map_query: Query<'w, 's, &'static Map>,
+    wall_query: Query<'w, 's, &'static Transform, With<Wall>>,
+    ghost_house_query: Query<'w, 's, &'static Tiles, With<GhostHouseArea>>,
+    one_way_query: Query<'w, 's, &'static Tiles, With<OneWay>>,
+    corner_query: Query<'w, 's, (&'static GhostCorner, &'static Tiles)>,
+    dot_query: Query<'w, 's, &'static Transform, With<Dot>>,
+    energizer_query: Query<'w, 's, &'static Transform, With<Energizer>>,
+    fruit_query: Query<'w, 's, (&'static Transform, &'static FruitPoints)>,
+    ghost_query: Query<'w, 's, (&'static Transform, &'static Ghost, &'static Dir, &'static GhostState)>,
+}
+
+impl MazeQueries<'_, '_> {
+    fn snapshot(&self) -> Maze {
+        let map = self.map_query.single();
+        let walls = self.wall_query.iter().map(|transform| Pos::from_vec3(transform.translation)).collect::<HashSet<_>>();
+        let ghost_house = self.ghost_house_query.iter().map(|tiles| tiles.to_pos());
+
+        let edibles = self.dot_query.iter().map(|transform| (transform, POINTS_PER_DOT))
+            .chain(self.energizer_query.iter().map(|transform| (transform, POINTS_PER_ENERGIZER)))
+            .chain(self.fruit_query.iter().map(|(transform, points)| (transform, **points)))
+            .map(|(transform, points)| (Pos::from_vec3(transform.translation), points))
+            .collect();
+
+        let ghosts = self.ghost_query
+            .iter()
+            .map(|(transform, ghost, dir, state)| PredictedGhost {
+                ghost: *ghost,
+                position: Pos::from_vec3(transform.translation),
+                direction: *dir,
+                state: *state,
+            })
+            .collect();
+
+        Maze {
+            width: map.width as isize,
+            height: map.height as isize,
+            blocked: walls.iter().copied().chain(ghost_house).collect(),
+            walls,
+            one_ways: self.one_way_query.iter().map(|tiles| tiles.to_pos()).collect(),
+            corners: self.corner_query.iter().map(|(corner, tiles)| (**corner, tiles.to_pos())).collect(),
+            edibles,
+            ghosts,
+        }
+    }
+}
+
+/// Everything an agent knows about the maze when it decides where pacman goes next.
+struct Maze {
+    width: isize,
+    height: isize,
+    /// The fields pacman cannot enter: the walls and the ghost house.
+    blocked: HashSet<Pos>,
+    /// The walls only, as the ghosts may enter their house.
+    walls: HashSet<Pos>,
+    one_ways: HashSet<Pos>,
+    corners: HashMap<Ghost, Pos>,
+    /// The points of every edible by its position.
+    edibles: HashMap<Pos, usize>,
+    ghosts: Vec<PredictedGhost>,
+}
+
+/// Where a ghost is (or is expected to be) and what it does.
+#[derive(Copy, Clone, Debug)]
+struct PredictedGhost {
+    ghost: Ghost,
+    position: Pos,
+    direction: Dir,
+    state: GhostState,
+}
+
+impl PredictedGhost {
+    /// Tells if the ghost kills pacman when they meet.
+    fn is_dangerous(&self) -> bool {
+        matches!(self.state, Chase | Scatter)
+    }
+}
+
+impl Maze {
+    fn is_blocked(&self, pos: Pos) -> bool {
+        // pacman leaves the maze only for a moment in a tunnel, which an agent cannot plan with
+        pos.x() < 0 || pos.y() < 0 || pos.x() >= self.width || pos.y() >= self.height || self.blocked.contains(&pos)
+    }
+
+    /// The fields pacman can move to from the given position.
+    fn pacman_moves(&self, pos: Pos) -> impl Iterator<Item=(Pos, Dir)> + '_ {
+        pos.neighbours_with_directions()
+            .into_iter()
+            .filter(|(neighbour, _)| !self.is_blocked(*neighbour))
+    }
+
+    /// Search the shortest way to the nearest edible with a breadth first search and return the first
+    /// direction of it. The avoided fields are never entered.
+    fn path_to_nearest_edible(&self, start: Pos, avoided: &HashSet<Pos>) -> Option<Dir> {
+        let mut first_directions = HashMap::from([(start, None)]);
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(pos) = queue.pop_front() {
+            let first_direction = first_directions[&pos];
+
+            if pos != start && self.edibles.contains_key(&pos) {
+                return first_direction;
+            }
+
+            for (neighbour, dir) in self.pacman_moves(pos) {
+                if avoided.contains(&neighbour) || first_directions.contains_key(&neighbour) {
+                    continue;
+                }
+
+                first_directions.insert(neighbour, first_direction.or(Some(dir)));
+                queue.push_back(neighbour);
+            }
+        }
+
+        None
+    }
+
+    /// Every field a dangerous ghost can reach within [DANGER_DISTANCE] moves.
+    fn danger_zone(&self) -> HashSet<Pos> {
+        let mut zone = HashSet::new();
+
+        for ghost in self.ghosts.iter().filter(|ghost| ghost.is_dangerous()) {
+            let mut frontier = vec![ghost.position];
+            zone.insert(ghost.position);
+
+            for _ in 0..DANGER_DISTANCE {
+                frontier = frontier
+                    .into_iter()
+                    .flat_map(|pos| self.pacman_moves(pos).map(|(neighbour, _)| neighbour).collect::<Vec<_>>())
+                    .filter(|pos| zone.insert(*pos))
+                    .collect();
+            }
+        }
+
+        zone
+    }
+
+    /// Return the direction which leads farthest away from the nearest dangerous ghost.
+    fn flee(&self, pos: Pos) -> Option<Dir> {
+        self.pacman_moves(pos)
+            .max_by(|(a, _), (b, _)| self.distance_to_nearest_danger(a).total_cmp(&self.distance_to_nearest_danger(b)))
+            .map(|(_, dir)| dir)
+    }
+
+    fn distance_to_nearest_danger(&self, pos: &Pos) -> f32 {
+        self.ghosts
+            .iter()
+            .filter(|ghost| ghost.is_dangerous())
+            .map(|ghost| ghost.position.distance(pos))
+            .fold(f32::MAX, f32::min)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::prelude::*;
+    use crate::scenario::Scenario;
+
+    fn scenario_with(controller: PacmanController) -> Scenario {
+        Scenario::with_settings(Settings {
+            game_mode: GameMode::SinglePlayer,
+            pacman_controller: controller,
+            muted: true,
+            ..Settings::default()
+        })
+    }
+
+    #[test]
+    fn the_greedy_agent_turns_around_for_the_dot_behind_pacman() {
+        let mut scenario = scenario_with(PacmanController::Greedy);
+        scenario
+            .remove_edibles_except(&[Pos::new(3, 26)])
+            .place_pacman(Pos::new(4, 26), Right)
+            .advance_until(30, |s| s.count::<DotWasEaten>() == 1);
+    }
+
+    /// Pacman and Blinky meet in the bottom corridor, with a dot between them and another one behind pacman.
+    fn escape_from_blinky(controller: PacmanController) {
+        let mut scenario = scenario_with(controller);
+        scenario
+            .remove_edibles_except(&[Pos::new(8, 2), Pos::new(1, 2)])
+            .place_pacman(Pos::new(6, 2), Right)
+            .place_ghost(Blinky, Pos::new(10, 2), Left, Scatter)
+            .advance(30);
+
+        assert_eq!(scenario.count::<PacmanWasHit>(), 0);
+        assert_eq!(scenario.pacman_direction(), Left);
+        assert!(scenario.pacman_tile().x() < 6);
+    }
+
+    #[test]
+    fn the_ghost_avoiding_agent_turns_away_from_a_ghost_in_the_corridor() {
+        escape_from_blinky(PacmanController::AvoidGhosts);
+    }
+
+    #[test]
+    fn the_lookahead_agent_turns_away_from_a_ghost_in_the_corridor() {
+        escape_from_blinky(PacmanController::Lookahead);
+    }
+
+    #[test]
+    fn the_lookahead_agent_eats_the_dot_next_to_pacman() {
+        let mut scenario = scenario_with(PacmanController::Lookahead);
+        scenario
+            .remove_edibles_except(&[Pos::new(3, 26)])
+            .place_pacman(Pos::new(4, 26), Right)
+            .advance_until(30, |s| s.count::<DotWasEaten>() == 1);
+    }
+}
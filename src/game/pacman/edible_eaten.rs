@@ -28,8 +28,8 @@ fn add_edible_stop_when_dot_eaten(
     mut event_reader: EventReader<DotWasEaten>,
     query: Query<Entity, With<Pacman>>,
 ) {
-    for _ in event_reader.read() {
-        for e in &query {
+    for DotWasEaten(pacman) in event_reader.read() {
+        if let Ok(e) = query.get(*pacman) {
             commands.entity(e).insert(EdibleEatenStop(Timer::new(Duration::from_secs_f32(1.0 / 60.0), TimerMode::Once)));
         }
     }
@@ -40,8 +40,8 @@ fn add_edible_stop_when_energizer_eaten(
     mut event_reader: EventReader<EnergizerWasEaten>,
     query: Query<Entity, With<Pacman>>,
 ) {
-    for _ in event_reader.read() {
-        for e in &query {
+    for EnergizerWasEaten(pacman) in event_reader.read() {
+        if let Ok(e) = query.get(*pacman) {
             commands.entity(e).insert(EdibleEatenStop(Timer::new(Duration::from_secs_f32(3.0 / 60.0), TimerMode::Once)));
         }
     }
@@ -1,11 +1,14 @@
 use bevy::prelude::*;
+use bevy::time::common_conditions::paused;
+use crate::game::pacman::agents::set_direction_based_on_agent;
 use crate::game::pacman::edible_eaten::EdibleEatenPlugin;
-use crate::game::pacman::movement::{InputBuffer, move_pacman, reset_input_buffer, set_direction_based_on_keyboard_input};
+use crate::game::pacman::movement::{move_pacman, reset_input_buffer, set_direction_based_on_keyboard_input};
 use crate::game::pacman::spawn::spawn_pacman;
 use crate::game::pacman::textures::{start_pacman_animation, update_pacman_appearance};
 
 use crate::core::prelude::*;
 
+mod agents;
 mod movement;
 mod spawn;
 pub(crate) mod textures;
@@ -17,12 +20,12 @@ impl Plugin for PacmanPlugin {
     fn build(&self, app: &mut App) {
         app
             .add_plugins(EdibleEatenPlugin)
-            .insert_resource(InputBuffer(None))
             .add_systems(OnEnter(Game(Ready)), spawn_pacman)
             .add_systems(OnEnter(Game(Running)), start_pacman_animation)
             .add_systems(Update, (
                 move_pacman,
-                set_direction_based_on_keyboard_input,
+                set_direction_based_on_agent.before(set_direction_based_on_keyboard_input).run_if(not(paused)),
+                set_direction_based_on_keyboard_input.run_if(not(paused)),
                 update_pacman_appearance.after(set_direction_based_on_keyboard_input)
             ).run_if(in_state(Game(Running))))
             .add_systems(OnEnter(Game(PacmanHit)), (
@@ -63,17 +66,9 @@ fn play_the_dying_animation(
 }
 
 fn play_the_dying_sound(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    mut event_writer: EventWriter<PlaySound>,
 ) {
-    commands.spawn((
-        Name::new("PacmanDyingSound"),
-        SoundEffect::new(4),
-        AudioBundle {
-            source: asset_server.load("sounds/dying.ogg"),
-            ..default()
-        }
-    ));
+    event_writer.send(PlaySound(SoundId::DYING));
 }
 
 fn despawn_pacman(
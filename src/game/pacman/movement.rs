@@ -57,13 +57,18 @@ fn get_modifiers_for_direction(direction: &Dir) -> (f32, f32) {
 
 pub(in crate::game) fn set_direction_based_on_keyboard_input(
     keyboard_input: Res<ButtonInput<KeyCode>>,
-    mut input_buffer: ResMut<InputBuffer>,
-    mut pacman_query: Query<(&Transform, &mut Dir), With<Pacman>>,
+    settings: Res<Settings>,
+    game_mode: Res<CurrentGameMode>,
+    mut pacman_query: Query<(&Transform, &mut Dir, &mut InputBuffer, &Player, &PacmanController), With<Pacman>>,
     wall_query: Query<&Transform, With<Wall>>
 ) {
-    for (transform, mut direction) in &mut pacman_query {
+    for (transform, mut direction, mut input_buffer, player, controller) in &mut pacman_query {
         let position = Pos::from_vec3(transform.translation);
-        let wished_direction = get_wished_direction(&keyboard_input, &input_buffer);
+        // an agent does not press keys, it writes its decision in the input buffer instead
+        let wished_direction = match controller.is_agent() {
+            true => **input_buffer,
+            false => get_wished_direction(&keyboard_input, &get_key_bindings(&settings, &game_mode, *player), &input_buffer),
+        };
 
         if let Some(dir) = wished_direction {
             let position_center = position.to_vec3(PACMAN_Z);
@@ -80,31 +85,31 @@ pub(in crate::game) fn set_direction_based_on_keyboard_input(
     }
 }
 
-/// Return the direction pacman should move to next. If no matching keyboard key was pressed, return the last buffered input.
-fn get_wished_direction(keyboard_input: &ButtonInput<KeyCode>, input_buffer: &InputBuffer) -> Option<Dir> {
-    if keyboard_input.pressed(KeyCode::ArrowLeft) || keyboard_input.pressed(KeyCode::KeyA) {
-        return Some(Left);
-    }
-
-    if keyboard_input.pressed(KeyCode::ArrowRight) || keyboard_input.pressed(KeyCode::KeyD) {
-        return Some(Right);
-    }
-
-    if keyboard_input.pressed(KeyCode::ArrowUp) || keyboard_input.pressed(KeyCode::KeyW) {
-        return Some(Up);
-    }
-
-    if keyboard_input.pressed(KeyCode::ArrowDown) || keyboard_input.pressed(KeyCode::KeyS) {
-        return Some(Down);
+/// Return the keys which steer the pacman of the given player.
+///
+/// Only if both players play at the same time (co-op or versus), the second player has their own keys.
+/// Otherwise, the players share the same keys like they share the joystick in the arcade.
+fn get_key_bindings(settings: &Settings, game_mode: &CurrentGameMode, player: Player) -> KeyBindings {
+    match (game_mode.plays_simultaneously(), player) {
+        (true, Player::One) => settings.controls.without(&settings.controls_player_two),
+        (true, Player::Two) => settings.controls_player_two.clone(),
+        (false, _) => settings.controls.clone(),
     }
+}
 
-    **input_buffer
+/// Return the direction pacman should move to next. If no bound key was pressed, return the last buffered input.
+fn get_wished_direction(keyboard_input: &ButtonInput<KeyCode>, key_bindings: &KeyBindings, input_buffer: &InputBuffer) -> Option<Dir> {
+    key_bindings
+        .pressed_direction(keyboard_input)
+        .or(**input_buffer)
 }
 
 pub (in crate::game) fn reset_input_buffer(
-    mut input_buffer: ResMut<InputBuffer>
+    mut query: Query<&mut InputBuffer, With<Pacman>>
 ) {
-    input_buffer.0 = None;
+    for mut input_buffer in &mut query {
+        input_buffer.0 = None;
+    }
 }
 
 /// Return if pacman is near enough to his currents position center to move to an orthogonal position.
@@ -120,5 +125,5 @@ fn is_centered_enough(coordinates: Vec3, direction: Dir, position_coordinates: V
 }
 
 /// Saves the wished direction pacman should move to next.
-#[derive(Deref, DerefMut, Resource)]
+#[derive(Component, Deref, DerefMut, Default)]
 pub struct InputBuffer(pub Option<Dir>);
\ No newline at end of file
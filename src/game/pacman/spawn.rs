@@ -1,18 +1,84 @@
 use bevy::prelude::*;
-use bevy_sprite_sheet::SpriteSheets;
+use crate::game::pacman::movement::InputBuffer;
 use crate::game::pacman::textures::create_pacman_animations;
 use crate::core::prelude::*;
 
+/// How many fields the pacmen in a co-op game are placed apart from the spawn.
+const COOP_SPAWN_OFFSET: f32 = 2.0;
+
+/// Spawn the pacman of the active player. In a co-op game, both players get a pacman (if they have lives left),
+/// placed left and right of the spawn.
 pub(crate) fn spawn_pacman(
     mut commands: Commands,
-    sprite_sheets: Res<SpriteSheets>,
+    theme: Res<Theme>,
+    animation_sheets: Res<AnimationSheets>,
     level: Res<Level>,
+    settings: Res<Settings>,
     specs_per_level: Res<SpecsPerLevel>,
+    game_mode: Res<CurrentGameMode>,
+    active_player: Res<ActivePlayer>,
+    lives: Res<Lives>,
+    other_player: Option<Res<OtherPlayer>>,
     spawns: Query<&Tiles, With<PacmanSpawn>>,
+    wall_query: Query<&Transform, With<Wall>>,
+) {
+    let spawn_coordinates = spawns.single().to_vec3(PACMAN_Z);
+    let speed = Speed(PACMAN_BASE_SPEED * specs_per_level.get_for(&level).pacman_normal_speed_modifier);
+
+    if !game_mode.is_coop() {
+        let player = **active_player;
+        spawn_pacman_for_player(&mut commands, &theme, &animation_sheets, player, settings.controller_of(player), spawn_coordinates, speed);
+        return;
+    }
+
+    let shared_lives = matches!(**game_mode, GameMode::Coop { shared_lives: true });
+    let other_player_can_play = shared_lives || other_player.is_some_and(|other| other.0.can_play());
+    let players = [
+        (**active_player, **lives > 0, -COOP_SPAWN_OFFSET),
+        (active_player.other(), other_player_can_play, COOP_SPAWN_OFFSET),
+    ];
+
+    for (player, can_play, offset) in players {
+        if !can_play {
+            continue;
+        }
+
+        let coordinates = get_coop_spawn_coordinates(spawn_coordinates, offset, &wall_query);
+        spawn_pacman_for_player(&mut commands, &theme, &animation_sheets, player, settings.controller_of(player), coordinates, speed);
+    }
+}
+
+/// Move the spawn by the given amount of fields to the side. If the new spawn would be inside a wall,
+/// the original spawn is used.
+fn get_coop_spawn_coordinates(
+    spawn_coordinates: Vec3,
+    offset: f32,
+    wall_query: &Query<&Transform, With<Wall>>,
+) -> Vec3 {
+    let coordinates = spawn_coordinates + Vec3::new(offset * FIELD_SIZE, 0.0, 0.0);
+    // the spawn lies between two fields, so both fields beneath the pacman must be free
+    let blocked = [-0.5, 0.5]
+        .into_iter()
+        .map(|x| Pos::from_vec3(coordinates + Vec3::new(x * FIELD_SIZE, 0.0, 0.0)))
+        .any(|pos| wall_query.iter().any(|transform| Pos::from_vec3(transform.translation) == pos));
+
+    if blocked {
+        spawn_coordinates
+    } else {
+        coordinates
+    }
+}
+
+fn spawn_pacman_for_player(
+    commands: &mut Commands,
+    theme: &Theme,
+    animation_sheets: &AnimationSheets,
+    player: Player,
+    controller: PacmanController,
+    coordinates: Vec3,
+    speed: Speed,
 ) {
-    let tiles = spawns.single();
-    let transform = Transform::from_translation(tiles.to_vec3(PACMAN_Z));
-    let mut animations = create_pacman_animations(&sprite_sheets);
+    let mut animations = create_pacman_animations(theme, animation_sheets);
     animations.stop();
 
     commands.spawn((
@@ -22,14 +88,17 @@ pub(crate) fn spawn_pacman(
                 custom_size: Some(Vec2::splat(PACMAN_DIMENSION)),
                 ..default()
             },
-            transform,
+            transform: Transform::from_translation(coordinates),
             visibility: Visibility::Visible,
             ..Default::default()
         },
         Name::new("Pacman"),
         Pacman,
-        Speed(PACMAN_BASE_SPEED * specs_per_level.get_for(&level).pacman_normal_speed_modifier),
+        player,
+        controller,
+        InputBuffer::default(),
+        speed,
         Up,
         animations
     ));
-}
\ No newline at end of file
+}
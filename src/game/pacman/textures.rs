@@ -1,5 +1,4 @@
 use bevy::prelude::*;
-use bevy_sprite_sheet::{SpriteSheet, SpriteSheets};
 use crate::core::prelude::*;
 
 pub(crate) fn update_pacman_appearance(
@@ -16,40 +15,23 @@ pub(crate) fn update_pacman_appearance(
 }
 
 pub(crate) fn create_pacman_animations(
-    sprite_sheets: &SpriteSheets
+    theme: &Theme,
+    animation_sheets: &AnimationSheets
 ) -> Animations {
+    let eating_animation = |direction| theme.animations(animation_sheets, &SpriteId::pacman_walking(direction)).untagged();
+
     Animations::new(
         [
-            ("eating_left", create_eating_animation(sprite_sheets.get_sheet("textures/pacman/pacman_walking_left"))),
-            ("eating_right", create_eating_animation(sprite_sheets.get_sheet("textures/pacman/pacman_walking_right"))),
-            ("eating_up", create_eating_animation(sprite_sheets.get_sheet("textures/pacman/pacman_walking_up"))),
-            ("eating_down", create_eating_animation(sprite_sheets.get_sheet("textures/pacman/pacman_walking_down"))),
-            ("dying", create_dying_animation(sprite_sheets.get_sheet("textures/pacman/pacman_dying")))
+            ("eating_left", eating_animation(Left)),
+            ("eating_right", eating_animation(Right)),
+            ("eating_up", eating_animation(Up)),
+            ("eating_down", eating_animation(Down)),
+            ("dying", theme.animations(animation_sheets, &SpriteId::PACMAN_DYING).animation("dying"))
         ],
         "eating_up",
     )
 }
 
-fn create_eating_animation(
-    sheet: &SpriteSheet,
-) -> Animation {
-    Animation::from_textures(
-        0.2,
-        true,
-        sheet.images_at(0..4)
-    )
-}
-
-fn create_dying_animation(
-    sheet: &SpriteSheet,
-) -> Animation {
-    Animation::from_textures(
-        1.5,
-        false,
-        sheet.images_at(0..12),
-    )
-}
-
 /// pacman starts with stopped animations. Restart them here
 pub(crate) fn start_pacman_animation(
     mut query: Query<&mut Animations, With<Pacman>>
@@ -0,0 +1,108 @@
+use std::mem::swap;
+use bevy::prelude::*;
+use crate::core::prelude::*;
+use crate::game::edibles::dots::spawn_dots_at;
+use crate::game::edibles::energizer::spawn_energizers_at;
+
+/// Manages the players of a game. In an alternating game, the players take turns: every time pacman dies,
+/// the progress of the active player gets stored and the progress of the waiting player gets restored.
+pub(super) struct PlayersPlugin;
+
+impl Plugin for PlayersPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<ActivePlayer>()
+            .init_resource::<CurrentGameMode>()
+            .add_systems(OnEnter(Game(Start)), start_players)
+            .add_systems(OnExit(Game(PacmanDead)), switch_player)
+        ;
+    }
+}
+
+/// Start the game with the game mode from the settings. Player one is the active player.
+///
+/// If two players play, player two gets a fresh game. In a co-op game with shared lives, player two
+/// has no lives on their own, as they use the lives of player one. In a versus game, player two steers
+/// a ghost and therefore has no lives at all.
+fn start_players(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    mut active_player: ResMut<ActivePlayer>,
+    mut game_mode: ResMut<CurrentGameMode>,
+    dot_spawn_query: Query<&DotSpawn>,
+) {
+    **active_player = Player::One;
+    **game_mode = settings.game_mode;
+
+    let lives_of_player_two = match settings.game_mode {
+        GameMode::SinglePlayer => {
+            commands.remove_resource::<OtherPlayer>();
+            return;
+        }
+        GameMode::Coop { shared_lives: true } | GameMode::Versus { .. } => 0,
+        _ => settings.lives_at_start(),
+    };
+
+    commands.insert_resource(OtherPlayer(PlayerProgress::new(
+        Player::Two,
+        lives_of_player_two,
+        settings.starting_level(),
+        dot_spawn_query.iter().count(),
+        settings.extra_life_threshold,
+    )));
+}
+
+/// Switch to the waiting player, if they have lives left. The remaining dots and energizers of
+/// the active player are stored and the maze of the waiting player is spawned instead.
+fn switch_player(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    game_mode: Res<CurrentGameMode>,
+    other_player: Option<ResMut<OtherPlayer>>,
+    mut active_player: ResMut<ActivePlayer>,
+    mut score: ResMut<Score>,
+    mut lives: ResMut<Lives>,
+    mut level: ResMut<Level>,
+    mut eaten_dots: ResMut<EatenDots>,
+    mut points_required_for_extra_life: ResMut<PointsRequiredForExtraLife>,
+    dots_query: Query<Entity, With<Dots>>,
+    energizers_query: Query<Entity, With<Energizers>>,
+    dot_query: Query<&Transform, With<Dot>>,
+    energizer_query: Query<&Transform, With<Energizer>>,
+    dot_spawn_query: Query<&Tiles, With<DotSpawn>>,
+    energizer_spawn_query: Query<&Tiles, With<EnergizerSpawn>>,
+) {
+    let Some(mut other_player) = other_player else {
+        return;
+    };
+
+    if **game_mode != GameMode::Alternating {
+        return;
+    }
+
+    if !other_player.0.can_play() {
+        return;
+    }
+
+    let waiting = &mut other_player.0;
+    swap(&mut **active_player, &mut waiting.player);
+    swap(&mut *score, &mut waiting.score);
+    swap(&mut *lives, &mut waiting.lives);
+    swap(&mut *level, &mut waiting.level);
+    swap(&mut *eaten_dots, &mut waiting.eaten_dots);
+    swap(&mut *points_required_for_extra_life, &mut waiting.points_required_for_extra_life);
+
+    let dots_to_spawn = waiting.remaining_dots
+        .replace(dot_query.iter().map(|transform| transform.translation).collect())
+        .unwrap_or_else(|| dot_spawn_query.iter().map(|tiles| tiles.to_vec3(DOT_Z)).collect());
+    let energizers_to_spawn = waiting.remaining_energizers
+        .replace(energizer_query.iter().map(|transform| transform.translation).collect())
+        .unwrap_or_else(|| energizer_spawn_query.iter().map(|tiles| tiles.to_vec3(ENERGIZER_Z)).collect());
+
+    for e in dots_query.iter().chain(energizers_query.iter()) {
+        commands.entity(e).despawn_recursive();
+    }
+
+    spawn_dots_at(&mut commands, &theme, dots_to_spawn);
+    spawn_energizers_at(&mut commands, &theme, energizers_to_spawn);
+}
@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+
+use crate::core::prelude::*;
+
+/// Starts a practice game instead of a regular one if it is enabled in the settings.
+///
+/// The level and lives of a practice game are set together with the ones of a regular game. Everything
+/// else (infinite lives, the cleared sections and the high score) checks if a [CurrentPractice] exists.
+pub(super) struct PracticePlugin;
+
+impl Plugin for PracticePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(Startup, prepare_next_game)
+            .add_systems(OnExit(Game(GameOver)), prepare_next_game)
+        ;
+    }
+}
+
+/// Take the practice settings for the next game. This happens before the game starts, so the maze
+/// already gets filled without the cleared sections.
+fn prepare_next_game(
+    mut commands: Commands,
+    settings: Res<Settings>,
+) {
+    match settings.practice.enabled {
+        true => commands.insert_resource(CurrentPractice(settings.practice.clone())),
+        false => commands.remove_resource::<CurrentPractice>(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use crate::core::prelude::*;
+    use crate::scenario::Scenario;
+
+    fn practice(practice: PracticeSettings) -> Scenario {
+        Scenario::with_settings(Settings {
+            muted: true,
+            practice: PracticeSettings {
+                enabled: true,
+                ..practice
+            },
+            ..default()
+        })
+    }
+
+    #[test]
+    fn a_practice_game_starts_in_the_selected_level_with_cleared_sections() {
+        let scenario = practice(PracticeSettings {
+            level: 5,
+            lives: 1,
+            cleared_energizers: vec![MazeSection::TopLeft, MazeSection::TopRight],
+            ..default()
+        });
+
+        assert_eq!(**scenario.resource::<Level>(), 5);
+        assert_eq!(**scenario.resource::<Lives>(), 1);
+        assert_eq!(scenario.count_entities::<Energizer>(), 2);
+    }
+
+    #[test]
+    fn pacman_keeps_his_lives_with_infinite_lives() {
+        let mut scenario = practice(PracticeSettings {
+            lives: 2,
+            infinite_lives: true,
+            ..default()
+        });
+        scenario
+            .pacman_runs_into(Blinky)
+            .advance_until(60, |s| s.count::<PacmanWasHit>() > 0);
+
+        assert_eq!(**scenario.resource::<Lives>(), 2);
+    }
+}
@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+
+use crate::core::prelude::*;
+use crate::game::accessibility::{recolor_sprites, WALL_SPRITES};
+
+/// Takes the ruleset from the settings when a game starts and colors the maze walls like the ruleset
+/// wants them in the current level.
+pub(super) struct RulesetPlugin;
+
+impl Plugin for RulesetPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<CurrentRuleset>()
+            .add_systems(OnEnter(Game(Start)), start_ruleset)
+            .add_systems(OnEnter(Game(Ready)), update_maze_color)
+        ;
+    }
+}
+
+fn start_ruleset(
+    settings: Res<Settings>,
+    mut ruleset: ResMut<CurrentRuleset>,
+) {
+    **ruleset = settings.ruleset;
+}
+
+/// Recolor the wall textures if the maze of this level has another color than the one before. The
+/// high contrast maze of the accessibility settings wins over the color of the ruleset.
+///
+/// Only walls in the blue of the arcade can be recolored. If the walls of the theme have another color,
+/// they keep it.
+fn update_maze_color(
+    mut current_color: Local<Option<[u8; 3]>>,
+    settings: Res<Settings>,
+    ruleset: Res<CurrentRuleset>,
+    level: Res<Level>,
+    theme: Res<Theme>,
+    animation_sheets: Res<AnimationSheets>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let from = current_color.unwrap_or(ARCADE_WALL_COLOR);
+    let to = match settings.accessibility.high_contrast_maze {
+        true => HIGH_CONTRAST_WALL_COLOR,
+        false => ruleset.maze_color(&level),
+    };
+
+    if from != to && recolor_sprites(&WALL_SPRITES, from, to, &theme, &animation_sheets, &mut images) {
+        *current_color = Some(to);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use bevy::utils::HashSet;
+
+    use crate::core::prelude::*;
+    use crate::game::accessibility::WALL_SPRITES;
+    use crate::scenario::Scenario;
+
+    /// Every color the wall textures show right now.
+    fn wall_colors(scenario: &Scenario) -> HashSet<[u8; 3]> {
+        let theme = scenario.resource::<Theme>();
+        let animation_sheets = scenario.resource::<AnimationSheets>();
+        let images = scenario.resource::<Assets<Image>>();
+
+        WALL_SPRITES
+            .iter()
+            .flat_map(|sprite| theme.animations(animation_sheets, sprite).images())
+            .filter_map(|handle| images.get(handle))
+            .flat_map(|image| image.data.chunks_exact(4))
+            .filter(|pixel| pixel[3] > 0)
+            .map(|pixel| [pixel[0], pixel[1], pixel[2]])
+            .collect()
+    }
+
+    #[test]
+    fn the_walls_are_blue_again_when_an_arcade_game_follows_a_ms_pacman_game() {
+        let ms_pacman_color = Ruleset::MsPacman.maze_color(&Level(1));
+        let mut scenario = Scenario::with_settings(Settings {
+            game_mode: GameMode::SinglePlayer,
+            ruleset: Ruleset::MsPacman,
+            muted: true,
+            ..Settings::default()
+        });
+        assert!(wall_colors(&scenario).contains(&ms_pacman_color));
+        assert!(!wall_colors(&scenario).contains(&ARCADE_WALL_COLOR));
+
+        **scenario.resource_mut::<Lives>() = 0;
+        scenario
+            .pacman_runs_into(Blinky)
+            .advance_until(60 * 10, |s| s.state() == Game(GameOver));
+
+        scenario.resource_mut::<Settings>().ruleset = Ruleset::Arcade;
+        scenario
+            .send(GameWasRestarted)
+            .advance_until(60 * 10, |s| s.state() == Game(Running));
+
+        assert!(wall_colors(&scenario).contains(&ARCADE_WALL_COLOR));
+        assert!(!wall_colors(&scenario).contains(&ms_pacman_color));
+    }
+}
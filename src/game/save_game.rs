@@ -0,0 +1,140 @@
+use bevy::prelude::*;
+
+use crate::core::prelude::*;
+use crate::game::edibles::dots::spawn_dots_at;
+use crate::game::edibles::energizer::spawn_energizers_at;
+
+/// Saves a single player game in progress when the game gets closed and resumes it on the next start.
+///
+/// Not part of the [crate::PacmanPlugin], so headless games (like simulations and tests) never
+/// pick up a game which was saved by a player.
+pub struct SaveGamePlugin;
+
+impl Plugin for SaveGamePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(Startup, load_saved_game)
+            .add_systems(
+                OnExit(Game(Start)),
+                resume_saved_game.run_if(resource_exists::<SavedGame>)
+            )
+            .add_systems(Last, save_game_on_exit)
+        ;
+    }
+}
+
+/// Load the saved game, if there is one. Only single player games get saved, so the game is only
+/// resumed if the next game would be a single player game too (and no practice game).
+fn load_saved_game(
+    mut commands: Commands,
+    settings: Res<Settings>,
+) {
+    if settings.game_mode != GameMode::SinglePlayer || settings.practice.enabled {
+        return;
+    }
+
+    if let Some(saved_game) = SavedGame::load() {
+        commands.insert_resource(saved_game);
+    }
+}
+
+/// Replace the fresh game with the saved one. The start of the game gets skipped when a saved game exists,
+/// so this happens right before the level restarts at Game(Ready).
+fn resume_saved_game(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    saved_game: Res<SavedGame>,
+    dots_query: Query<Entity, With<Dots>>,
+    energizers_query: Query<Entity, With<Energizers>>,
+) {
+    commands.insert_resource(saved_game.score.clone());
+    commands.insert_resource(saved_game.high_score.clone());
+    commands.insert_resource(saved_game.lives.clone());
+    commands.insert_resource(saved_game.level.clone());
+    commands.insert_resource(saved_game.points_required_for_extra_life.clone());
+    commands.insert_resource(saved_game.eaten_dots.clone());
+    commands.insert_resource(saved_game.ghost_house_gate.clone());
+    commands.insert_resource(ResumedGhostSchedule(saved_game.ghost_schedule.clone()));
+    commands.insert_resource(CurrentRuleset(saved_game.ruleset));
+
+    for e in dots_query.iter().chain(energizers_query.iter()) {
+        commands.entity(e).despawn_recursive();
+    }
+
+    spawn_dots_at(&mut commands, &theme, saved_game.remaining_dots.iter().copied());
+    spawn_energizers_at(&mut commands, &theme, saved_game.remaining_energizers.iter().copied());
+
+    commands.remove_resource::<SavedGame>();
+    SavedGame::delete();
+}
+
+/// Save the game when the app exits, if a single player game is in progress. Practice games are not saved.
+fn save_game_on_exit(
+    mut exit_events: EventReader<AppExit>,
+    game_state: Res<State<GameState>>,
+    game_mode: Res<CurrentGameMode>,
+    practice: Option<Res<CurrentPractice>>,
+    score: Res<Score>,
+    high_score: Res<HighScore>,
+    lives: Res<Lives>,
+    level: Res<Level>,
+    points_required_for_extra_life: Res<PointsRequiredForExtraLife>,
+    eaten_dots: Option<Res<EatenDots>>,
+    ghost_schedule: Option<Res<GhostSchedule>>,
+    ghost_house_gate: Option<Res<GhostHouseGate>>,
+    ruleset: Res<CurrentRuleset>,
+    dot_query: Query<&Transform, With<Dot>>,
+    energizer_query: Query<&Transform, With<Energizer>>,
+) {
+    if exit_events.read().count() == 0 {
+        return;
+    }
+
+    if **game_mode != GameMode::SinglePlayer || practice.is_some() || !game_in_progress(game_state.get(), &lives) {
+        return;
+    }
+
+    let (Some(eaten_dots), Some(ghost_schedule), Some(ghost_house_gate)) = (eaten_dots, ghost_schedule, ghost_house_gate) else {
+        return;
+    };
+
+    SavedGame {
+        map: MAP_SCENE_PATH.to_string(),
+        score: score.clone(),
+        high_score: high_score.clone(),
+        lives: lives.clone(),
+        level: level.clone(),
+        points_required_for_extra_life: points_required_for_extra_life.clone(),
+        eaten_dots: eaten_dots.clone(),
+        remaining_dots: dot_query.iter().map(|transform| transform.translation).collect(),
+        remaining_energizers: energizer_query.iter().map(|transform| transform.translation).collect(),
+        ghost_schedule: ghost_schedule.clone(),
+        ghost_house_gate: ghost_house_gate.clone(),
+        ruleset: **ruleset,
+    }.save();
+}
+
+/// Tells if the game can be continued later. This is not the case before the game started, after it is over
+/// or when pacman just lost his last life.
+fn game_in_progress(game_state: &GameState, lives: &Lives) -> bool {
+    match game_state {
+        Game(Start | GameOver) => false,
+        Game(PacmanHit | PacmanDying | PacmanDead) => **lives > 0,
+        Game(_) => true,
+        _ => false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::prelude::*;
+    use crate::game::save_game::game_in_progress;
+
+    #[test]
+    fn a_game_is_not_saved_when_pacman_lost_his_last_life() {
+        assert!(game_in_progress(&Game(PacmanDying), &Lives(1)));
+        assert!(!game_in_progress(&Game(PacmanDying), &Lives(0)));
+        assert!(game_in_progress(&Game(Running), &Lives(1)));
+        assert!(!game_in_progress(&Game(GameOver), &Lives(0)));
+    }
+}
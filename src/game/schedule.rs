@@ -18,19 +18,30 @@ impl Plugin for SchedulePlugin {
 
 fn register_start_schedule(
     mut commands: Commands,
+    level: Res<Level>,
     schedule_by_level: Res<ScheduleByLevel>,
 ) {
-    commands.insert_resource(schedule_by_level.get_schedule_for_level(&Level(1)));
+    commands.insert_resource(schedule_by_level.get_schedule_for_level(&level));
 }
 
+/// Use the schedule of the new level when the level changed. A resumed game continues the schedule
+/// it was saved with instead.
 fn switch_schedule_when_level_changed(
+    mut commands: Commands,
     mut schedule: ResMut<GhostSchedule>,
     level: Res<Level>,
     schedule_by_level: Res<ScheduleByLevel>,
+    resumed_schedule: Option<Res<ResumedGhostSchedule>>,
 ) {
     if !level.is_changed() { return; }
 
-    *schedule = schedule_by_level.get_schedule_for_level(&level);
+    *schedule = match resumed_schedule {
+        Some(resumed_schedule) => {
+            commands.remove_resource::<ResumedGhostSchedule>();
+            resumed_schedule.0.clone()
+        }
+        None => schedule_by_level.get_schedule_for_level(&level)
+    };
 }
 
 /// Update the currently active schedule.
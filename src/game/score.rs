@@ -1,4 +1,5 @@
 use std::time::Duration;
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 
 use crate::core::prelude::*;
@@ -24,6 +25,8 @@ impl Plugin for ScorePlugin {
                         .in_set(ProcessIntersectionsWithPacman),
                     add_points_for_eaten_fruit_and_display_score_text
                         .in_set(ProcessIntersectionsWithPacman),
+                    add_points_for_caught_pacman
+                        .in_set(ProcessIntersectionsWithPacman),
                     update_high_score,
                     play_highscore_broken_sound.after(update_high_score)
                 )
@@ -51,39 +54,60 @@ impl Plugin for ScorePlugin {
     }
 }
 
+/// The scores of all players. Points are always added to the player controlling the pacman
+/// which collected them.
+#[derive(SystemParam)]
+struct PlayerScores<'w, 's> {
+    score: ResMut<'w, Score>,
+    active_player: Res<'w, ActivePlayer>,
+    other_player: Option<ResMut<'w, OtherPlayer>>,
+    pacman_query: Query<'w, 's, &'static Player, With<Pacman>>,
+}
+
+impl<'w, 's> PlayerScores<'w, 's> {
+    fn add(&mut self, pacman: Entity, points: usize) {
+        let player = self.pacman_query.get(pacman).copied().unwrap_or(**self.active_player);
+
+        match self.other_player {
+            Some(ref mut other_player) if player != **self.active_player => other_player.0.score.add(points),
+            _ => self.score.add(points)
+        }
+    }
+}
+
 fn add_points_for_eaten_dot(
-    mut score: ResMut<Score>,
+    mut scores: PlayerScores,
     mut event_reader: EventReader<DotWasEaten>,
 ) {
-    for _ in event_reader.read() {
-        score.add(POINTS_PER_DOT)
+    for DotWasEaten(pacman) in event_reader.read() {
+        scores.add(*pacman, POINTS_PER_DOT)
     }
 }
 
 fn add_points_for_eaten_energizer(
-    mut score: ResMut<Score>,
+    mut scores: PlayerScores,
     mut event_reader: EventReader<EnergizerWasEaten>,
 ) {
-    for _ in event_reader.read() {
-        score.add(POINTS_PER_ENERGIZER)
+    for EnergizerWasEaten(pacman) in event_reader.read() {
+        scores.add(*pacman, POINTS_PER_ENERGIZER)
     }
 }
 
 fn add_points_for_eaten_ghost_and_display_score_text(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    mut score: ResMut<Score>,
+    theme: Res<Theme>,
+    mut scores: PlayerScores,
     mut eaten_ghost_counter: ResMut<EatenGhostCounter>,
     mut event_reader: EventReader<GhostWasEaten>,
 ) {
     for event in event_reader.read() {
         let points = POINTS_PER_GHOST * 2usize.pow(**eaten_ghost_counter as u32);
-        score.add(points);
+        scores.add(event.2, points);
         **eaten_ghost_counter += 1;
 
         let mut coordinates = event.1.translation;
         coordinates.z = TEXT_Z;
-        spawn_score_text(&mut commands, &asset_server, Color::Srgba(Srgba::hex("31FFFF").unwrap()), points, coordinates)
+        spawn_score_text(&mut commands, &theme, &ColorId::GHOST_POINTS, points, coordinates)
     }
 }
 
@@ -104,36 +128,25 @@ fn reset_ghost_eaten_counter(
 
 fn add_points_for_eaten_fruit_and_display_score_text(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    mut score: ResMut<Score>,
+    theme: Res<Theme>,
+    mut scores: PlayerScores,
     mut event_reader: EventReader<FruitWasEaten>,
 ) {
     for event in event_reader.read() {
-        let (fruit, transform) = (event.0, event.1);
-
-        let points = match fruit {
-            Cherry => 100,
-            Strawberry => 300,
-            Peach => 500,
-            Apple => 700,
-            Grapes => 1000,
-            Galaxian => 2000,
-            Bell => 3000,
-            Key => 5000
-        };
+        let (transform, pacman, points) = (event.1, event.2, event.3);
 
         let mut coordinates = transform.translation;
         coordinates.z = TEXT_Z;
 
-        score.add(points);
-        spawn_score_text(&mut commands, &asset_server, Color::Srgba(Srgba::hex("FFBDFF").unwrap()), points, coordinates)
+        scores.add(pacman, points);
+        spawn_score_text(&mut commands, &theme, &ColorId::FRUIT_POINTS, points, coordinates)
     }
 }
 
 fn spawn_score_text(
     commands: &mut Commands,
-    asset_server: &AssetServer,
-    color: Color,
+    theme: &Theme,
+    color: &ColorId,
     points: usize,
     coordinates: Vec3,
 ) {
@@ -141,11 +154,7 @@ fn spawn_score_text(
         Text2dBundle {
             text: Text::from_section(
                 points.to_string(),
-                TextStyle {
-                    font: asset_server.load(FONT),
-                    font_size: 10.0,
-                    color,
-                },
+                theme.text_style(color),
             ).with_justify(JustifyText::Center),
             transform: Transform::from_translation(coordinates),
             ..Default::default()
@@ -170,17 +179,49 @@ fn update_score_texts(
     }
 }
 
+/// In a versus game, player two scores when their ghost catches pacman.
+fn add_points_for_caught_pacman(
+    mut event_reader: EventReader<PacmanWasHit>,
+    other_player: Option<ResMut<OtherPlayer>>,
+    ghost_query: Query<(), With<PlayerControlledGhost>>,
+) {
+    let Some(mut other_player) = other_player else {
+        event_reader.clear();
+        return;
+    };
+
+    for PacmanWasHit(_, ghost) in event_reader.read() {
+        if ghost_query.contains(*ghost) {
+            other_player.0.score.add(POINTS_PER_CAUGHT_PACMAN);
+        }
+    }
+}
+
+/// Update the high score with the best score of all players. A practice game does not count for the high score.
 fn update_high_score(
     score: Res<Score>,
+    other_player: Option<Res<OtherPlayer>>,
+    practice: Option<Res<CurrentPractice>>,
     mut high_score: ResMut<HighScore>,
     mut event_writer: EventWriter<HighScoreWasBeaten>,
 ) {
-    if !score.is_changed() {
+    if practice.is_some() {
         return;
     }
 
-    if **score > high_score.score {
-        high_score.score = **score;
+    let other_score_changed = other_player.as_ref().is_some_and(|other| other.is_changed());
+
+    if !score.is_changed() && !other_score_changed {
+        return;
+    }
+
+    let best_score = other_player
+        .map(|other| *other.0.score)
+        .unwrap_or_default()
+        .max(**score);
+
+    if best_score > high_score.score {
+        high_score.score = best_score;
 
         if !high_score.was_beaten {
             high_score.was_beaten = true;
@@ -190,19 +231,11 @@ fn update_high_score(
 }
 
 fn play_highscore_broken_sound(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
     mut event_reader: EventReader<HighScoreWasBeaten>,
+    mut event_writer: EventWriter<PlaySound>,
 ) {
     for _ in event_reader.read() {
-        commands.spawn((
-            Name::new("HighScoreBrokenSound"),
-            SoundEffect::new(3),
-            AudioBundle {
-                source: asset_server.load("sounds/high_score.ogg"),
-                ..default()
-            }
-        ));
+        event_writer.send(PlaySound(SoundId::HIGH_SCORE));
     }
 }
 
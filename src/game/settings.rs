@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, WindowMode};
+
+use crate::core::prelude::*;
+
+/// Applies the settings to the game and persists them when they change.
+pub(super) struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(
+                Update,
+                (
+                    save_settings_when_changed,
+                    apply_window_mode,
+                    apply_difficulty,
+                ).run_if(resource_changed::<Settings>)
+            )
+        ;
+    }
+}
+
+fn save_settings_when_changed(
+    settings: Res<Settings>,
+) {
+    // the settings were just loaded from disk, so no need to write them back
+    if settings.is_added() {
+        return;
+    }
+
+    settings.save()
+}
+
+fn apply_window_mode(
+    settings: Res<Settings>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let mode = match settings.fullscreen {
+        true => WindowMode::BorderlessFullscreen,
+        false => WindowMode::Windowed
+    };
+
+    for mut window in &mut windows {
+        if window.mode != mode {
+            window.mode = mode;
+        }
+    }
+}
+
+fn apply_difficulty(
+    settings: Res<Settings>,
+    mut specs_per_level: ResMut<SpecsPerLevel>,
+) {
+    *specs_per_level = SpecsPerLevel::for_difficulty(settings.difficulty);
+}
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+use std::time::Duration;
 use bevy::prelude::*;
 use crate::core::prelude::*;
 
@@ -8,12 +10,23 @@ impl Plugin for SoundEffectPlugin {
         app
             .add_systems(
                 Update,
-                update_sound_effects
+                (
+                    update_sound_effects,
+                    play_sounds
+                ).chain().run_if(resource_exists::<SoundRegistry>)
             )
         ;
     }
 }
 
+/// A sound effect which currently plays.
+struct PlayingSound {
+    entity: Entity,
+    id: SoundId,
+    elapsed: Duration,
+    priority: u8,
+}
+
 /// Updates the timer on a all sound effects. As I currently know no other way to check if a sound
 /// finished playing, this is the solution.
 /// It doesn't matter if the sound plays longer than its timer, the entity can be deleted
@@ -32,4 +45,99 @@ fn update_sound_effects(
             commands.entity(entity).despawn();
         }
     }
-}
\ No newline at end of file
+}
+
+/// Play every requested sound according to the rules in the sound registry. Queued sounds from
+/// previous frames get played first, if there is room for them now.
+fn play_sounds(
+    mut commands: Commands,
+    registry: Res<SoundRegistry>,
+    mut queued: Local<HashSet<SoundId>>,
+    mut event_reader: EventReader<PlaySound>,
+    sound_query: Query<(Entity, &SoundEffect)>,
+) {
+    let mut playing = sound_query
+        .iter()
+        .filter(|(_, sound)| !sound.finished())
+        .map(|(entity, sound)| PlayingSound {
+            entity,
+            id: sound.id.clone(),
+            elapsed: sound.elapsed(),
+            priority: registry.get(&sound.id).map(|spec| spec.priority).unwrap_or_default(),
+        })
+        .collect::<Vec<_>>();
+
+    let requested = queued
+        .drain()
+        .chain(event_reader.read().map(|PlaySound(id)| id.clone()))
+        .collect::<Vec<_>>();
+
+    for id in requested {
+        let Some(spec) = registry.get(&id) else {
+            warn!("the sound {id:?} is not in the sound registry");
+            continue;
+        };
+
+        let instances = playing.iter().filter(|sound| sound.id == id).count();
+
+        if instances >= spec.max_concurrent {
+            match spec.retrigger {
+                Retrigger::Drop => continue,
+                Retrigger::Queue => {
+                    queued.insert(id);
+                    continue;
+                }
+                Retrigger::Restart => {
+                    let oldest = playing
+                        .iter()
+                        .filter(|sound| sound.id == id)
+                        .max_by_key(|sound| sound.elapsed)
+                        .map(|sound| sound.entity);
+                    stop_sound(&mut commands, &mut playing, oldest);
+                }
+            }
+        }
+
+        if playing.len() >= registry.max_sounds {
+            let least_important = playing
+                .iter()
+                .filter(|sound| sound.priority < spec.priority)
+                .min_by_key(|sound| (sound.priority, std::cmp::Reverse(sound.elapsed)))
+                .map(|sound| sound.entity);
+
+            if least_important.is_none() {
+                continue;
+            }
+
+            stop_sound(&mut commands, &mut playing, least_important);
+        }
+
+        let entity = commands.spawn((
+            Name::new(format!("{}Sound", id.as_str())),
+            SoundEffect::new(id.clone(), spec.duration),
+            spec.bus,
+            AudioBundle {
+                source: spec.source.clone(),
+                ..default()
+            }
+        )).id();
+
+        playing.push(PlayingSound {
+            entity,
+            id,
+            elapsed: Duration::ZERO,
+            priority: spec.priority,
+        });
+    }
+}
+
+fn stop_sound(
+    commands: &mut Commands,
+    playing: &mut Vec<PlayingSound>,
+    entity_opt: Option<Entity>,
+) {
+    if let Some(entity) = entity_opt {
+        commands.entity(entity).despawn();
+        playing.retain(|sound| sound.entity != entity);
+    }
+}
@@ -53,12 +53,12 @@ fn update_blinky_speed(
     comps: &mut GhostSpeedUpdateComponentsItem,
     tunnel_query: &Query<&Transform, Or<(With<Tunnel>, With<TunnelHallway>)>>,
 ) {
-    let spec = specs_per_level.get_for(&level);
+    let spec = specs_per_level.get_for(level);
     let remaining_dots = eaten_dots.get_remaining();
 
     if *comps.state == Eaten {
         *comps.speed = Speed(GHOST_BASE_SPEED * 2.0)
-    } else if is_in_tunnel(&comps.transform, tunnel_query) {
+    } else if is_in_tunnel(comps.transform, tunnel_query) {
         *comps.speed = Speed(GHOST_BASE_SPEED * spec.ghost_tunnel_speed_modifier);
     } else if *comps.state == Frightened {
         *comps.speed = Speed(GHOST_BASE_SPEED * spec.ghost_frightened_speed_modifier)
@@ -77,11 +77,11 @@ fn update_non_blinky_speed(
     comps: &mut GhostSpeedUpdateComponentsItem,
     tunnel_query: &Query<&Transform, Or<(With<Tunnel>, With<TunnelHallway>)>>,
 ) {
-    let spec = specs_per_level.get_for(&level);
+    let spec = specs_per_level.get_for(level);
 
     if *comps.state == Eaten {
         *comps.speed = Speed(GHOST_BASE_SPEED * 2.0)
-    } else if is_in_tunnel(&comps.transform, tunnel_query) {
+    } else if is_in_tunnel(comps.transform, tunnel_query) {
         *comps.speed = Speed(GHOST_BASE_SPEED * spec.ghost_tunnel_speed_modifier);
     } else if *comps.state == Frightened {
         *comps.speed = Speed(GHOST_BASE_SPEED * spec.ghost_frightened_speed_modifier)
@@ -43,6 +43,7 @@ fn update_state(
     energizer_eaten_events: EventReader<EnergizerWasEaten>,
     ghost_eaten_events: EventReader<GhostWasEaten>,
     spawns_query: Query<&GhostSpawn>,
+    houses_query: Query<&GhostHouse>,
     mut query: Query<StateUpdateComponents, With<Ghost>>,
 ) {
     let energizer_eaten = energizer_eaten(energizer_eaten_events);
@@ -61,10 +62,10 @@ fn update_state(
         }
 
         match *components.state {
-            Spawned => process_spawned(&schedule, &mut components, &spawns_query),
+            Spawned => process_spawned(&schedule, &mut components, &spawns_query, &houses_query),
             Scatter | Chase => process_scatter_chase(&schedule, &mut components),
             Frightened => process_frightened(&schedule, energizer_over, &mut components),
-            Eaten => process_eaten(&mut components, &spawns_query),
+            Eaten => process_eaten(&mut components, &spawns_query, &houses_query),
         }
     }
 }
@@ -72,18 +73,19 @@ fn update_state(
 fn update_state_on_eaten_pause(
     schedule: Res<GhostSchedule>,
     spawns_query: Query<&GhostSpawn>,
+    houses_query: Query<&GhostHouse>,
     mut query: Query<StateUpdateComponents, With<Ghost>>,
 ) {
     for mut components in &mut query {
         match *components.state {
-            Spawned => process_spawned(&schedule, &mut components, &spawns_query),
-            Eaten => process_eaten(&mut components, &spawns_query),
+            Spawned => process_spawned(&schedule, &mut components, &spawns_query, &houses_query),
+            Eaten => process_eaten(&mut components, &spawns_query, &houses_query),
             _ => continue
         }
     }
 }
 
-fn collect_events<'a, E: Copy + Event>(mut event_reader: EventReader<E>) -> Vec<E> {
+fn collect_events<E: Copy + Event>(mut event_reader: EventReader<E>) -> Vec<E> {
     event_reader.read().copied().collect()
 }
 
@@ -118,20 +120,19 @@ fn process_energizer_eaten(
     components.target.set(coordinates_ghost_came_from);
 }
 
+/// A spawned ghost starts to chase or scatter when it left its house and stands in front of the entrance.
 fn process_spawned(
     schedule: &GhostSchedule,
     components: &mut StateUpdateComponentsItem,
     spawns_query: &Query<&GhostSpawn>,
+    houses_query: &Query<&GhostHouse>,
 ) {
-    let blinky_spawn = spawns_query
-        .iter()
-        .find(|spawn| spawn.ghost == Blinky)
-        .expect("blinky should have a spawn");
+    let (_, house) = get_spawn_and_house(*components.ghost, spawns_query, houses_query);
 
     let coordinates = components.transform.translation;
-    if coordinates.xy_equal(&blinky_spawn.coordinates) {
+    if coordinates.xy_equal(&house.entrance_coordinates) {
         *components.state = schedule.current_state();
-        *components.direction = blinky_spawn.spawn_direction;
+        *components.direction = house.leave_direction;
     }
 }
 
@@ -173,23 +174,64 @@ fn process_frightened(
 fn process_eaten(
     components: &mut StateUpdateComponentsItem,
     spawns_query: &Query<&GhostSpawn>,
+    houses_query: &Query<&GhostHouse>,
 ) {
-    let respawn = spawns_query
-        .iter()
-        .find(|spawn| match *components.ghost {
-            Blinky => spawn.ghost == Pinky,
-            _ => spawn.ghost == *components.ghost
-        })
-        .expect("every ghost should have a spawn");
+    let (spawn, house) = get_spawn_and_house(*components.ghost, spawns_query, houses_query);
     let coordinates = components.transform.translation;
 
-    if coordinates.xy_equal(&respawn.coordinates) {
+    if coordinates.xy_equal(&house.respawn_coordinates(spawn)) {
         *components.state = Spawned
     }
 }
 
+fn get_spawn_and_house<'a>(
+    ghost: Ghost,
+    spawns_query: &'a Query<&GhostSpawn>,
+    houses_query: &'a Query<&GhostHouse>,
+) -> (&'a GhostSpawn, &'a GhostHouse) {
+    let spawn = spawns_query
+        .iter()
+        .find(|spawn| spawn.ghost == ghost)
+        .expect("every ghost should have a spawn");
+    let house = houses_query
+        .iter()
+        .find(|house| house.index == spawn.house)
+        .expect("every ghost should live in a ghost house");
+    (spawn, house)
+}
+
 impl std::fmt::Display for GhostState {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:?}", self)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::prelude::*;
+    use crate::scenario::Scenario;
+
+    #[test]
+    fn ghosts_turn_around_when_an_energizer_gets_eaten() {
+        let mut scenario = Scenario::new();
+        scenario
+            .place_pacman(Pos::new(1, 27), Up)
+            .place_ghost(Blinky, Pos::new(20, 26), Right, Scatter)
+            .hold(Up);
+
+        let mut direction_before = scenario.ghost_direction(Blinky);
+
+        scenario.advance_until(60, |s| {
+            let frightened = s.ghost_state(Blinky) == Frightened;
+
+            if !frightened {
+                direction_before = s.ghost_direction(Blinky);
+            }
+
+            frightened
+        });
+
+        assert_eq!(scenario.count::<EnergizerWasEaten>(), 1);
+        assert_eq!(scenario.ghost_direction(Blinky), direction_before.opposite());
+    }
+}
@@ -0,0 +1,103 @@
+use crate::game::target::TargetSetter;
+use crate::core::prelude::*;
+
+impl<'a, 'b, 'c> TargetSetter<'a, 'b, 'c> {
+    /// Determine the next target coordinates for a ghost when in "Eaten" state.
+    ///
+    /// When eaten, a ghost walks to the ghost house and enters it. When at the ghost house, he aligns perfectly
+    /// before the entrance, moves than to the house center and finally to his spawn coordinates, which depend on the ghost type.
+    pub fn set_eaten_target(&mut self) {
+        if self.is_directly_before_entrance() {
+            self.move_in_house_center()
+        } else if self.is_before_entrance() {
+            self.move_directly_before_entrance()
+        } else if self.is_in_center() {
+            self.move_to_respawn()
+        } else {
+            // TODO: Maybe only take this branch when not already in the ghost house, just to avoid bugs
+            self.move_to_nearest_position_before_entrance()
+        }
+    }
+
+    /// Return if the ghost is perfectly centered in front of the ghost house entrance.
+    fn is_directly_before_entrance(&self) -> bool {
+        self.components.transform.translation.xy_equal(&self.get_house(*self.components.ghost).entrance_coordinates)
+    }
+
+    fn move_in_house_center(&mut self) {
+        let house = self.get_house(*self.components.ghost);
+        let (direction, center) = (house.exit_direction.opposite(), house.center_coordinates);
+        *self.components.direction = direction;
+        self.components.target.set(center);
+    }
+
+    /// Return if the ghost is just on a position in front of the house.
+    fn is_before_entrance(&self) -> bool {
+        self.get_house(*self.components.ghost)
+            .entrance_positions
+            .contains(&Pos::from_vec3(self.components.transform.translation))
+    }
+
+    fn move_directly_before_entrance(&mut self) {
+        let house = self.get_house(*self.components.ghost);
+        let (in_front_of_house, exit_direction) = (house.entrance_coordinates, house.exit_direction);
+        let position_coordinates = Pos::from_vec3(self.components.transform.translation).to_vec3(0.0);
+
+        *self.components.direction = match exit_direction {
+            Up | Down => match in_front_of_house.x < position_coordinates.x {
+                true => Left,
+                false => Right
+            },
+            Left | Right => match in_front_of_house.y < position_coordinates.y {
+                true => Down,
+                false => Up
+            },
+        };
+        self.components.target.set(in_front_of_house);
+    }
+
+    fn is_in_center(&self) -> bool {
+        self.components.transform.translation.xy_equal(&self.get_house(*self.components.ghost).center_coordinates)
+    }
+
+    fn move_to_respawn(&mut self) {
+        let house = self.get_house(*self.components.ghost);
+        let (center, exit_direction) = (house.center_coordinates, house.exit_direction);
+        let respawn = self.get_respawn_coordinates(*self.components.ghost);
+
+        *self.components.direction = match exit_direction {
+            Up | Down => match respawn.x < center.x {
+                true => Left,
+                false => Right
+            },
+            Left | Right => match respawn.y < center.y {
+                true => Down,
+                false => Up
+            },
+        };
+        self.components.target.set(respawn);
+    }
+
+    /// Walk towards the entrance of the ghost's house. If the ghosts take the shortest path, every tile knows how far it
+    /// is from the entrance. Otherwise, like in the arcade, the ghost heads for the nearest position in front
+    /// of the house as the crow flies.
+    fn move_to_nearest_position_before_entrance(&mut self) {
+        if let Some(distances) = self.eaten_distances.and_then(|distances| distances.of_house(self.get_house(*self.components.ghost).index)) {
+            let next_target_neighbour = self.get_neighbour_closest_by_path(distances);
+            return self.set_target_to_neighbour(next_target_neighbour);
+        }
+
+        let position = Pos::from_vec3(self.components.transform.translation);
+        let nearest_spawn_position = self.get_house(*self.components.ghost)
+            .entrance_positions
+            .iter()
+            .copied()
+            .map(|pos| (pos, pos.distance(&position)))
+            .min_by(|(_, dis_a), (_, dis_b)| dis_a.partial_cmp(dis_b).unwrap())
+            .map(|(pos, _)| pos)
+            .unwrap();
+
+        let next_target_neighbour = self.get_nearest_neighbour_to(nearest_spawn_position);
+        self.set_target_to_neighbour(next_target_neighbour)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,694 @@
+use std::cmp::Ordering;
+
+use bevy::ecs::query::QueryData;
+use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+
+use crate::core::prelude::*;
+
+mod spawned;
+mod eaten;
+
+pub(in crate::game) type Neighbour = (Pos, Dir);
+
+/// Clyde flees to his corner when he comes closer to pacman than this many steps.
+const CLYDE_FLEE_STEPS: usize = 8;
+/// Clyde flees to his corner when he comes closer to pacman than this.
+pub(crate) const CLYDE_FLEE_DISTANCE: f32 = FIELD_SIZE * CLYDE_FLEE_STEPS as f32;
+
+pub(in crate::game) struct TargetPlugin;
+
+impl Plugin for TargetPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(OnEnter(Game(Start)), create_ghost_house_distances)
+            .add_systems(
+                Update,
+                set_target
+                    .in_set(SetTarget)
+                    .run_if(in_state(Game(Running))),
+            )
+            .add_systems(
+                Update,
+                set_target_on_ghost_pause
+                    .in_set(SetTarget)
+                    .run_if(in_state(Game(GhostEatenPause))),
+            )
+        ;
+    }
+}
+
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub struct TargetComponents<'a> {
+    ghost: &'a Ghost,
+    target: &'a mut Target,
+    direction: &'a mut Dir,
+    transform: &'a Transform,
+    state: &'a GhostState,
+    player_controlled: Option<&'a mut PlayerControlledGhost>,
+}
+
+fn set_target(
+    mut random: ResMut<Random>,
+    settings: Res<Settings>,
+    ruleset: Res<CurrentRuleset>,
+    schedule: Option<Res<GhostSchedule>>,
+    ghost_house_gate: Res<GhostHouseGate>,
+    ghost_house_distances: Option<Res<GhostHouseDistances>>,
+    walkable_tiles: Option<Res<WalkableTiles>>,
+    corner_query: Query<(&GhostCorner, &Tiles)>,
+    wall_query: Query<&Transform, With<Wall>>,
+    ghost_spawn_query: Query<&GhostSpawn>,
+    ghost_house_query: Query<&GhostHouse>,
+    pacman_query: Query<(&Transform, &Dir), With<Pacman>>,
+    one_ways: Query<&Tiles, With<OneWay>>,
+    mut ghost_query: Query<TargetComponents, Without<Pacman>>,
+) {
+    let pacmen = pacman_query.iter().map(|(transform, dir)| (*transform, *dir)).collect::<Vec<_>>();
+
+    if pacmen.is_empty() {
+        return;
+    }
+
+    let blinky_transform = get_blinky_transform(&ghost_query);
+    let eaten_distances = get_eaten_distances(&settings, &ghost_house_distances);
+    let path_tiles = get_path_tiles(&settings, &walkable_tiles);
+    let first_phase = schedule.is_some_and(|schedule| schedule.is_in_first_phase());
+
+    for mut components in &mut ghost_query {
+        if components.target.is_set() {
+            continue;
+        }
+
+        let (pm_transform, pm_dir) = get_nearest_pacman(&pacmen, components.transform);
+
+        let state = *components.state;
+        let scatters_randomly = ruleset.scatters_randomly(*components.ghost, first_phase);
+        let mut setter = TargetSetter::new(
+            &mut random,
+            &ghost_house_gate,
+            pm_transform,
+            pm_dir,
+            blinky_transform,
+            &corner_query,
+            &wall_query,
+            &ghost_spawn_query,
+            &ghost_house_query,
+            &one_ways,
+            eaten_distances,
+            path_tiles,
+            &mut components,
+        );
+
+        match state {
+            Chase | Scatter if setter.is_player_controlled() => setter.set_player_controlled_target(),
+            Chase => setter.set_chase_target(),
+            Scatter if scatters_randomly => setter.set_frightened_target(),
+            Scatter => setter.set_scatter_target(),
+            Frightened => setter.set_frightened_target(),
+            Eaten => setter.set_eaten_target(),
+            Spawned => setter.set_spawned_target(),
+        }
+    }
+}
+
+/// Set the target when on ghost pause (meaning only eaten and spawned)
+fn set_target_on_ghost_pause(
+    mut random: ResMut<Random>,
+    settings: Res<Settings>,
+    ghost_house_gate: Res<GhostHouseGate>,
+    ghost_house_distances: Option<Res<GhostHouseDistances>>,
+    walkable_tiles: Option<Res<WalkableTiles>>,
+    corner_query: Query<(&GhostCorner, &Tiles)>,
+    wall_query: Query<&Transform, With<Wall>>,
+    ghost_spawn_query: Query<&GhostSpawn>,
+    ghost_house_query: Query<&GhostHouse>,
+    pacman_query: Query<(&Transform, &Dir), With<Pacman>>,
+    one_ways: Query<&Tiles, With<OneWay>>,
+    mut ghost_query: Query<TargetComponents, Without<Pacman>>,
+) {
+    let pacmen = pacman_query.iter().map(|(transform, dir)| (*transform, *dir)).collect::<Vec<_>>();
+
+    if pacmen.is_empty() {
+        return;
+    }
+
+    let blinky_transform = get_blinky_transform(&ghost_query);
+    let eaten_distances = get_eaten_distances(&settings, &ghost_house_distances);
+    let path_tiles = get_path_tiles(&settings, &walkable_tiles);
+
+    for mut components in &mut ghost_query {
+        if components.target.is_set() {
+            continue;
+        }
+
+        let (pm_transform, pm_dir) = get_nearest_pacman(&pacmen, components.transform);
+
+        let state = *components.state;
+        let mut setter = TargetSetter::new(
+            &mut random,
+            &ghost_house_gate,
+            pm_transform,
+            pm_dir,
+            blinky_transform,
+            &corner_query,
+            &wall_query,
+            &ghost_spawn_query,
+            &ghost_house_query,
+            &one_ways,
+            eaten_distances,
+            path_tiles,
+            &mut components,
+        );
+
+        match state {
+            Eaten => setter.set_eaten_target(),
+            Spawned => setter.set_spawned_target(),
+            _ => continue
+        }
+    }
+}
+
+struct TargetSetter<'a, 'b, 'c> {
+    random: &'a mut Random,
+    ghost_house_gate: &'a GhostHouseGate,
+    pacman_transform: Transform,
+    pacman_direction: Dir,
+    blinky_transform: Transform,
+    corner_positions: HashMap<Ghost, Pos>,
+    wall_positions: HashSet<Pos>,
+    ghost_spawns: HashMap<Ghost, GhostSpawn>,
+    ghost_houses: HashMap<usize, GhostHouse>,
+    one_ways: HashSet<Pos>,
+    /// The distances eaten ghosts follow home, if they take the shortest path.
+    eaten_distances: Option<&'a GhostHouseDistances>,
+    /// The tiles to measure real path distances on, if the ghosts take the shortest path.
+    path_tiles: Option<&'a WalkableTiles>,
+    components: &'a mut TargetComponentsItem<'b, 'c>,
+}
+
+impl<'a, 'b, 'c> TargetSetter<'a, 'b, 'c> {
+    pub fn new(
+        random: &'a mut Random,
+        ghost_house_gate: &'a GhostHouseGate,
+        pacman_transform: Transform,
+        pacman_direction: Dir,
+        blinky_transform: Transform,
+        corner_query: &Query<(&GhostCorner, &Tiles)>,
+        wall_query: &Query<&Transform, With<Wall>>,
+        ghost_spawn_query: &Query<&GhostSpawn>,
+        ghost_house_query: &Query<&GhostHouse>,
+        one_ways: &Query<&Tiles, With<OneWay>>,
+        eaten_distances: Option<&'a GhostHouseDistances>,
+        path_tiles: Option<&'a WalkableTiles>,
+        components: &'a mut TargetComponentsItem<'b, 'c>,
+    ) -> Self {
+        let corner_positions = corner_query.iter().map(|(corner, tiles)| (**corner, tiles.to_pos())).collect();
+        let wall_positions = wall_query.iter().map(|transform| Pos::from_vec3(transform.translation)).collect();
+        let ghost_spawns = ghost_spawn_query.iter().map(|spawn| (spawn.ghost, *spawn)).collect();
+        let ghost_houses = ghost_house_query.iter().map(|house| (house.index, house.clone())).collect();
+        let one_ways = one_ways.iter().map(|t| t.to_pos()).collect();
+
+        Self {
+            random,
+            ghost_spawns,
+            ghost_houses,
+            ghost_house_gate,
+            pacman_transform,
+            pacman_direction,
+            blinky_transform,
+            corner_positions,
+            wall_positions,
+            one_ways,
+            eaten_distances,
+            path_tiles,
+            components
+        }
+    }
+
+    /// Every ghost has its own technique to hunt pacman, see [calculate_chase_target]. If the ghosts take the
+    /// shortest path, Clyde measures how close he is to pacman by the path through the maze.
+    fn set_chase_target(&mut self) {
+        let ghost = *self.components.ghost;
+        let pacman_position = Pos::from_vec3(self.pacman_transform.translation);
+        let corner = *self.corner_positions.get(&ghost).unwrap();
+        let target = match (ghost, self.path_tiles) {
+            (Clyde, Some(path_tiles)) => {
+                let clyde_position = Pos::from_vec3(self.components.transform.translation);
+                match clyde_is_near_pacman_by_path(path_tiles, clyde_position, pacman_position) {
+                    true => corner,
+                    false => pacman_position,
+                }
+            },
+            _ => calculate_chase_target(
+                ghost,
+                self.components.transform.translation,
+                pacman_position,
+                self.pacman_direction,
+                Pos::from_vec3(self.blinky_transform.translation),
+                corner,
+            ),
+        };
+        let next_target_neighbour = self.get_nearest_neighbour_to(target);
+        self.set_target_to_neighbour(next_target_neighbour)
+    }
+
+    fn set_scatter_target(&mut self) {
+        let corner_pos = *self.corner_positions.get(self.components.ghost).unwrap();
+        let next_target_neighbour = self.get_nearest_neighbour_to(corner_pos);
+        self.set_target_to_neighbour(next_target_neighbour)
+    }
+
+    fn is_player_controlled(&self) -> bool {
+        self.components.player_controlled.is_some()
+    }
+
+    /// Let the player decide where the ghost goes next. If the ghost cannot go in the wished direction yet,
+    /// it keeps its direction and the wish is kept for the next intersection. If it cannot go on either, it
+    /// takes any other way it is allowed to.
+    fn set_player_controlled_target(&mut self) {
+        let ghost_pos = Pos::from_vec3(self.components.transform.translation);
+        let opposite_dir = self.components.direction.opposite();
+        let current_dir = *self.components.direction;
+        let wished_dir = self.components.player_controlled.as_ref().and_then(|p| p.wished_direction);
+
+        let possible_neighbours = ghost_pos
+            .neighbours_with_directions()
+            .into_iter()
+            .filter(|(_, dir)| *dir != opposite_dir)
+            .filter(|(pos, _)| !self.wall_positions.contains(pos))
+            .filter(|(_, dir)| if self.is_on_one_way(ghost_pos) {
+                *dir == Left || *dir == Right
+            } else {
+                true
+            })
+            .collect::<Vec<_>>();
+
+        let wished_neighbour = possible_neighbours.iter().find(|(_, dir)| Some(*dir) == wished_dir);
+        let next_target_neighbour = match wished_neighbour {
+            Some(neighbour) => {
+                if let Some(ref mut player_controlled) = self.components.player_controlled {
+                    player_controlled.wished_direction = None;
+                }
+                *neighbour
+            },
+            None => possible_neighbours
+                .iter()
+                .find(|(_, dir)| *dir == current_dir)
+                .or(possible_neighbours.first())
+                .copied()
+                .unwrap_or_else(|| (ghost_pos.neighbour_in_direction(opposite_dir), opposite_dir))
+        };
+        self.set_target_to_neighbour(next_target_neighbour)
+    }
+
+    fn set_frightened_target(&mut self) {
+        let ghost_pos = Pos::from_vec3(self.components.transform.translation);
+        let opposite_dir = self.components.direction.opposite();
+
+        let possible_neighbours = ghost_pos
+            .neighbours_with_directions()
+            .into_iter()
+            .filter(|(_, dir)| *dir != opposite_dir)
+            .filter(|(pos, _)| !self.wall_positions.contains(pos))
+            .filter(|(_, dir)| if self.is_on_one_way(ghost_pos) {
+                *dir == Left || *dir == Right
+            } else {
+                true
+            })
+            .collect::<Vec<_>>();
+        let next_target_neighbour = match possible_neighbours.len() {
+            0 => (ghost_pos.neighbour_in_direction(opposite_dir), opposite_dir),
+            1 => possible_neighbours[0],
+            len => possible_neighbours[self.random.zero_to(len)]
+        };
+        self.set_target_to_neighbour(next_target_neighbour)
+    }
+
+    fn get_nearest_neighbour_to(&self, target: Pos) -> Neighbour {
+        let ghost_pos = Pos::from_vec3(self.components.transform.translation);
+
+        match self.path_tiles {
+            Some(path_tiles) => get_nearest_neighbour_by_path_to(ghost_pos, *self.components.direction, target, path_tiles, &self.wall_positions, &self.one_ways),
+            None => get_nearest_neighbour_to(ghost_pos, *self.components.direction, target, &self.wall_positions, &self.one_ways),
+        }
+    }
+
+    fn get_neighbour_closest_by_path(&self, distances: &DistanceField) -> Neighbour {
+        let ghost_pos = Pos::from_vec3(self.components.transform.translation);
+        get_neighbour_closest_by_path(ghost_pos, *self.components.direction, distances, &self.wall_positions, &self.one_ways)
+    }
+
+    fn is_on_one_way(&self, pos: Pos) -> bool {
+        self.one_ways.contains(&pos)
+    }
+
+    fn set_target_to_neighbour(&mut self, neighbour: Neighbour) {
+        *self.components.direction = neighbour.1;
+        self.components.target.set(neighbour.0.to_vec3(0.0));
+    }
+
+    fn get_spawn(&self, ghost: Ghost) -> &GhostSpawn {
+        self.ghost_spawns.get(&ghost).unwrap()
+    }
+
+    /// The ghost house the ghost lives in.
+    fn get_house(&self, ghost: Ghost) -> &GhostHouse {
+        self.ghost_houses.get(&self.get_spawn(ghost).house).unwrap()
+    }
+
+    /// Where the ghost walks to when it comes back to its house after being eaten.
+    fn get_respawn_coordinates(&self, ghost: Ghost) -> Vec3 {
+        self.get_house(ghost).respawn_coordinates(self.get_spawn(ghost))
+    }
+}
+
+/// Return the position a ghost is chasing. Every ghost has its own technique:
+///
+/// - Blinky goes straight for pacman.
+/// - Pinky aims 4 fields ahead of pacman.
+/// - Inky uses pacmans and blinkys position, see [calculate_inky_target].
+/// - Clyde goes for pacman, but flees to his corner when he comes closer than 8 fields.
+///
+/// These are the same rules the ghosts follow in the game, so AI agents can use them to predict the ghosts.
+pub(crate) fn calculate_chase_target(
+    ghost: Ghost,
+    ghost_coordinates: Vec3,
+    pacman_position: Pos,
+    pacman_direction: Dir,
+    blinky_position: Pos,
+    corner: Pos,
+) -> Pos {
+    match ghost {
+        Blinky => pacman_position,
+        Pinky => calculate_pinky_target(pacman_position, pacman_direction),
+        Inky => calculate_inky_target(pacman_position, pacman_direction, blinky_position),
+        Clyde => match clyde_is_near_pacman(ghost_coordinates, pacman_position) {
+            true => corner,
+            false => pacman_position,
+        },
+    }
+}
+
+/// Return the pinky target position 4 fields in pacmans direction.
+fn calculate_pinky_target(pacman_position: Pos, pacman_direction: Dir) -> Pos {
+    let x = pacman_position.x();
+    let y = pacman_position.y();
+
+    match pacman_direction {
+        Up => Pos::new(x, y + 4),
+        Down => Pos::new(x, y - 4),
+        Left => Pos::new(x - 4, y),
+        Right => Pos::new(x + 4, y),
+    }
+}
+
+/// Inky is moving to a field calculated by using pacmans and blinkys position.
+///
+/// 1. You take a field pacman is facing with two fields distance
+/// 2. You shoot a line from blinkys position trough this field
+/// 3. You double this distance. The field this line is ending on is inkys target.
+fn calculate_inky_target(pacman_position: Pos, pacman_direction: Dir, blinky_position: Pos) -> Pos {
+    let position_pacman_is_facing = inky_pivot(pacman_position, pacman_direction);
+    let x_diff = position_pacman_is_facing.x() - blinky_position.x();
+    let y_diff = position_pacman_is_facing.y() - blinky_position.y();
+    Pos::new(blinky_position.x() + 2 * x_diff, blinky_position.y() + 2 * y_diff)
+}
+
+/// The field two fields in front of pacman, which is the middle of the line from Blinky to Inky's target.
+pub(crate) fn inky_pivot(pacman_position: Pos, pacman_direction: Dir) -> Pos {
+    pacman_position.position_in_direction(pacman_direction, 2)
+}
+
+fn clyde_is_near_pacman(clyde_coordinates: Vec3, pacman_position: Pos) -> bool {
+    let pacman_coordinates = pacman_position.to_vec3(clyde_coordinates.z);
+    let distance = clyde_coordinates.distance(pacman_coordinates);
+    distance < CLYDE_FLEE_DISTANCE
+}
+
+/// Like [clyde_is_near_pacman], but counts the steps through the maze instead of measuring as the crow flies.
+/// A pacman Clyde cannot reach is never near.
+fn clyde_is_near_pacman_by_path(path_tiles: &WalkableTiles, clyde_position: Pos, pacman_position: Pos) -> bool {
+    path_tiles
+        .path_distance(clyde_position, pacman_position)
+        .is_some_and(|steps| steps < CLYDE_FLEE_STEPS)
+}
+
+/// Get the neighbour with the shortest distance (euclidean) to a given position. To filter not allowed
+/// positions, a specific filter is provided.
+///
+/// It is generally not allowed for ghosts to turn around, so the position behind the ghost is always filtered. However,
+/// if due to some circumstances (like bad map design) a ghost has no other way to go, we allow the poor soul to
+/// turn around.
+pub(in crate::game) fn get_nearest_neighbour_to(
+    ghost_pos: Pos,
+    ghost_direction: Dir,
+    target: Pos,
+    wall_positions: &HashSet<Pos>,
+    one_ways: &HashSet<Pos>,
+) -> Neighbour {
+    allowed_neighbours(ghost_pos, ghost_direction, wall_positions, one_ways)
+        .min_by(|n_a, n_b| minimal_distance_to_neighbours(&target, n_a, n_b))
+        .unwrap_or_else(|| turn_around(ghost_pos, ghost_direction))
+}
+
+/// Like [get_nearest_neighbour_to], but neighbours which are equally close to the target as the crow flies get
+/// told apart by their path through the maze. If the path does not decide either, like for a target inside a wall,
+/// the ghost picks like in the arcade.
+fn get_nearest_neighbour_by_path_to(
+    ghost_pos: Pos,
+    ghost_direction: Dir,
+    target: Pos,
+    path_tiles: &WalkableTiles,
+    wall_positions: &HashSet<Pos>,
+    one_ways: &HashSet<Pos>,
+) -> Neighbour {
+    let neighbours = allowed_neighbours(ghost_pos, ghost_direction, wall_positions, one_ways).collect::<Vec<_>>();
+    let Some(nearest) = neighbours.iter().min_by(|n_a, n_b| minimal_distance_to_neighbours(&target, n_a, n_b)) else {
+        return turn_around(ghost_pos, ghost_direction);
+    };
+    let ties = neighbours
+        .iter()
+        .filter(|neighbour| minimal_distance_to_neighbours(&target, neighbour, nearest) == Ordering::Equal)
+        .collect::<Vec<_>>();
+
+    if ties.len() == 1 {
+        return *nearest;
+    }
+
+    let distances = path_tiles.distance_field([target]);
+    **ties
+        .iter()
+        .min_by_key(|(pos, _)| distances.distance(*pos).unwrap_or(usize::MAX))
+        .unwrap()
+}
+
+/// Get the neighbour with the shortest path through the maze to the goals of the distance field. Unlike
+/// [get_nearest_neighbour_to], this never runs in circles, even in mazes with long walls between the
+/// ghost and its goal. The same rules about turning around and one ways apply.
+pub(in crate::game) fn get_neighbour_closest_by_path(
+    ghost_pos: Pos,
+    ghost_direction: Dir,
+    distances: &DistanceField,
+    wall_positions: &HashSet<Pos>,
+    one_ways: &HashSet<Pos>,
+) -> Neighbour {
+    allowed_neighbours(ghost_pos, ghost_direction, wall_positions, one_ways)
+        .min_by_key(|(pos, _)| distances.distance(*pos).unwrap_or(usize::MAX))
+        .unwrap_or_else(|| turn_around(ghost_pos, ghost_direction))
+}
+
+/// The neighbours a ghost may walk to next: no walls, no turning around and only left or right on a one way.
+fn allowed_neighbours<'a>(
+    ghost_pos: Pos,
+    ghost_direction: Dir,
+    wall_positions: &'a HashSet<Pos>,
+    one_ways: &'a HashSet<Pos>,
+) -> impl Iterator<Item=Neighbour> + 'a {
+    let opposite_dir = ghost_direction.opposite();
+    let on_one_way = one_ways.contains(&ghost_pos);
+
+    ghost_pos
+        .neighbours_with_directions()
+        .into_iter()
+        .filter(move |(_, dir)| *dir != opposite_dir)
+        .filter(|(pos, _)| !wall_positions.contains(pos))
+        .filter(move |(_, dir)| !on_one_way || *dir == Left || *dir == Right)
+}
+
+fn turn_around(ghost_pos: Pos, ghost_direction: Dir) -> Neighbour {
+    let opposite_dir = ghost_direction.opposite();
+    (ghost_pos.neighbour_in_direction(opposite_dir), opposite_dir)
+}
+
+/// The distances eaten ghosts follow home, or None if they use the arcade routing.
+fn get_eaten_distances<'a>(settings: &Settings, ghost_house_distances: &'a Option<Res<GhostHouseDistances>>) -> Option<&'a GhostHouseDistances> {
+    match settings.eaten_ghost_routing {
+        GhostRouting::Arcade => None,
+        GhostRouting::ShortestPath => ghost_house_distances.as_deref(),
+    }
+}
+
+/// The tiles to measure real path distances on, or None if the ghosts use the arcade routing.
+fn get_path_tiles<'a>(settings: &Settings, walkable_tiles: &'a Option<Res<WalkableTiles>>) -> Option<&'a WalkableTiles> {
+    match settings.eaten_ghost_routing {
+        GhostRouting::Arcade => None,
+        GhostRouting::ShortestPath => walkable_tiles.as_deref(),
+    }
+}
+
+/// Calculate how far every tile of the maze is from the entrance of every ghost house. The ghost houses
+/// and the walls are blocked, the tunnels connect both ends of the maze.
+fn create_ghost_house_distances(
+    mut commands: Commands,
+    map_query: Query<&Map>,
+    wall_query: Query<&Transform, With<Wall>>,
+    ghost_house_area_query: Query<&Tiles, With<GhostHouseArea>>,
+    tunnel_query: Query<(&Tunnel, &Tiles)>,
+    ghost_house_query: Query<&GhostHouse>,
+) {
+    let Ok(map) = map_query.get_single() else {
+        return;
+    };
+
+    let blocked = wall_query
+        .iter()
+        .map(|transform| Pos::from_vec3(transform.translation))
+        .chain(ghost_house_area_query.iter().map(|tiles| tiles.to_pos()));
+    let tunnels = tunnel_query
+        .iter()
+        .flat_map(|(tunnel_a, tiles_a)| tunnel_query
+            .iter()
+            .filter(move |(tunnel_b, tiles_b)| tunnel_a.index == tunnel_b.index && tiles_a.to_pos() != tiles_b.to_pos())
+            .map(move |(tunnel_b, tiles_b)| ((tiles_a.to_pos(), tunnel_a.direction), (tiles_b.to_pos(), tunnel_b.direction)))
+        );
+    let walkable_tiles = WalkableTiles::new(map.width, map.height, blocked, tunnels);
+    let distances = ghost_house_query
+        .iter()
+        .map(|house| (house.index, walkable_tiles.distance_field(house.entrance_positions.iter().copied())))
+        .collect();
+
+    commands.insert_resource(GhostHouseDistances(distances));
+    commands.insert_resource(walkable_tiles);
+}
+
+/// Get the transform and direction of the pacman which is closest to the ghost. In a co-op
+/// game, every ghost hunts the pacman next to it.
+fn get_nearest_pacman(pacmen: &[(Transform, Dir)], ghost_transform: &Transform) -> (Transform, Dir) {
+    *pacmen.iter()
+        .min_by(|(a, _), (b, _)| {
+            let distance_a = a.translation.distance(ghost_transform.translation);
+            let distance_b = b.translation.distance(ghost_transform.translation);
+            distance_a.total_cmp(&distance_b)
+        })
+        .expect("there should be at least one pacman")
+}
+
+/// Get the transform of blinky.
+fn get_blinky_transform(query: &Query<TargetComponents, Without<Pacman>>) -> Transform {
+    query.iter()
+        .filter(|comps| comps.ghost == &Blinky)
+        .map(|comps| *comps.transform)
+        .next()
+        .expect("there should be one blinky")
+}
+
+fn minimal_distance_to_neighbours(big_target: &Pos, neighbour_a: &Neighbour, neighbour_b: &Neighbour) -> Ordering {
+    minimal_distance_to_positions(big_target, &neighbour_a.0, &neighbour_b.0)
+}
+
+fn minimal_distance_to_positions(big_target: &Pos, position_a: &Pos, position_b: &Pos) -> Ordering {
+    big_target.distance(position_a).partial_cmp(&big_target.distance(position_b)).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use bevy::utils::HashSet;
+
+    use crate::core::prelude::*;
+    use crate::scenario::Scenario;
+    use super::{clyde_is_near_pacman, clyde_is_near_pacman_by_path, get_nearest_neighbour_by_path_to};
+
+    /// The tiles an eaten Blinky walks on from the bottom right corner of the maze until he stands in front of
+    /// the ghost house, together with the distances of every tile to the ghost house.
+    fn walk_home(routing: GhostRouting) -> (Vec<Pos>, DistanceField) {
+        let mut scenario = Scenario::with_settings(Settings {
+            game_mode: GameMode::SinglePlayer,
+            muted: true,
+            eaten_ghost_routing: routing,
+            ..default()
+        });
+        scenario
+            .place_pacman(Pos::new(26, 29), Left)
+            .place_ghost(Blinky, Pos::new(26, 2), Left, Eaten);
+
+        let distances = scenario.resource::<GhostHouseDistances>().of_house(0).unwrap().clone();
+        let mut tiles = vec![scenario.ghost_tile(Blinky)];
+        scenario.advance_until(600, |s| {
+            let tile = s.ghost_tile(Blinky);
+
+            if tiles.last() != Some(&tile) {
+                tiles.push(tile);
+            }
+
+            distances.distance(tile) == Some(0)
+        });
+
+        (tiles, distances)
+    }
+
+    #[test]
+    fn eaten_ghosts_can_take_the_shortest_path_home() {
+        let (shortest, distances) = walk_home(GhostRouting::ShortestPath);
+        let (arcade, _) = walk_home(GhostRouting::Arcade);
+
+        assert_eq!(distances.distance(Pos::new(26, 2)), Some(30));
+        assert_eq!(shortest.len() - 1, 30);
+        assert!(shortest.windows(2).all(|step| distances.distance(step[1]).unwrap() + 1 == distances.distance(step[0]).unwrap()));
+        assert!(arcade.len() > shortest.len());
+    }
+
+    #[test]
+    fn clyde_counts_the_steps_around_walls_with_the_shortest_path() {
+        // a wall splits the maze in two rows, which are only connected at the right end
+        let tiles = WalkableTiles::new(10, 3, (0..9).map(|x| Pos::new(x, 1)), []);
+        let (clyde, pacman) = (Pos::new(0, 0), Pos::new(0, 2));
+
+        assert!(clyde_is_near_pacman(clyde.to_vec3(0.0), pacman));
+        assert!(!clyde_is_near_pacman_by_path(&tiles, clyde, pacman));
+        assert!(clyde_is_near_pacman_by_path(&tiles, Pos::new(6, 2), pacman));
+    }
+
+    #[test]
+    fn ghosts_break_ties_by_the_shorter_path() {
+        // the ghost stands above the wall right between two equally distant ways down to the target,
+        // and the wall reaches further to the side which is blocked
+        for (blocked_side, shorter_side) in [(Left, Right), (Right, Left)] {
+            let ghost = Pos::new(2, 2);
+            let walls = [Pos::new(2, 1), ghost.neighbour_in_direction(blocked_side).neighbour_in_direction(Down)];
+            let tiles = WalkableTiles::new(5, 3, walls, []);
+            let (next, direction) = get_nearest_neighbour_by_path_to(
+                ghost,
+                Down,
+                Pos::new(2, 0),
+                &tiles,
+                &walls.into_iter().collect(),
+                &HashSet::new(),
+            );
+
+            assert_eq!(direction, shorter_side);
+            assert_eq!(next, ghost.neighbour_in_direction(shorter_side));
+        }
+    }
+
+    #[test]
+    fn the_distance_to_the_ghost_house_goes_through_the_tunnel() {
+        let scenario = Scenario::new();
+        let distances = scenario.resource::<GhostHouseDistances>().of_house(0).unwrap();
+
+        assert_eq!(distances.distance(Pos::new(0, 17)), distances.distance(Pos::new(27, 17)));
+        assert_eq!(distances.distance(Pos::new(-1, 17)), distances.distance(Pos::new(27, 17)));
+    }
+}
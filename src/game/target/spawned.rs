@@ -0,0 +1,113 @@
+use bevy::prelude::*;
+use crate::game::target::TargetSetter;
+use crate::core::prelude::*;
+
+impl<'a, 'b, 'c> TargetSetter<'a, 'b, 'c> {
+    /// Determine the next target coordinates for a ghost when in "Spawned" state.
+    ///
+    /// A ghost can only leave the house if their dot counter reached its predefined limit.
+    /// When ready to leave, the ghost moves from its spawn to the house center, from the center to
+    /// the entrance and from the entrance were ever his destiny leads him.
+    ///
+    /// If a ghost cannot leave the house yet, he just moves around, eager to leave and hunt pacman.
+    pub fn set_spawned_target(&mut self) {
+        if self.ghost_house_gate.ghost_can_leave_house(self.components.ghost) {
+            self.leave_house()
+        } else {
+            self.bounce_around()
+        }
+    }
+
+    /// If a ghost cannot leave the ghost house, he just moves around.
+    fn bounce_around(&mut self) {
+        let coordinates = self.components.transform.translation;
+        let respawn = self.get_spawn(*self.components.ghost).coordinates;
+        let exit_direction = self.get_house(*self.components.ghost).exit_direction;
+        let above_respawn = self.coordinates_slightly_in_direction(respawn, exit_direction);
+        let below_respawn = self.coordinates_slightly_in_direction(respawn, exit_direction.opposite());
+
+        if coordinates.xy_equal(&respawn) {
+            match *self.components.direction {
+                dir if dir == exit_direction => self.components.target.set(above_respawn),
+                _ => self.components.target.set(below_respawn)
+            };
+        } else if coordinates.xy_equal(&above_respawn) {
+            self.components.target.set(below_respawn);
+            *self.components.direction = exit_direction.opposite();
+        } else if coordinates.xy_equal(&below_respawn) {
+            self.components.target.set(above_respawn);
+            *self.components.direction = exit_direction;
+        }
+    }
+
+    fn leave_house(&mut self) {
+        if self.is_near_center() {
+            self.move_to_entrance()
+        } else if self.is_near_spawn() {
+            self.move_near_center()
+        }
+    }
+
+    fn is_near_center(&self) -> bool {
+        let coordinates = self.components.transform.translation;
+        let house = self.get_house(*self.components.ghost);
+        let center = house.center_coordinates;
+
+        match house.exit_direction {
+            Up | Down => coordinates.x == center.x,
+            Left | Right => coordinates.y == center.y,
+        }
+    }
+
+    fn move_to_entrance(&mut self) {
+        let house = self.get_house(*self.components.ghost);
+        let (exit_direction, entrance_coordinates) = (house.exit_direction, house.entrance_coordinates);
+        *self.components.direction = exit_direction;
+        self.components.target.set(entrance_coordinates);
+    }
+
+    fn is_near_spawn(&self) -> bool {
+        let coordinates = self.components.transform.translation;
+        let respawn = self.get_respawn_coordinates(*self.components.ghost);
+
+        match self.get_house(*self.components.ghost).exit_direction {
+            Up | Down => coordinates.x == respawn.x,
+            Left | Right => coordinates.y == respawn.y,
+        }
+    }
+
+    fn move_near_center(&mut self) {
+        let coordinates = self.components.transform.translation;
+        let house = self.get_house(*self.components.ghost);
+        let (center, exit_direction) = (house.center_coordinates, house.exit_direction);
+        let respawn = self.get_spawn(*self.components.ghost).coordinates;
+
+        *self.components.direction = match exit_direction {
+            Up | Down => match respawn.x < center.x {
+                true => Right,
+                false => Left
+            },
+            Right | Left => match respawn.y < center.y {
+                true => Up,
+                false => Down
+            },
+        };
+
+        match exit_direction {
+            Up | Down => self.components.target.set(Vec3::new(center.x, coordinates.y, 0.0)),
+            Left | Right => self.components.target.set(Vec3::new(coordinates.x, center.y, 0.0)),
+        }
+    }
+
+    /// A ghost in the ghost house does not walk a full field in the ghost house (because he would clip into the wall).
+    /// When bouncing around in the ghost house, he only moves slightly in one direction.
+    fn coordinates_slightly_in_direction(&self, v: Vec3, d: Dir) -> Vec3 {
+        let distance = FIELD_SIZE / 2.0;
+        match d {
+            Up => Vec3::new(v.x, v.y + distance, v.z),
+            Down => Vec3::new(v.x, v.y - distance, v.z),
+            Left => Vec3::new(v.x - distance, v.y, v.z),
+            Right => Vec3::new(v.x + distance, v.y, v.z),
+        }
+    }
+}
\ No newline at end of file
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 use bevy::prelude::PositionType::Absolute;
-use bevy::prelude::Val::Percent;
+use bevy::prelude::Val::{Percent, Px};
 use crate::core::prelude::*;
 
 pub(super) struct BottomUIPlugin;
@@ -46,23 +46,26 @@ struct UIFruits;
 #[derive(Component)]
 struct UIFruit;
 
+/// Lives and fruits in the bottom ui are as big as pacman and the fruits in the maze.
+const UI_ICON_SIZE: f32 = 2.0 * FIELD_SIZE;
+
 fn spawn_bottom_ui(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    theme: Res<Theme>,
     lives: Res<Lives>,
     level: Res<Level>,
-    specs_per_level: Res<SpecsPerLevel>,
+    fruit_tables: Query<&FruitTable>,
 ) {
     let bottom_ui = commands.spawn((
         Name::new("BottomUI"),
         BottomUI,
         NodeBundle {
             style: Style {
-                width: Percent(40.0),
-                height: Percent(10.0),
+                width: Percent(100.0),
+                height: Px(HUD_BOTTOM_ROWS as f32 * FIELD_SIZE),
                 justify_content: JustifyContent::SpaceBetween,
-                top: Percent(90.0),
-                left: Percent(30.0),
+                bottom: Px(0.0),
+                padding: UiRect::horizontal(Px(2.0 * FIELD_SIZE)),
                 position_type: Absolute,
                 ..default()
             },
@@ -70,15 +73,15 @@ fn spawn_bottom_ui(
         }
     )).id();
 
-    let ui_lives = spawn_ui_lives(&mut commands, &asset_server, &lives);
-    let ui_fruits = spawn_ui_fruits(&mut commands, &asset_server, &level, &specs_per_level);
+    let ui_lives = spawn_ui_lives(&mut commands, &theme, &lives);
+    let ui_fruits = spawn_ui_fruits(&mut commands, &theme, &level, fruit_tables.single());
 
     commands.entity(bottom_ui).push_children(&[ui_lives, ui_fruits]);
 }
 
 fn spawn_ui_lives(
     commands: &mut Commands,
-    asset_server: &AssetServer,
+    theme: &Theme,
     lives: &Lives,
 ) -> Entity {
     let ui_lives = commands.spawn((
@@ -86,19 +89,16 @@ fn spawn_ui_lives(
         UILives,
         NodeBundle {
             style: Style {
-                width: Percent(40.0),
-                height: Percent(50.0),
-                position_type: Absolute,
-                bottom: Percent(40.0),
-                justify_content: JustifyContent::SpaceBetween,
+                height: Percent(100.0),
+                justify_content: JustifyContent::FlexStart,
                 ..default()
             },
             ..default()
         }
     )).id();
 
-    let ui_live_vec = (0..**lives).into_iter()
-        .map(|i| spawn_ui_live(i, commands, asset_server))
+    let ui_live_vec = (0..**lives)
+        .map(|_| spawn_ui_live(commands, theme))
         .collect::<Vec<_>>();
 
     commands.entity(ui_lives).push_children(&ui_live_vec);
@@ -106,21 +106,18 @@ fn spawn_ui_lives(
 }
 
 fn spawn_ui_live(
-    index: usize,
     commands: &mut Commands,
-    asset_server: &AssetServer,
+    theme: &Theme,
 ) -> Entity {
-    let image = asset_server.load("textures/pacman/pacman_life.png");
+    let image = theme.image(&SpriteId::PACMAN_LIFE);
     commands.spawn((
         Name::new("UILive"),
         UILive,
         ImageBundle {
             image: UiImage::new(image.clone()),
             style: Style {
-                width: Percent(20.0),
-                height: Percent(100.0),
-                left: Percent(index as f32 * 20.0),
-                position_type: Absolute,
+                width: Px(UI_ICON_SIZE),
+                height: Px(UI_ICON_SIZE),
                 ..default()
             },
             ..default()
@@ -130,56 +127,51 @@ fn spawn_ui_live(
 
 fn spawn_ui_fruits(
     commands: &mut Commands,
-    asset_server: &AssetServer,
+    theme: &Theme,
     level: &Level,
-    specs_per_level: &SpecsPerLevel,
+    fruit_table: &FruitTable,
 ) -> Entity {
     let ui_fruits = commands.spawn((
         Name::new("UIFruits"),
         UIFruits,
         NodeBundle {
             style: Style {
-                width: Percent(60.0),
-                height: Percent(50.0),
-                position_type: Absolute,
-                left: Percent(40.0),
-                bottom: Percent(40.0),
-                justify_content: JustifyContent::SpaceBetween,
+                height: Percent(100.0),
+                // the fruit of the current level is the first child, but should be displayed on the far right
+                flex_direction: FlexDirection::RowReverse,
                 ..default()
             },
             ..default()
         }
     )).id();
 
-    let fruits_to_display = get_fruits_to_display(&level, &specs_per_level);
+    let fruits_to_display = get_fruits_to_display(level, fruit_table);
 
-    for (i, fruit) in fruits_to_display.into_iter().enumerate() {
-        let ui_fruit = spawn_ui_fruit(commands, asset_server, i, fruit);
+    for sprite in fruits_to_display {
+        let ui_fruit = spawn_ui_fruit(commands, theme, &sprite);
         commands.entity(ui_fruits).push_children(&[ui_fruit]);
     }
 
     ui_fruits
 }
 
+/// The sprites of the fruits of the current level and the six levels before.
 fn get_fruits_to_display(
     level: &Level,
-    specs_per_level: &SpecsPerLevel,
-) -> Vec<Fruit> {
+    fruit_table: &FruitTable,
+) -> Vec<SpriteId> {
     let border = level.checked_sub(6).unwrap_or(1).max(1);
     (border..=**level).rev()
-        .into_iter()
-        .map(|i| specs_per_level.get_for(&Level(i)).fruit_to_spawn)
+        .map(|i| fruit_table.for_level(&Level(i)).sprite())
         .collect()
 }
 
 fn spawn_ui_fruit(
     commands: &mut Commands,
-    asset_server: &AssetServer,
-    index: usize,
-    fruit: Fruit,
+    theme: &Theme,
+    sprite: &SpriteId,
 ) -> Entity {
-    let image = get_texture_for_fruit(&fruit, asset_server);
-    let left_percent = 100.0 - index as f32 * (100.0 / 7.0) - 100.0 / 7.0;
+    let image = theme.image(sprite);
 
     commands.spawn((
         Name::new("UIFruit"),
@@ -187,10 +179,8 @@ fn spawn_ui_fruit(
         ImageBundle {
             image: UiImage::new(image),
             style: Style {
-                width: Percent(100.0 / 7.0),
-                height: Percent(100.0),
-                left: Percent(left_percent),
-                position_type: Absolute,
+                width: Px(UI_ICON_SIZE),
+                height: Px(UI_ICON_SIZE),
                 ..default()
             },
             ..default()
@@ -202,7 +192,7 @@ fn spawn_ui_fruit(
 fn update_lives(
     mut commands: Commands,
     lives: Res<Lives>,
-    asset_server: Res<AssetServer>,
+    theme: Res<Theme>,
     bottom_ui_query: Query<Entity, With<BottomUI>>,
     ui_lives_query: Query<Entity, With<UILives>>,
 ) {
@@ -212,17 +202,18 @@ fn update_lives(
         }
 
         for bottom_ui in &bottom_ui_query {
-            let ui_lives = spawn_ui_lives(&mut commands, &asset_server, &lives);
-            commands.entity(bottom_ui).push_children(&[ui_lives]);
+            let ui_lives = spawn_ui_lives(&mut commands, &theme, &lives);
+            // the lives must stay the first child, so they remain on the left side
+            commands.entity(bottom_ui).insert_children(0, &[ui_lives]);
         }
     }
 }
 
 fn update_fruits(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    theme: Res<Theme>,
     level: Res<Level>,
-    specs_per_level: Res<SpecsPerLevel>,
+    fruit_tables: Query<&FruitTable>,
     bottom_ui_query: Query<Entity, With<BottomUI>>,
     ui_fruits_query: Query<Entity, With<UIFruits>>,
 ) {
@@ -232,7 +223,7 @@ fn update_fruits(
         }
 
         for bottom_ui in &bottom_ui_query {
-            let ui_fruits = spawn_ui_fruits(&mut commands, &asset_server, &level, &specs_per_level);
+            let ui_fruits = spawn_ui_fruits(&mut commands, &theme, &level, fruit_tables.single());
             commands.entity(bottom_ui).push_children(&[ui_fruits]);
         }
     }
@@ -29,43 +29,47 @@ struct RestartGameScreen;
 
 fn spawn_screens(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    theme: Res<Theme>,
+    localization: Res<Localization>,
 ) {
+    let text_style = theme.text_style(&ColorId::GAME_OVER_TEXT);
+
     commands.spawn((
         Name::new("GameOverScreen"),
         GameOverScreen,
-        TextBundle::from_section(
-            "GAME OVER",
-            TextStyle {
-                font: asset_server.load(FONT),
-                font_size: 20.0,
-                color: Color::srgb(1.0, 0.0, 0.0),
-            },
-        ).with_style(Style {
-            position_type: PositionType::Absolute,
-            left: Percent(42.5),
-            top: Percent(55.0),
-            ..default()
-        }),
-    ));
+        centered_row(Val::Percent(55.0), Val::Auto)
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section(
+            localization.text(&TextId::GAME_OVER),
+            text_style.clone(),
+        ));
+    });
 
     commands.spawn((
         Name::new("RestartGameScreen"),
         RestartGameScreen,
-        TextBundle::from_section(
-            "Press R to restart",
-            TextStyle {
-                font: asset_server.load(FONT),
-                font_size: 20.0,
-                color: Color::srgb(1.0, 0.0, 0.0),
-            },
-        ).with_style(Style {
+        centered_row(Val::Auto, Val::Px(0.0))
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section(
+            localization.text(&TextId::RESTART),
+            text_style,
+        ));
+    });
+}
+
+/// A full width row which centers its content horizontally.
+fn centered_row(top: Val, bottom: Val) -> NodeBundle {
+    NodeBundle {
+        style: Style {
             position_type: PositionType::Absolute,
-            left: Percent(37.5),
-            top: Percent(96.0),
+            width: Percent(100.0),
+            top,
+            bottom,
+            justify_content: JustifyContent::Center,
             ..default()
-        }),
-    ));
+        },
+        ..default()
+    }
 }
 
 fn despawn_screens(
@@ -73,6 +77,6 @@ fn despawn_screens(
     query: Query<Entity, Or<(With<GameOverScreen>, With<RestartGameScreen>)>>,
 ) {
     for e in &query {
-        commands.entity(e).despawn();
+        commands.entity(e).despawn_recursive();
     }
 }
\ No newline at end of file
@@ -2,12 +2,14 @@ use bevy::prelude::*;
 use crate::game::ui::bottom::BottomUIPlugin;
 use crate::game::ui::game_over_screen::GameOverScreenPlugin;
 use crate::game::ui::ready_screen::ReadyScreenPlugin;
+use crate::game::ui::settings_menu::SettingsMenuPlugin;
 use crate::game::ui::top::TopUIPlugin;
 
 mod top;
 mod bottom;
 mod game_over_screen;
 mod ready_screen;
+mod settings_menu;
 
 pub(super) struct UIPlugin;
 
@@ -18,7 +20,8 @@ impl Plugin for UIPlugin {
                 TopUIPlugin,
                 BottomUIPlugin,
                 ReadyScreenPlugin,
-                GameOverScreenPlugin
+                GameOverScreenPlugin,
+                SettingsMenuPlugin
             ))
         ;
     }
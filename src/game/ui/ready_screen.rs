@@ -23,34 +23,65 @@ impl Plugin for ReadyScreenPlugin {
 #[derive(Component)]
 struct ReadyScreen;
 
+/// Identifies the cyan text above the ghost house which tells whose turn it is in an alternating game.
+#[derive(Component)]
+struct PlayerScreen;
+
 fn spawn_screen(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    theme: Res<Theme>,
+    localization: Res<Localization>,
+    active_player: Res<ActivePlayer>,
+    game_mode: Res<CurrentGameMode>,
 ) {
+    if **game_mode == GameMode::Alternating {
+        commands.spawn((
+            Name::new("PlayerScreen"),
+            PlayerScreen,
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Percent(100.0),
+                    top: Percent(38.0),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                ..default()
+            }
+        )).with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                localization.text(&TextId::player_name(**active_player)),
+                theme.text_style(&ColorId::PLAYER_TEXT),
+            ));
+        });
+    }
+
     commands.spawn((
         Name::new("ReadyScreen"),
         ReadyScreen,
-        TextBundle::from_section(
-            "Ready!",
-            TextStyle {
-                font: asset_server.load(FONT),
-                font_size: 20.0,
-                color: Color::srgb(1.0, 1.0, 0.0),
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Percent(100.0),
+                top: Percent(55.0),
+                justify_content: JustifyContent::Center,
+                ..default()
             },
-        ).with_style(Style {
-            position_type: PositionType::Absolute,
-            left: Percent(45.0),
-            top: Percent(55.0),
             ..default()
-        }),
-    ));
+        }
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section(
+            localization.text(&TextId::READY),
+            theme.text_style(&ColorId::READY_TEXT),
+        ));
+    });
 }
 
 fn despawn_screen(
     mut commands: Commands,
-    query: Query<Entity, With<ReadyScreen>>,
+    query: Query<Entity, Or<(With<ReadyScreen>, With<PlayerScreen>)>>,
 ) {
     for entity in query.iter() {
-        commands.entity(entity).despawn()
+        commands.entity(entity).despawn_recursive()
     }
 }
\ No newline at end of file
@@ -0,0 +1,668 @@
+use std::ops::Range;
+
+use bevy::prelude::*;
+use bevy::prelude::Val::{Percent, Px};
+use crate::core::prelude::*;
+
+pub(super) struct SettingsMenuPlugin;
+
+impl Plugin for SettingsMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(
+                Update,
+                (
+                    toggle_menu,
+                    (
+                        switch_page,
+                        select_entry,
+                        change_value,
+                        rebind_key,
+                        update_entries,
+                        scroll_entries,
+                    ).chain().run_if(resource_exists::<SettingsMenu>),
+                ).chain().run_if(in_game)
+            )
+        ;
+    }
+}
+
+/// Exists while the settings menu is open. The game is paused during this time.
+#[derive(Resource, Default)]
+struct SettingsMenu {
+    page: MenuPage,
+    selected: usize,
+    /// If true, the next pressed key gets bound to the selected direction.
+    rebinding: bool,
+}
+
+/// The settings are split into pages, which are switched with Tab.
+#[derive(Copy, Clone, Default, Eq, PartialEq)]
+enum MenuPage {
+    #[default]
+    Settings,
+    /// The starting conditions of a practice game
+    Practice,
+    Accessibility,
+}
+
+impl MenuPage {
+    fn entries(&self) -> &'static [SettingsEntry] {
+        match self {
+            MenuPage::Settings => &ENTRIES,
+            MenuPage::Practice => &PRACTICE_ENTRIES,
+            MenuPage::Accessibility => &ACCESSIBILITY_ENTRIES,
+        }
+    }
+
+    fn title(&self) -> TextId {
+        match self {
+            MenuPage::Settings => TextId::new("menu_settings_title"),
+            MenuPage::Practice => TextId::new("menu_practice_title"),
+            MenuPage::Accessibility => TextId::new("menu_accessibility_title"),
+        }
+    }
+
+    fn next(&self) -> Self {
+        match self {
+            MenuPage::Settings => MenuPage::Practice,
+            MenuPage::Practice => MenuPage::Accessibility,
+            MenuPage::Accessibility => MenuPage::Settings,
+        }
+    }
+}
+
+/// Marks the root node of the settings menu.
+#[derive(Component)]
+struct SettingsMenuScreen;
+
+/// Shows that the page has more entries above or beneath the visible ones.
+#[derive(Component, Copy, Clone, Eq, PartialEq)]
+enum MoreEntries {
+    Above,
+    Beneath,
+}
+
+/// How many entries of a page are shown at once. Longer pages scroll with the selected entry, as the
+/// menu must fit into the height of the maze.
+const VISIBLE_ENTRIES: usize = 14;
+
+/// One row in the settings menu, displaying the name and value of one setting.
+#[derive(Component, Copy, Clone, Eq, PartialEq)]
+enum SettingsEntry {
+    MasterVolume,
+    Volume(AudioBus),
+    Fullscreen,
+    Scale,
+    StartingLives,
+    ExtraLife,
+    Difficulty,
+    GameMode,
+    Ruleset,
+    Controller,
+    Theme,
+    Language,
+    Binding(Player, Dir),
+    Practice,
+    PracticeLevel,
+    PracticeLives,
+    InfiniteLives,
+    ClearedDots(MazeSection),
+    ClearedEnergizer(MazeSection),
+    GhostPalette,
+    GhostMarkers,
+    HighContrastMaze,
+    FrightenedWarning,
+}
+
+const ENTRIES: [SettingsEntry; 22] = [
+    SettingsEntry::MasterVolume,
+    SettingsEntry::Volume(AudioBus::Music),
+    SettingsEntry::Volume(AudioBus::Sfx),
+    SettingsEntry::Volume(AudioBus::Ui),
+    SettingsEntry::Fullscreen,
+    SettingsEntry::Scale,
+    SettingsEntry::StartingLives,
+    SettingsEntry::ExtraLife,
+    SettingsEntry::Difficulty,
+    SettingsEntry::GameMode,
+    SettingsEntry::Ruleset,
+    SettingsEntry::Controller,
+    SettingsEntry::Theme,
+    SettingsEntry::Language,
+    SettingsEntry::Binding(Player::One, Up),
+    SettingsEntry::Binding(Player::One, Down),
+    SettingsEntry::Binding(Player::One, Left),
+    SettingsEntry::Binding(Player::One, Right),
+    SettingsEntry::Binding(Player::Two, Up),
+    SettingsEntry::Binding(Player::Two, Down),
+    SettingsEntry::Binding(Player::Two, Left),
+    SettingsEntry::Binding(Player::Two, Right),
+];
+
+const PRACTICE_ENTRIES: [SettingsEntry; 12] = [
+    SettingsEntry::Practice,
+    SettingsEntry::PracticeLevel,
+    SettingsEntry::PracticeLives,
+    SettingsEntry::InfiniteLives,
+    SettingsEntry::ClearedDots(MazeSection::TopLeft),
+    SettingsEntry::ClearedDots(MazeSection::TopRight),
+    SettingsEntry::ClearedDots(MazeSection::BottomLeft),
+    SettingsEntry::ClearedDots(MazeSection::BottomRight),
+    SettingsEntry::ClearedEnergizer(MazeSection::TopLeft),
+    SettingsEntry::ClearedEnergizer(MazeSection::TopRight),
+    SettingsEntry::ClearedEnergizer(MazeSection::BottomLeft),
+    SettingsEntry::ClearedEnergizer(MazeSection::BottomRight),
+];
+
+const ACCESSIBILITY_ENTRIES: [SettingsEntry; 4] = [
+    SettingsEntry::GhostPalette,
+    SettingsEntry::GhostMarkers,
+    SettingsEntry::HighContrastMaze,
+    SettingsEntry::FrightenedWarning,
+];
+
+const VOLUME_STEP: f32 = 0.1;
+const MAX_SCALE: u32 = 8;
+const MAX_STARTING_LIVES: usize = 9;
+const EXTRA_LIFE_STEP: usize = 5000;
+const MAX_EXTRA_LIFE_THRESHOLD: usize = 100000;
+
+impl SettingsEntry {
+    fn label(&self) -> TextId {
+        TextId::new(match self {
+            SettingsEntry::MasterVolume => "menu_volume",
+            SettingsEntry::Volume(AudioBus::Music) => "menu_music",
+            SettingsEntry::Volume(AudioBus::Sfx) => "menu_sounds",
+            SettingsEntry::Volume(AudioBus::Ui) => "menu_alerts",
+            SettingsEntry::Fullscreen => "menu_fullscreen",
+            SettingsEntry::Scale => "menu_scale",
+            SettingsEntry::StartingLives => "menu_lives",
+            SettingsEntry::ExtraLife => "menu_extra_life",
+            SettingsEntry::Difficulty => "menu_difficulty",
+            SettingsEntry::GameMode => "menu_players",
+            SettingsEntry::Ruleset => "menu_ruleset",
+            SettingsEntry::Controller => "menu_controller",
+            SettingsEntry::Theme => "menu_theme",
+            SettingsEntry::Language => "menu_language",
+            SettingsEntry::Binding(Player::One, Up) => "menu_player_one_up",
+            SettingsEntry::Binding(Player::One, Down) => "menu_player_one_down",
+            SettingsEntry::Binding(Player::One, Left) => "menu_player_one_left",
+            SettingsEntry::Binding(Player::One, Right) => "menu_player_one_right",
+            SettingsEntry::Binding(Player::Two, Up) => "menu_player_two_up",
+            SettingsEntry::Binding(Player::Two, Down) => "menu_player_two_down",
+            SettingsEntry::Binding(Player::Two, Left) => "menu_player_two_left",
+            SettingsEntry::Binding(Player::Two, Right) => "menu_player_two_right",
+            SettingsEntry::Practice => "menu_practice",
+            SettingsEntry::PracticeLevel => "menu_practice_level",
+            SettingsEntry::PracticeLives => "menu_practice_lives",
+            SettingsEntry::InfiniteLives => "menu_infinite_lives",
+            SettingsEntry::ClearedDots(MazeSection::TopLeft) => "menu_dots_top_left",
+            SettingsEntry::ClearedDots(MazeSection::TopRight) => "menu_dots_top_right",
+            SettingsEntry::ClearedDots(MazeSection::BottomLeft) => "menu_dots_bottom_left",
+            SettingsEntry::ClearedDots(MazeSection::BottomRight) => "menu_dots_bottom_right",
+            SettingsEntry::ClearedEnergizer(MazeSection::TopLeft) => "menu_energizer_top_left",
+            SettingsEntry::ClearedEnergizer(MazeSection::TopRight) => "menu_energizer_top_right",
+            SettingsEntry::ClearedEnergizer(MazeSection::BottomLeft) => "menu_energizer_bottom_left",
+            SettingsEntry::ClearedEnergizer(MazeSection::BottomRight) => "menu_energizer_bottom_right",
+            SettingsEntry::GhostPalette => "menu_ghost_palette",
+            SettingsEntry::GhostMarkers => "menu_ghost_markers",
+            SettingsEntry::HighContrastMaze => "menu_high_contrast_maze",
+            SettingsEntry::FrightenedWarning => "menu_frightened_warning",
+        })
+    }
+
+    fn value(&self, settings: &Settings, localization: &Localization) -> String {
+        let text = |id: &'static str| localization.text(&TextId::new(id)).to_string();
+        let on_off = |on: bool| text(if on { "value_on" } else { "value_off" });
+        let cleared = |cleared: bool| text(if cleared { "value_cleared" } else { "value_full" });
+        let volume = |volume: f32, muted: bool| match muted {
+            true => text("value_mute"),
+            false => format!("{}%", (volume * 100.0).round())
+        };
+
+        match self {
+            SettingsEntry::MasterVolume => volume(settings.master_volume, settings.muted),
+            SettingsEntry::Volume(bus) => volume(settings.volume_of(*bus), settings.is_bus_muted(*bus)),
+            SettingsEntry::Fullscreen => on_off(settings.fullscreen),
+            SettingsEntry::Scale => match settings.scale {
+                Some(scale) => format!("{scale}X"),
+                None => text("value_auto")
+            },
+            SettingsEntry::StartingLives => settings.starting_lives.to_string(),
+            SettingsEntry::ExtraLife => match settings.extra_life_threshold {
+                0 => text("value_off"),
+                threshold => threshold.to_string()
+            },
+            SettingsEntry::Difficulty => text(match settings.difficulty {
+                Difficulty::Easy => "value_easy",
+                Difficulty::Normal => "value_normal",
+                Difficulty::Hard => "value_hard",
+            }),
+            SettingsEntry::GameMode => match settings.game_mode {
+                GameMode::SinglePlayer => "1".to_string(),
+                GameMode::Alternating => text("value_alternating"),
+                GameMode::Coop { shared_lives: true } => text("value_coop_shared"),
+                GameMode::Coop { shared_lives: false } => text("value_coop"),
+                GameMode::Versus { ghost } => format!("{} {}", text("value_versus"), format!("{ghost:?}").to_uppercase()),
+            },
+            SettingsEntry::Ruleset => text(match settings.ruleset {
+                Ruleset::Arcade => "value_arcade",
+                Ruleset::MsPacman => "value_ms_pacman",
+            }),
+            SettingsEntry::Controller => text(match settings.pacman_controller {
+                PacmanController::Keyboard => "value_keyboard",
+                PacmanController::Greedy => "value_ai_greedy",
+                PacmanController::AvoidGhosts => "value_ai_careful",
+                PacmanController::Lookahead => "value_ai_lookahead",
+            }),
+            SettingsEntry::Theme => settings.theme.to_uppercase(),
+            SettingsEntry::Language => settings.language.to_uppercase(),
+            SettingsEntry::Binding(player, dir) => settings.controls_of(*player)
+                .keys_for(*dir)
+                .first()
+                .map(|key| format!("{key:?}").to_uppercase())
+                .unwrap_or_else(|| text("value_none")),
+            SettingsEntry::Practice => on_off(settings.practice.enabled),
+            SettingsEntry::PracticeLevel => settings.practice.level.to_string(),
+            SettingsEntry::PracticeLives => settings.practice.lives.to_string(),
+            SettingsEntry::InfiniteLives => on_off(settings.practice.infinite_lives),
+            SettingsEntry::ClearedDots(section) => cleared(settings.practice.cleared_dots.contains(section)),
+            SettingsEntry::ClearedEnergizer(section) => cleared(settings.practice.cleared_energizers.contains(section)),
+            SettingsEntry::GhostPalette => text(match settings.accessibility.ghost_palette {
+                GhostPalette::Arcade => "value_arcade",
+                GhostPalette::RedGreen => "value_red_green",
+                GhostPalette::BlueYellow => "value_blue_yellow",
+            }),
+            SettingsEntry::GhostMarkers => on_off(settings.accessibility.ghost_markers),
+            SettingsEntry::HighContrastMaze => on_off(settings.accessibility.high_contrast_maze),
+            SettingsEntry::FrightenedWarning => on_off(settings.accessibility.frightened_warning),
+        }
+    }
+
+    /// Change the value of this entry one step up (increase = true) or down.
+    fn change(&self, settings: &mut Settings, increase: bool) {
+        match self {
+            SettingsEntry::MasterVolume => change_volume(&mut settings.master_volume, increase),
+            SettingsEntry::Volume(bus) => change_volume(settings.volume_of_mut(*bus), increase),
+            SettingsEntry::Fullscreen => settings.fullscreen = !settings.fullscreen,
+            SettingsEntry::Scale => settings.scale = match (settings.scale, increase) {
+                (None, true) => Some(1),
+                (None, false) => None,
+                (Some(scale), true) => Some((scale + 1).min(MAX_SCALE)),
+                (Some(1), false) => None,
+                (Some(scale), false) => Some(scale - 1),
+            },
+            SettingsEntry::StartingLives => settings.starting_lives = match increase {
+                true => (settings.starting_lives + 1).min(MAX_STARTING_LIVES),
+                false => settings.starting_lives.saturating_sub(1).max(1),
+            },
+            SettingsEntry::ExtraLife => settings.extra_life_threshold = match increase {
+                true => (settings.extra_life_threshold + EXTRA_LIFE_STEP).min(MAX_EXTRA_LIFE_THRESHOLD),
+                false => settings.extra_life_threshold.saturating_sub(EXTRA_LIFE_STEP),
+            },
+            SettingsEntry::Difficulty => settings.difficulty = match increase {
+                true => settings.difficulty.next(),
+                false => settings.difficulty.previous(),
+            },
+            SettingsEntry::GameMode => settings.game_mode = match increase {
+                true => settings.game_mode.next(),
+                false => settings.game_mode.previous(),
+            },
+            SettingsEntry::Ruleset => settings.ruleset = settings.ruleset.other(),
+            SettingsEntry::Controller => settings.pacman_controller = match increase {
+                true => settings.pacman_controller.next(),
+                false => settings.pacman_controller.previous(),
+            },
+            SettingsEntry::Theme => settings.theme = cycle(&available_themes(), &settings.theme, increase),
+            SettingsEntry::Language => settings.language = cycle(&available_languages(), &settings.language, increase),
+            SettingsEntry::Binding(..) => (),
+            SettingsEntry::Practice => settings.practice.enabled = !settings.practice.enabled,
+            SettingsEntry::PracticeLevel => settings.practice.level = match increase {
+                true => (settings.practice.level + 1).min(MAX_PRACTICE_LEVEL),
+                false => settings.practice.level.saturating_sub(1).max(1),
+            },
+            SettingsEntry::PracticeLives => settings.practice.lives = match increase {
+                true => (settings.practice.lives + 1).min(MAX_STARTING_LIVES),
+                false => settings.practice.lives.saturating_sub(1).max(1),
+            },
+            SettingsEntry::InfiniteLives => settings.practice.infinite_lives = !settings.practice.infinite_lives,
+            SettingsEntry::ClearedDots(section) => settings.practice.toggle_cleared_dots(*section),
+            SettingsEntry::ClearedEnergizer(section) => settings.practice.toggle_cleared_energizers(*section),
+            SettingsEntry::GhostPalette => settings.accessibility.ghost_palette = match increase {
+                true => settings.accessibility.ghost_palette.next(),
+                false => settings.accessibility.ghost_palette.previous(),
+            },
+            SettingsEntry::GhostMarkers => settings.accessibility.ghost_markers = !settings.accessibility.ghost_markers,
+            SettingsEntry::HighContrastMaze => settings.accessibility.high_contrast_maze = !settings.accessibility.high_contrast_maze,
+            SettingsEntry::FrightenedWarning => settings.accessibility.frightened_warning = !settings.accessibility.frightened_warning,
+        }
+    }
+}
+
+/// The option after (increase = true) or before the current one, wrapping around at the ends.
+fn cycle(options: &[String], current: &str, increase: bool) -> String {
+    let index = options.iter().position(|option| *option == current).unwrap_or(0);
+    let next = match increase {
+        true => (index + 1) % options.len(),
+        false => (index + options.len() - 1) % options.len(),
+    };
+    options[next].to_string()
+}
+
+fn change_volume(volume: &mut f32, increase: bool) {
+    let step = if increase { VOLUME_STEP } else { -VOLUME_STEP };
+    // round to avoid floating point errors adding up
+    *volume = ((*volume + step) * 10.0).round().clamp(0.0, 10.0) / 10.0;
+}
+
+/// Open or close the menu with Escape. While a key gets rebound, Escape cancels the rebinding instead.
+fn toggle_menu(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    localization: Res<Localization>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    menu: Option<ResMut<SettingsMenu>>,
+    mut time: ResMut<Time<Virtual>>,
+    screen_query: Query<Entity, With<SettingsMenuScreen>>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::Escape) {
+        return;
+    }
+
+    match menu {
+        Some(mut menu) if menu.rebinding => menu.rebinding = false,
+        Some(_) => {
+            commands.remove_resource::<SettingsMenu>();
+            time.unpause();
+            for e in &screen_query {
+                commands.entity(e).despawn_recursive();
+            }
+        }
+        None => {
+            commands.init_resource::<SettingsMenu>();
+            time.pause();
+            spawn_menu(&mut commands, &theme, &localization, MenuPage::default());
+        }
+    }
+}
+
+/// Show the next page with Tab.
+fn switch_page(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    localization: Res<Localization>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut menu: ResMut<SettingsMenu>,
+    screen_query: Query<Entity, With<SettingsMenuScreen>>,
+) {
+    if menu.rebinding || !keyboard_input.just_pressed(KeyCode::Tab) {
+        return;
+    }
+
+    menu.page = menu.page.next();
+    menu.selected = 0;
+
+    for e in &screen_query {
+        commands.entity(e).despawn_recursive();
+    }
+
+    spawn_menu(&mut commands, &theme, &localization, menu.page);
+}
+
+fn spawn_menu(
+    commands: &mut Commands,
+    theme: &Theme,
+    localization: &Localization,
+    page: MenuPage,
+) {
+    commands.spawn((
+        Name::new("SettingsMenu"),
+        SettingsMenuScreen,
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Percent(100.0),
+                height: Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                row_gap: Px(FIELD_SIZE),
+                padding: UiRect::horizontal(Px(2.0 * FIELD_SIZE)),
+                ..default()
+            },
+            background_color: BackgroundColor(theme.color(&ColorId::MENU_BACKGROUND)),
+            z_index: ZIndex::Global(1),
+            ..default()
+        }
+    )).with_children(|parent| {
+        parent.spawn((
+            Name::new("Title"),
+            TextBundle::from_section(localization.text(&page.title()), theme.text_style(&ColorId::MENU_SELECTED))
+        ));
+
+        parent.spawn(more_entries_hint(theme, MoreEntries::Above));
+
+        for entry in page.entries().iter().copied() {
+            parent.spawn((
+                Name::new(entry.label().as_str().to_owned()),
+                entry,
+                TextBundle::from_sections([
+                    TextSection::new(localization.text(&entry.label()), theme.text_style(&ColorId::MENU_TEXT)),
+                    TextSection::new("", theme.text_style(&ColorId::MENU_TEXT)),
+                ])
+            ));
+        }
+
+        parent.spawn(more_entries_hint(theme, MoreEntries::Beneath));
+    });
+}
+
+fn more_entries_hint(theme: &Theme, more_entries: MoreEntries) -> impl Bundle {
+    (
+        Name::new("MoreEntries"),
+        more_entries,
+        TextBundle::from_section("...", theme.text_style(&ColorId::MENU_TEXT)),
+    )
+}
+
+fn select_entry(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut menu: ResMut<SettingsMenu>,
+) {
+    if menu.rebinding {
+        return;
+    }
+
+    let num_entries = menu.page.entries().len();
+
+    if keyboard_input.just_pressed(KeyCode::ArrowDown) {
+        menu.selected = (menu.selected + 1) % num_entries;
+    } else if keyboard_input.just_pressed(KeyCode::ArrowUp) {
+        menu.selected = (menu.selected + num_entries - 1) % num_entries;
+    }
+}
+
+/// Change the selected setting with left and right. Enter mutes a volume or starts rebinding a key.
+fn change_value(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut menu: ResMut<SettingsMenu>,
+    mut settings: ResMut<Settings>,
+) {
+    if menu.rebinding {
+        return;
+    }
+
+    if !keyboard_input.any_just_pressed([KeyCode::ArrowRight, KeyCode::ArrowLeft, KeyCode::Enter]) {
+        return;
+    }
+
+    let entry = menu.page.entries()[menu.selected];
+    // the settings only count as changed if a value really changed, which a key binding entry never does here
+    let previous = settings.clone();
+    let values = settings.bypass_change_detection();
+
+    if keyboard_input.just_pressed(KeyCode::ArrowRight) {
+        entry.change(values, true)
+    } else if keyboard_input.just_pressed(KeyCode::ArrowLeft) {
+        entry.change(values, false)
+    } else {
+        match entry {
+            SettingsEntry::Binding(..) => menu.rebinding = true,
+            SettingsEntry::MasterVolume => values.muted = !values.muted,
+            SettingsEntry::Volume(bus) => values.toggle_bus_mute(bus),
+            _ => entry.change(values, true)
+        }
+    }
+
+    if *settings != previous {
+        settings.set_changed();
+    }
+}
+
+/// Bind the next pressed key to the direction of the selected entry.
+fn rebind_key(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut menu: ResMut<SettingsMenu>,
+    mut settings: ResMut<Settings>,
+) {
+    if !menu.rebinding {
+        return;
+    }
+
+    let SettingsEntry::Binding(player, dir) = menu.page.entries()[menu.selected] else {
+        return;
+    };
+
+    // the enter press which started the rebinding is ignored
+    let key = keyboard_input
+        .get_just_pressed()
+        .find(|key| !matches!(key, KeyCode::Enter | KeyCode::Escape));
+
+    if let Some(key) = key {
+        settings.controls_of_mut(player).rebind(dir, *key);
+        menu.rebinding = false;
+    }
+}
+
+fn update_entries(
+    menu: Res<SettingsMenu>,
+    settings: Res<Settings>,
+    theme: Res<Theme>,
+    localization: Res<Localization>,
+    mut query: Query<(&SettingsEntry, &mut Text)>,
+) {
+    for (entry, mut text) in &mut query {
+        let selected = *entry == menu.page.entries()[menu.selected];
+        let color = match selected {
+            true => theme.color(&ColorId::MENU_SELECTED),
+            false => theme.color(&ColorId::MENU_TEXT),
+        };
+        let value = if selected && menu.rebinding {
+            localization.text(&TextId::new("value_press_a_key")).to_string()
+        } else {
+            entry.value(&settings, &localization)
+        };
+
+        text.sections[1].value = format!(" {value}");
+
+        for section in &mut text.sections {
+            section.style.color = color;
+        }
+    }
+}
+
+/// Only show the entries around the selected one and hint at the hidden ones.
+fn scroll_entries(
+    menu: Res<SettingsMenu>,
+    mut entry_query: Query<(&SettingsEntry, &mut Style)>,
+    mut hint_query: Query<(&MoreEntries, &mut Visibility)>,
+) {
+    let entries = menu.page.entries();
+    let visible = visible_entries(menu.selected, entries.len());
+
+    for (entry, mut style) in &mut entry_query {
+        let index = entries.iter().position(|e| e == entry).unwrap_or_default();
+        let display = match visible.contains(&index) {
+            true => Display::Flex,
+            false => Display::None,
+        };
+
+        if style.display != display {
+            style.display = display;
+        }
+    }
+
+    for (more_entries, mut visibility) in &mut hint_query {
+        let hidden_entries = match more_entries {
+            MoreEntries::Above => visible.start > 0,
+            MoreEntries::Beneath => visible.end < entries.len(),
+        };
+        let new_visibility = match hidden_entries {
+            true => Visibility::Inherited,
+            false => Visibility::Hidden,
+        };
+
+        if *visibility != new_visibility {
+            *visibility = new_visibility;
+        }
+    }
+}
+
+/// The indices of the [VISIBLE_ENTRIES] entries shown around the selected one. The selected entry stays in the
+/// middle, unless the page is scrolled to its start or end.
+fn visible_entries(selected: usize, num_entries: usize) -> Range<usize> {
+    let start = selected
+        .saturating_sub(VISIBLE_ENTRIES / 2)
+        .min(num_entries.saturating_sub(VISIBLE_ENTRIES));
+    start..(start + VISIBLE_ENTRIES).min(num_entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use crate::core::prelude::*;
+    use super::{change_value, visible_entries, SettingsEntry, SettingsMenu, ENTRIES, VISIBLE_ENTRIES};
+
+    #[test]
+    fn the_visible_entries_follow_the_selected_one() {
+        assert_eq!(visible_entries(0, ENTRIES.len()), 0..VISIBLE_ENTRIES);
+        assert_eq!(visible_entries(10, ENTRIES.len()), 3..17);
+        assert_eq!(visible_entries(ENTRIES.len() - 1, ENTRIES.len()), 8..22);
+        assert_eq!(visible_entries(2, 4), 0..4);
+    }
+
+    #[derive(Resource, Default)]
+    struct SettingsChanged(bool);
+
+    fn detect_changed_settings(settings: Res<Settings>, mut changed: ResMut<SettingsChanged>) {
+        changed.0 = settings.is_changed();
+    }
+
+    /// Press the key on the entry and tell if the settings were marked as changed.
+    fn press_on_entry(app: &mut App, entry: usize, key: KeyCode) -> bool {
+        app.world_mut().resource_mut::<SettingsMenu>().selected = entry;
+        let mut keyboard_input = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard_input.reset_all();
+        keyboard_input.press(key);
+        app.update();
+        app.world().resource::<SettingsChanged>().0
+    }
+
+    #[test]
+    fn only_changed_values_mark_the_settings_as_changed() {
+        let mut app = App::new();
+        app
+            .insert_resource(Settings::default())
+            .init_resource::<SettingsMenu>()
+            .init_resource::<SettingsChanged>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .add_systems(Update, (change_value, detect_changed_settings).chain());
+        app.update();
+
+        let binding = ENTRIES.iter().position(|entry| matches!(entry, SettingsEntry::Binding(..))).unwrap();
+        let fullscreen = ENTRIES.iter().position(|entry| *entry == SettingsEntry::Fullscreen).unwrap();
+
+        assert!(!press_on_entry(&mut app, binding, KeyCode::ArrowRight));
+        assert!(press_on_entry(&mut app, fullscreen, KeyCode::ArrowRight));
+    }
+}
@@ -1,6 +1,6 @@
 use std::time::Duration;
 use bevy::prelude::*;
-use bevy::prelude::Val::Percent;
+use bevy::prelude::Val::{Percent, Px};
 use crate::core::prelude::*;
 
 pub(super) struct TopUIPlugin;
@@ -17,7 +17,7 @@ impl Plugin for TopUIPlugin {
                 (
                     update_scoreboard,
                     update_high_score_board,
-                    blink_1_up_label
+                    blink_player_label
                 ).run_if(in_game))
             .add_systems(
                 OnExit(Game(GameOver)),
@@ -31,70 +31,95 @@ impl Plugin for TopUIPlugin {
 #[derive(Component)]
 struct TopUI;
 
-/// Shows the score of the current game
+/// Shows the score of a player in the current game
 #[derive(Component)]
-struct ScoreBoard;
+struct ScoreBoard(Player);
 
 /// Shows the current high score
 #[derive(Component)]
 struct HighScoreBoard;
 
-/// Shows the "1UP" in the top left corner of the screen
+/// Shows the "1UP" in the top left or the "2UP" in the top right corner of the screen
 #[derive(Component)]
-struct OneUpLabel;
+struct PlayerLabel(Player);
 
 fn spawn_top_ui(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    theme: Res<Theme>,
+    localization: Res<Localization>,
+    settings: Res<Settings>,
 ) {
-    let font = asset_server.load(FONT);
+    let text_style = theme.text_style(&ColorId::TEXT);
 
     commands.spawn((
         Name::new("TopUI"),
         TopUI,
         NodeBundle {
             style: Style {
-                width: Percent(40.0),
-                height: Percent(10.0),
+                width: Percent(100.0),
+                height: Px(HUD_TOP_ROWS as f32 * FIELD_SIZE),
                 justify_content: JustifyContent::SpaceBetween,
-                left: Percent(30.0),
+                top: Px(0.0),
                 position_type: PositionType::Absolute,
                 ..default()
             },
             ..default()
         }
     ))
-        .with_children(|parent| spawn_score_board(font.clone(), parent))
-        .with_children(|parent| spawn_high_score_board(font.clone(), parent))
-        .with_children(|parent| spawn_high_score_label(font.clone(), parent))
-        .with_children(|parent| spawn_1up_label(font.clone(), parent))
+        .with_children(|parent| {
+            spawn_column(parent, |column| {
+                spawn_player_label(text_style.clone(), &localization, column, Player::One);
+                spawn_score_board(text_style.clone(), column, Player::One);
+            });
+            spawn_column(parent, |column| {
+                spawn_high_score_label(text_style.clone(), &localization, column);
+                spawn_high_score_board(text_style.clone(), column);
+            });
+            spawn_column(parent, |column| {
+                if settings.game_mode.has_two_players() {
+                    spawn_player_label(text_style.clone(), &localization, column, Player::Two);
+                    spawn_score_board(text_style.clone(), column, Player::Two);
+                }
+            });
+        })
     ;
 }
 
+/// Spawn one of the three columns of the top ui. Every column shows a label with a score beneath.
+fn spawn_column(
+    parent: &mut ChildBuilder,
+    spawn_children: impl FnOnce(&mut ChildBuilder),
+) {
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                width: Percent(100.0 / 3.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(spawn_children);
+}
+
 fn spawn_score_board(
-    font: Handle<Font>,
+    text_style: TextStyle,
     parent: &mut ChildBuilder,
+    player: Player,
 ) {
     parent.spawn((
         Name::new("ScoreBoard"),
-        ScoreBoard,
+        ScoreBoard(player),
         TextBundle::from_section(
             "0",
-            TextStyle {
-                font,
-                font_size: 20.0,
-                color: Color::srgb(1.0, 1.0, 1.0),
-            },
-        ).with_style(Style {
-            position_type: PositionType::Absolute,
-            top: Percent(50.0),
-            ..default()
-        })
+            text_style,
+        )
     ));
 }
 
 fn spawn_high_score_board(
-    font: Handle<Font>,
+    text_style: TextStyle,
     parent: &mut ChildBuilder,
 ) {
     parent.spawn((
@@ -102,78 +127,65 @@ fn spawn_high_score_board(
         HighScoreBoard,
         TextBundle::from_section(
             "0",
-            TextStyle {
-                font,
-                font_size: 20.0,
-                color: Color::srgb(1.0, 1.0, 1.0),
-            },
-        ).with_style(Style {
-            position_type: PositionType::Absolute,
-            left: Percent(50.0),
-            top: Percent(50.0),
-            ..default()
-        })
+            text_style,
+        )
     ));
 }
 
 fn spawn_high_score_label(
-    font: Handle<Font>,
+    text_style: TextStyle,
+    localization: &Localization,
     parent: &mut ChildBuilder,
 ) {
     parent.spawn((
         Name::new("HighScoreBoardLabel"),
         TextBundle::from_section(
-            "HIGH SCORE",
-            TextStyle {
-                font,
-                font_size: 20.0,
-                color: Color::srgb(1.0, 1.0, 1.0),
-            },
-        ).with_style(Style {
-            position_type: PositionType::Absolute,
-            top: Percent(10.0),
-            left: Percent(30.0),
-            ..default()
-        })
+            localization.text(&TextId::HIGH_SCORE),
+            text_style,
+        )
     ));
 }
 
-/// Spawn the "1UP" in the top left of the screen.
+/// Spawn the "1UP" in the top left or the "2UP" in the top right of the screen.
 ///
-/// To be honest, I didn't find out what this exactly does. It's not a Super Mario 1UP (aka extra live), but probably a pinball 1UP
-/// (if 1UP is displayed then you see the score of player one; when he is done, it says 2UP and its player twos turn and score).
-fn spawn_1up_label(
-    font: Handle<Font>,
+/// Like in pinball, the label shows whose score is beneath it. The label of the player whose turn it is blinks.
+fn spawn_player_label(
+    text_style: TextStyle,
+    localization: &Localization,
     parent: &mut ChildBuilder,
+    player: Player,
 ) {
     parent.spawn((
-        Name::new("1UPLabel"),
-        OneUpLabel,
+        Name::new(format!("{}Label", player.label())),
+        PlayerLabel(player),
         TextBundle::from_section(
-            "1UP",
-            TextStyle {
-                font,
-                font_size: 20.0,
-                color: Color::srgb(1.0, 1.0, 1.0),
-            },
-        ).with_style(Style {
-            position_type: PositionType::Absolute,
-            top: Percent(10.0),
-            ..default()
-        })
+            localization.text(&TextId::player_label(player)),
+            text_style,
+        )
     ));
 }
 
+/// Show the score of the active player and the score of the other player (if any) on their boards.
 fn update_scoreboard(
     score: Res<Score>,
-    mut query: Query<&mut Text, With<ScoreBoard>>,
+    active_player: Res<ActivePlayer>,
+    other_player: Option<Res<OtherPlayer>>,
+    mut query: Query<(&mut Text, &ScoreBoard)>,
 ) {
-    if !score.is_changed() {
+    let other_player_changed = other_player.as_ref().is_some_and(|other| other.is_changed());
+
+    if !score.is_changed() && !active_player.is_changed() && !other_player_changed {
         return;
     }
 
-    for mut text in query.iter_mut() {
-        text.sections[0].value = format!("{}", **score)
+    for (mut text, ScoreBoard(player)) in query.iter_mut() {
+        let player_score = if *player == **active_player {
+            **score
+        } else {
+            other_player.as_ref().map(|other| *other.0.score).unwrap_or_default()
+        };
+
+        text.sections[0].value = format!("{}", player_score)
     }
 }
 
@@ -191,26 +203,29 @@ fn update_high_score_board(
 }
 
 #[derive(Deref, DerefMut)]
-struct OneUpBlinkTimer(Timer);
+struct PlayerLabelBlinkTimer(Timer);
 
-impl Default for OneUpBlinkTimer {
+impl Default for PlayerLabelBlinkTimer {
     fn default() -> Self {
-        OneUpBlinkTimer(Timer::new(Duration::from_secs_f32(0.2), TimerMode::Repeating))
+        PlayerLabelBlinkTimer(Timer::new(Duration::from_secs_f32(0.2), TimerMode::Repeating))
     }
 }
 
-/// Let the "1UP" blink, like in the original arcade game.
-fn blink_1_up_label(
-    mut timer: Local<OneUpBlinkTimer>,
+/// Let the label of the active player blink, like in the original arcade game.
+fn blink_player_label(
+    mut timer: Local<PlayerLabelBlinkTimer>,
     time: Res<Time>,
-    mut query: Query<&mut Visibility, With<OneUpLabel>>,
+    active_player: Res<ActivePlayer>,
+    mut query: Query<(&mut Visibility, &PlayerLabel)>,
 ) {
     timer.tick(time.delta());
 
     let timer_finished = timer.just_finished();
 
-    for mut vis in &mut query {
-        if timer_finished {
+    for (mut vis, PlayerLabel(player)) in &mut query {
+        if *player != **active_player {
+            vis.set_if_neq(Visibility::Visible);
+        } else if timer_finished {
             *vis = match *vis {
                 Visibility::Visible => Visibility::Hidden,
                 Visibility::Hidden => Visibility::Visible,
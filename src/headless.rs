@@ -0,0 +1,89 @@
+use std::time::{Duration, Instant};
+
+use bevy::app::{PluginGroupBuilder, Plugins, PluginsState};
+use bevy::log::LogPlugin;
+use bevy::prelude::*;
+use bevy::render::RenderPlugin;
+use bevy::render::settings::WgpuSettings;
+use bevy::time::TimeUpdateStrategy;
+use bevy::window::ExitCondition;
+use bevy::winit::WinitPlugin;
+
+use crate::core::prelude::*;
+use crate::PacmanPlugin;
+
+/// How long the game may take to load until it starts. Loading the assets is the only part which does not depend
+/// on the simulated time, so this is measured in real time.
+const MAX_LOADING_TIME: Duration = Duration::from_secs(60);
+
+/// The bevy default plugins without a window and without a GPU. The game runs the same, but nothing gets rendered.
+/// Assets are still loaded, as the maze and the sprite sheets are created from them.
+///
+/// Without winit, the app needs another runner, like the [bevy::app::ScheduleRunnerPlugin].
+pub(crate) fn headless_default_plugins() -> PluginGroupBuilder {
+    DefaultPlugins
+        .set(WindowPlugin {
+            primary_window: None,
+            exit_condition: ExitCondition::DontExit,
+            close_when_requested: false,
+        })
+        .set(RenderPlugin {
+            render_creation: WgpuSettings {
+                backends: None,
+                ..default()
+            }.into(),
+            ..default()
+        })
+        .disable::<WinitPlugin>()
+        // nobody reads the log in a headless game, and it would be printed right into a terminal frontend
+        .disable::<LogPlugin>()
+}
+
+/// Create a headless game whose time is simulated, so every update advances the game by the tick duration,
+/// no matter how fast the machine is. Together with the seed, the same inputs always lead to the same game.
+///
+/// The additional plugins are added before the app gets finished. The app has no runner, it has to be
+/// updated by hand.
+pub(crate) fn create_simulated_app<M>(
+    seed: u64,
+    tick_duration: Duration,
+    settings: Settings,
+    additional_plugins: impl Plugins<M>,
+) -> App {
+    let mut app = App::new();
+    app
+        .add_plugins((
+            headless_default_plugins(),
+            PacmanPlugin,
+        ))
+        .add_plugins(additional_plugins)
+        .insert_resource(TimeUpdateStrategy::ManualDuration(tick_duration))
+        .insert_resource(Random::from_seed(seed))
+        .insert_resource(settings)
+    ;
+
+    while app.plugins_state() == PluginsState::Adding {
+        bevy::tasks::tick_global_task_pools_on_main_thread();
+    }
+
+    app.finish();
+    app.cleanup();
+    app
+}
+
+/// Update the game until pacman can move for the first time.
+pub(crate) fn run_until_game_started(app: &mut App) {
+    let start = Instant::now();
+
+    while current_state(app.world()) != Game(Running) {
+        if start.elapsed() > MAX_LOADING_TIME {
+            panic!("the game did not start in time, are the assets available?")
+        }
+
+        app.update();
+    }
+}
+
+pub(crate) fn current_state(world: &World) -> GameState {
+    *world.resource::<State<GameState>>().get()
+}
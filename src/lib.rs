@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+use bevy_asset_preload::{AssetPreloadPlugin, load_assets};
+use bevy_sprite_sheet::SpriteSheetPlugin;
+
+use crate::core::prelude::*;
+use crate::core::CorePlugin;
+use crate::game::GamePlugin;
+use crate::spawn::SpawnPlugin;
+
+pub use crate::core::constants::{WINDOW_HEIGHT, WINDOW_WIDTH};
+pub use crate::debug::DebugPlugin;
+pub use crate::game::save_game::SaveGamePlugin;
+pub use crate::map_creator::create_map;
+pub use crate::tui::TuiPlugin;
+
+mod core;
+pub mod env;
+mod debug;
+mod game;
+mod headless;
+mod map_creator;
+#[cfg(test)]
+mod scenario;
+pub mod simulate;
+mod spawn;
+mod tui;
+
+/// The whole game, from loading the assets to playing it. Needs the bevy default plugins (or a headless
+/// version of them) and does not care how it gets presented.
+pub struct PacmanPlugin;
+
+impl Plugin for PacmanPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_plugins((
+                CorePlugin,
+                GamePlugin,
+                SpawnPlugin,
+                AssetPreloadPlugin::load_given_paths(Setup(PreloadAssets), Setup(LoadAnimations), load_assets!()),
+                SpriteSheetPlugin::new(Setup(CreateSpriteSheets), Spawn(SpawnMapScene)),
+            ))
+        ;
+    }
+}
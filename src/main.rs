@@ -1,54 +1,65 @@
 use bevy::prelude::*;
-use bevy_asset_preload::{AssetPreloadPlugin, load_assets};
-use bevy_sprite_sheet::SpriteSheetPlugin;
 
-use core::prelude::*;
-use crate::core::CorePlugin;
-use crate::debug::DebugPlugin;
-use crate::game::GamePlugin;
+use pacman::{create_map, simulate, DebugPlugin, PacmanPlugin, SaveGamePlugin, TuiPlugin, WINDOW_HEIGHT, WINDOW_WIDTH};
 
-use crate::map_creator::create_map;
-use crate::spawn::SpawnPlugin;
+fn main() {
+    if should_simulate() {
+        if let Err(e) = simulate::run(std::env::args().skip(2)) {
+            eprintln!("{e}\n\n{}", simulate::USAGE);
+            std::process::exit(2);
+        }
 
-mod debug;
-mod game;
-mod map_creator;
-mod spawn;
-mod core;
+        return;
+    }
 
-fn main() {
     let mut app = App::new();
-    app
-        .add_plugins(DefaultPlugins
-            .set(WindowPlugin {
-                primary_window: Some(Window {
-                    resolution: (WINDOW_WIDTH, WINDOW_HEIGHT).into(),
-                    title: "PacMan".to_string(),
-                    resizable: false,
-                    ..Default::default()
-                }),
-                ..default()
-            })
-            .set(ImagePlugin::default_nearest())
-        )
-        .insert_resource(ClearColor(Color::srgb(0.0, 0.0, 0.0)))
-        .add_plugins((
-            CorePlugin,
-            GamePlugin,
-            SpawnPlugin,
-            AssetPreloadPlugin::load_given_paths(Setup(PreloadAssets), Setup(CreateSpriteSheets), load_assets!()),
-            SpriteSheetPlugin::new(Setup(CreateSpriteSheets), Spawn(SpawnMapScene)),
-            DebugPlugin,
-        ))
-    ;
+    let run_in_terminal = should_run_in_terminal();
+
+    if run_in_terminal {
+        app.add_plugins(TuiPlugin);
+    } else {
+        app
+            .add_plugins(DefaultPlugins
+                .set(WindowPlugin {
+                    primary_window: Some(Window {
+                        resolution: (WINDOW_WIDTH, WINDOW_HEIGHT).into(),
+                        title: "PacMan".to_string(),
+                        resizable: true,
+                        ..Default::default()
+                    }),
+                    ..default()
+                })
+                .set(ImagePlugin::default_nearest())
+            )
+            .insert_resource(ClearColor(Color::srgb(0.0, 0.0, 0.0)))
+        ;
+    }
+
+    app.add_plugins(PacmanPlugin);
+
+    if !run_in_terminal {
+        app.add_plugins(DebugPlugin);
+    }
 
     if should_create_map() {
         create_map(&mut app);
     } else {
-        app.run();
+        app
+            .add_plugins(SaveGamePlugin)
+            .run();
     }
 }
 
+/// Let an AI agent play many games without a window with "pacman simulate", see [simulate::USAGE].
+fn should_simulate() -> bool {
+    std::env::args().nth(1).is_some_and(|arg| arg == "simulate")
+}
+
 fn should_create_map() -> bool {
     std::env::args().any(|arg| arg.contains("create_map"))
 }
+
+/// Run the game in the terminal instead of a window with "pacman --tui".
+fn should_run_in_terminal() -> bool {
+    std::env::args().any(|arg| arg == "--tui")
+}
@@ -7,6 +7,7 @@ use crate::core::prelude::*;
 pub fn create_map(commands: &mut App) {
     let mut creator = MapCreator::new(28, 31, commands);
     creator.create();
+    creator.mark_ghost_house();
     creator.save()
 }
 
@@ -125,7 +126,7 @@ macro_rules! ghost_house {
         for _ in 0..$amount {
             $creator.spawn(
                 $creator.maze,
-                GhostHouseArea {rotation: D0}
+                GhostHouseArea { house: 0 }
             );
         }
     };
@@ -752,6 +753,17 @@ impl<'a> MapCreator<'a> {
         corner!(self, D180, O, Inky);
     }
 
+    /// Mark the entrance, the center and the ghost spawns of the ghost house. Most of these tiles lie inside
+    /// the ghost house area, so they are placed at fixed positions instead of row by row.
+    fn mark_ghost_house(&mut self) {
+        self.spawn_double_at(self.map, GhostHouseEntrance { house: 0, direction: Left }, Pos::new(13, 20));
+        self.spawn_double_at(self.map, GhostHouseCenter { house: 0 }, Pos::new(13, 17));
+        self.spawn_double_at(self.map, GhostSpawnPoint { ghost: Blinky, house: 0, direction: Left }, Pos::new(13, 20));
+        self.spawn_double_at(self.map, GhostSpawnPoint { ghost: Pinky, house: 0, direction: Up }, Pos::new(13, 17));
+        self.spawn_double_at(self.map, GhostSpawnPoint { ghost: Inky, house: 0, direction: Down }, Pos::new(11, 17));
+        self.spawn_double_at(self.map, GhostSpawnPoint { ghost: Clyde, house: 0, direction: Down }, Pos::new(15, 17));
+    }
+
     fn spawn(&mut self, parent: Entity, bundle: impl Bundle) {
         let e = self.map_world.spawn((
             bundle,
@@ -778,6 +790,19 @@ impl<'a> MapCreator<'a> {
         self.cont();
     }
 
+    /// Spawn an entity on the given position and its right neighbour, without moving on to the next position.
+    fn spawn_double_at(&mut self, parent: Entity, bundle: impl Bundle, pos: Pos) {
+        let e = self.map_world.spawn((
+            bundle,
+            Tiles::Double {
+                pos_a: pos,
+                pos_b: Pos::new(pos.x() + 1, pos.y()),
+            }
+        )).id();
+
+        self.map_world.entity_mut(parent).push_children(&[e]);
+    }
+
     /// Continue to next pos
     fn cont(&mut self) {
         self.current_column += 1;
@@ -0,0 +1,337 @@
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::core::prelude::*;
+use crate::headless::{create_simulated_app, current_state, run_until_game_started};
+
+/// How much game time passes with every tick of a scenario.
+const TICK_DURATION: Duration = Duration::from_nanos(1_000_000_000 / 60);
+
+/// The seed of every scenario, so the frightened ghosts always take the same ways.
+const SEED: u64 = 0;
+
+/// A harness to test the game with scripted scenarios.
+///
+/// A scenario starts a headless single player game on the default map and waits until pacman can move.
+/// Then pacman and the ghosts can be placed, keys can be held and the game can be advanced tick by tick.
+/// Every tick is 1/60 of a second of game time. The events of the game get counted, so a test can check
+/// what happened.
+///
+/// ```ignore
+/// let mut scenario = Scenario::new();
+/// scenario
+///     .place_pacman(Pos::new(3, 26), Right)
+///     .hold(Right)
+///     .advance(30);
+///
+/// assert!(scenario.count::<DotWasEaten>() > 0);
+/// ```
+pub(crate) struct Scenario {
+    app: App,
+}
+
+impl Scenario {
+    pub(crate) fn new() -> Self {
+        Self::with_settings(Settings {
+            game_mode: GameMode::SinglePlayer,
+            muted: true,
+            ..default()
+        })
+    }
+
+    /// Start the scenario with other settings, like a practice game.
+    pub(crate) fn with_settings(settings: Settings) -> Self {
+        let mut app = create_simulated_app(SEED, TICK_DURATION, settings, (EventRecorderPlugin, FreezePlugin));
+        run_until_game_started(&mut app);
+        Scenario { app }
+    }
+
+    pub(crate) fn level(&mut self, level: usize) -> &mut Self {
+        **self.app.world_mut().resource_mut::<Level>() = level;
+        self
+    }
+
+    /// Put pacman in the center of the given tile, looking in the given direction. In a co-op game
+    /// both pacmen get placed there.
+    pub(crate) fn place_pacman(&mut self, tile: Pos, direction: Dir) -> &mut Self {
+        let world = self.app.world_mut();
+        let mut query = world.query_filtered::<(&mut Transform, &mut Dir), With<Pacman>>();
+
+        for (mut transform, mut dir) in query.iter_mut(world) {
+            transform.translation = tile.to_vec3(transform.translation.z);
+            *dir = direction;
+        }
+
+        self
+    }
+
+    /// Put the ghost in the center of the given tile. It forgets where it was going and decides anew
+    /// in the next tick.
+    pub(crate) fn place_ghost(&mut self, ghost: Ghost, tile: Pos, direction: Dir, state: GhostState) -> &mut Self {
+        let world = self.app.world_mut();
+        let mut query = world.query::<(&Ghost, &mut Transform, &mut Dir, &mut GhostState, &mut Target)>();
+
+        for (g, mut transform, mut dir, mut ghost_state, mut target) in query.iter_mut(world) {
+            if *g != ghost {
+                continue;
+            }
+
+            transform.translation = tile.to_vec3(transform.translation.z);
+            *dir = direction;
+            *ghost_state = state;
+            target.clear();
+        }
+
+        self
+    }
+
+    /// Remove every dot and energizer which is not on one of the given tiles.
+    pub(crate) fn remove_edibles_except(&mut self, tiles: &[Pos]) -> &mut Self {
+        let world = self.app.world_mut();
+        let edibles = world
+            .query_filtered::<(Entity, &Transform), With<Edible>>()
+            .iter(world)
+            .filter(|(_, transform)| !tiles.contains(&Pos::from_vec3(transform.translation)))
+            .map(|(entity, _)| entity)
+            .collect::<Vec<_>>();
+
+        for entity in edibles {
+            world.entity_mut(entity).despawn_recursive();
+        }
+
+        self
+    }
+
+    /// Keep every ghost on its current tile for the rest of the scenario, so the ghosts cannot hit pacman
+    /// while the test watches something else. Pacman still dies if he walks into one of them.
+    pub(crate) fn freeze_ghosts(&mut self) -> &mut Self {
+        let world = self.app.world_mut();
+        let ghosts = world
+            .query_filtered::<(Entity, &Transform), With<Ghost>>()
+            .iter(world)
+            .map(|(entity, transform)| (entity, Frozen(transform.translation)))
+            .collect::<Vec<_>>();
+
+        for (entity, frozen) in ghosts {
+            world.entity_mut(entity).insert(frozen);
+        }
+
+        self
+    }
+
+    /// Let pacman and the ghost walk towards each other in the bottom corridor of the maze, which the ghost
+    /// cannot turn off before they meet. Pacman gets hit within a second.
+    pub(crate) fn pacman_runs_into(&mut self, ghost: Ghost) -> &mut Self {
+        self
+            .place_pacman(Pos::new(3, 2), Right)
+            .place_ghost(ghost, Pos::new(10, 2), Left, Scatter)
+            .hold(Right)
+    }
+
+    /// Hold the first key player one has bound to the direction, until another one is held or it gets released.
+    pub(crate) fn hold(&mut self, direction: Dir) -> &mut Self {
+        let world = self.app.world_mut();
+        let key = *world.resource::<Settings>().controls.keys_for(direction).first().expect("every direction should have a key");
+        let mut keyboard_input = world.resource_mut::<ButtonInput<KeyCode>>();
+        keyboard_input.release_all();
+        keyboard_input.press(key);
+        self
+    }
+
+    pub(crate) fn release(&mut self) -> &mut Self {
+        self.app.world_mut().resource_mut::<ButtonInput<KeyCode>>().release_all();
+        self
+    }
+
+    /// Send the event as if the game sent it, like a restart of the game.
+    pub(crate) fn send<E: Event>(&mut self, event: E) -> &mut Self {
+        self.app.world_mut().send_event(event);
+        self
+    }
+
+    pub(crate) fn advance(&mut self, ticks: usize) -> &mut Self {
+        for _ in 0..ticks {
+            self.app.update();
+        }
+
+        self
+    }
+
+    /// Advance the game until the condition is true. Panics if it takes more than the given ticks.
+    pub(crate) fn advance_until(&mut self, max_ticks: usize, mut condition: impl FnMut(&Scenario) -> bool) -> &mut Self {
+        for _ in 0..max_ticks {
+            if condition(self) {
+                return self;
+            }
+
+            self.app.update();
+        }
+
+        assert!(condition(self), "the condition was not met within {max_ticks} ticks");
+        self
+    }
+
+    /// How often the event was sent since the scenario started.
+    pub(crate) fn count<E: Event>(&self) -> usize {
+        self.app
+            .world()
+            .get_resource::<RecordedEvents<E>>()
+            .expect("the event is not recorded, add it to the EventRecorderPlugin")
+            .count
+    }
+
+    /// How many entities with the component exist right now.
+    pub(crate) fn count_entities<C: Component>(&self) -> usize {
+        self.app
+            .world()
+            .iter_entities()
+            .filter(|entity| entity.contains::<C>())
+            .count()
+    }
+
+    pub(crate) fn resource<R: Resource>(&self) -> &R {
+        self.app.world().resource::<R>()
+    }
+
+    pub(crate) fn resource_mut<R: Resource>(&mut self) -> Mut<'_, R> {
+        self.app.world_mut().resource_mut::<R>()
+    }
+
+    pub(crate) fn state(&self) -> GameState {
+        current_state(self.app.world())
+    }
+
+    pub(crate) fn pacman_tile(&self) -> Pos {
+        let transform = self.pacman_component::<Transform>();
+        Pos::from_vec3(transform.translation)
+    }
+
+    pub(crate) fn pacman_direction(&self) -> Dir {
+        *self.pacman_component::<Dir>()
+    }
+
+    /// The tile of the first entity with the given component, like a fruit. None if there is no such entity.
+    pub(crate) fn entity_tile<C: Component>(&self) -> Option<Pos> {
+        self.app
+            .world()
+            .iter_entities()
+            .filter(|entity| entity.contains::<C>())
+            .find_map(|entity| entity.get::<Transform>())
+            .map(|transform| Pos::from_vec3(transform.translation))
+    }
+
+    pub(crate) fn ghost_tile(&self, ghost: Ghost) -> Pos {
+        let transform = self.ghost_component::<Transform>(ghost);
+        Pos::from_vec3(transform.translation)
+    }
+
+    pub(crate) fn ghost_direction(&self, ghost: Ghost) -> Dir {
+        *self.ghost_component::<Dir>(ghost)
+    }
+
+    pub(crate) fn ghost_state(&self, ghost: Ghost) -> GhostState {
+        *self.ghost_component::<GhostState>(ghost)
+    }
+
+    fn pacman_component<C: Component>(&self) -> &C {
+        self.app
+            .world()
+            .iter_entities()
+            .filter(|entity| entity.contains::<Pacman>())
+            .find_map(|entity| entity.get::<C>())
+            .expect("there should be a pacman")
+    }
+
+    fn ghost_component<C: Component>(&self, ghost: Ghost) -> &C {
+        self.app
+            .world()
+            .iter_entities()
+            .filter(|entity| entity.get::<Ghost>() == Some(&ghost))
+            .find_map(|entity| entity.get::<C>())
+            .unwrap_or_else(|| panic!("there should be a {ghost:?}"))
+    }
+}
+
+/// Keeps the frozen ghosts of a scenario in place.
+struct FreezePlugin;
+
+impl Plugin for FreezePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, keep_frozen_in_place.after(MoveEntities));
+    }
+}
+
+/// Where a frozen entity stays, see [Scenario::freeze_ghosts].
+#[derive(Component)]
+struct Frozen(Vec3);
+
+fn keep_frozen_in_place(mut query: Query<(&Frozen, &mut Transform)>) {
+    for (frozen, mut transform) in &mut query {
+        transform.translation = frozen.0;
+    }
+}
+
+/// Counts the events a scenario can check.
+struct EventRecorderPlugin;
+
+impl Plugin for EventRecorderPlugin {
+    fn build(&self, app: &mut App) {
+        record::<PacmanWasHit>(app);
+        record::<GhostWasEaten>(app);
+        record::<DotWasEaten>(app);
+        record::<EnergizerWasEaten>(app);
+        record::<FruitWasEaten>(app);
+        record::<EAllEdiblesEaten>(app);
+        record::<AnimationFinished>(app);
+    }
+}
+
+#[derive(Resource)]
+struct RecordedEvents<E: Event> {
+    count: usize,
+    event: PhantomData<E>,
+}
+
+fn record<E: Event>(app: &mut App) {
+    app
+        .insert_resource(RecordedEvents::<E> { count: 0, event: PhantomData })
+        .add_systems(Last, count_events::<E>)
+    ;
+}
+
+fn count_events<E: Event>(
+    mut recorded: ResMut<RecordedEvents<E>>,
+    mut event_reader: EventReader<E>,
+) {
+    recorded.count += event_reader.read().count();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::prelude::*;
+    use crate::scenario::Scenario;
+
+    #[test]
+    fn pacman_scores_for_every_eaten_dot() {
+        let mut scenario = Scenario::new();
+        scenario
+            .place_pacman(Pos::new(3, 26), Right)
+            .hold(Right)
+            .advance(30);
+
+        let eaten_dots = scenario.count::<DotWasEaten>();
+        assert!(eaten_dots > 0);
+        assert_eq!(**scenario.resource::<Score>(), eaten_dots * POINTS_PER_DOT);
+    }
+
+    #[test]
+    fn pacman_gets_hit_when_he_runs_into_a_ghost() {
+        let mut scenario = Scenario::new();
+        scenario
+            .pacman_runs_into(Blinky)
+            .advance_until(60, |s| s.count::<PacmanWasHit>() > 0)
+            .advance_until(5, |s| s.state() == Game(PacmanHit));
+    }
+}
@@ -0,0 +1,200 @@
+use std::path::PathBuf;
+
+use crate::core::prelude::*;
+
+/// How many games are played if neither the number of games nor the seeds are given.
+const DEFAULT_GAMES: u64 = 10;
+
+pub const USAGE: &str = "\
+usage: pacman simulate [options]
+
+options:
+  --agent <agent>            the AI which plays: greedy, bfs or lookahead (default: bfs)
+  --games <n>                how many games to play (default: one for every seed)
+  --seeds <from..to | seed>  the seeds of the games, both ends included (default: 1..<games>)
+  --difficulty <difficulty>  easy, normal or hard (default: normal)
+  --format <format>          csv or json (default: json if the output ends with .json, otherwise csv)
+  --output <file>            where to write the report (default: the standard output)";
+
+/// Everything the simulate command was told to do.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimulationConfig {
+    pub agent: PacmanController,
+    /// The seed of every game to play.
+    pub seeds: Vec<u64>,
+    pub difficulty: Difficulty,
+    pub format: ReportFormat,
+    /// The file to write the report to. None writes it to the standard output.
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ReportFormat {
+    Csv,
+    Json,
+}
+
+impl SimulationConfig {
+    /// Parse the arguments which follow "simulate".
+    pub fn from_args(args: impl IntoIterator<Item=String>) -> Result<Self, String> {
+        let mut agent = PacmanController::AvoidGhosts;
+        let mut games = None;
+        let mut seeds = None;
+        let mut difficulty = Difficulty::Normal;
+        let mut format = None;
+        let mut output = None;
+
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| format!("{arg} needs a value"));
+
+            match arg.as_str() {
+                "--agent" => agent = parse_agent(&value()?)?,
+                "--games" => games = Some(value()?.parse::<u64>().map_err(|e| format!("invalid number of games: {e}"))?),
+                "--seeds" => seeds = Some(parse_seeds(&value()?)?),
+                "--difficulty" => difficulty = parse_difficulty(&value()?)?,
+                "--format" => format = Some(parse_format(&value()?)?),
+                "--output" => output = Some(PathBuf::from(value()?)),
+                _ => return Err(format!("unknown argument {arg}"))
+            }
+        }
+
+        let seeds = match (games, seeds) {
+            (None, None) => (1..=DEFAULT_GAMES).collect(),
+            (Some(games), None) => (1..=games).collect(),
+            (None, Some(seeds)) => seeds,
+            (Some(games), Some(seeds)) if games as usize > seeds.len() => {
+                return Err(format!("{games} games need at least as many seeds, but only {} are given", seeds.len()))
+            }
+            (Some(games), Some(seeds)) => seeds.into_iter().take(games as usize).collect(),
+        };
+
+        let format = format.unwrap_or_else(|| match &output {
+            Some(path) if path.extension().is_some_and(|ext| ext == "json") => ReportFormat::Json,
+            _ => ReportFormat::Csv,
+        });
+
+        Ok(SimulationConfig {
+            agent,
+            seeds,
+            difficulty,
+            format,
+            output,
+        })
+    }
+}
+
+fn parse_agent(value: &str) -> Result<PacmanController, String> {
+    match value {
+        "greedy" => Ok(PacmanController::Greedy),
+        "bfs" => Ok(PacmanController::AvoidGhosts),
+        "lookahead" => Ok(PacmanController::Lookahead),
+        _ => Err(format!("unknown agent {value}, expected greedy, bfs or lookahead"))
+    }
+}
+
+/// Parse either a single seed or a range like 1..1000, which includes both ends.
+fn parse_seeds(value: &str) -> Result<Vec<u64>, String> {
+    let parse = |seed: &str| seed.trim().parse::<u64>().map_err(|e| format!("invalid seed {seed}: {e}"));
+
+    match value.split_once("..") {
+        Some((from, to)) => {
+            let (from, to) = (parse(from)?, parse(to.trim_start_matches('='))?);
+
+            if from > to {
+                return Err(format!("the seeds {value} are empty"));
+            }
+
+            Ok((from..=to).collect())
+        }
+        None => Ok(vec![parse(value)?])
+    }
+}
+
+fn parse_difficulty(value: &str) -> Result<Difficulty, String> {
+    match value {
+        "easy" => Ok(Difficulty::Easy),
+        "normal" => Ok(Difficulty::Normal),
+        "hard" => Ok(Difficulty::Hard),
+        _ => Err(format!("unknown difficulty {value}, expected easy, normal or hard"))
+    }
+}
+
+fn parse_format(value: &str) -> Result<ReportFormat, String> {
+    match value {
+        "csv" => Ok(ReportFormat::Csv),
+        "json" => Ok(ReportFormat::Json),
+        _ => Err(format!("unknown format {value}, expected csv or json"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::core::prelude::*;
+    use super::{ReportFormat, SimulationConfig};
+
+    fn parse(args: &str) -> Result<SimulationConfig, String> {
+        SimulationConfig::from_args(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn without_arguments_ten_games_are_played_with_the_bfs_agent() {
+        assert_eq!(parse(""), Ok(SimulationConfig {
+            agent: PacmanController::AvoidGhosts,
+            seeds: (1..=10).collect(),
+            difficulty: Difficulty::Normal,
+            format: ReportFormat::Csv,
+            output: None,
+        }));
+    }
+
+    #[test]
+    fn seeds_can_be_a_range_or_a_single_seed() {
+        assert_eq!(parse("--seeds 1..1000").unwrap().seeds, (1..=1000).collect::<Vec<_>>());
+        assert_eq!(parse("--seeds 5..=7").unwrap().seeds, vec![5, 6, 7]);
+        assert_eq!(parse("--seeds 42").unwrap().seeds, vec![42]);
+    }
+
+    #[test]
+    fn the_number_of_games_limits_the_seeds() {
+        assert_eq!(parse("--games 3").unwrap().seeds, vec![1, 2, 3]);
+        assert_eq!(parse("--games 2 --seeds 10..20").unwrap().seeds, vec![10, 11]);
+        assert!(parse("--games 5 --seeds 1..3").is_err());
+    }
+
+    #[test]
+    fn every_agent_can_be_chosen() {
+        assert_eq!(parse("--agent greedy").unwrap().agent, PacmanController::Greedy);
+        assert_eq!(parse("--agent bfs").unwrap().agent, PacmanController::AvoidGhosts);
+        assert_eq!(parse("--agent lookahead").unwrap().agent, PacmanController::Lookahead);
+    }
+
+    #[test]
+    fn the_format_follows_the_output_file_unless_it_is_given() {
+        let config = parse("--output report.json").unwrap();
+        assert_eq!(config.format, ReportFormat::Json);
+        assert_eq!(config.output, Some(PathBuf::from("report.json")));
+
+        assert_eq!(parse("--output report.csv").unwrap().format, ReportFormat::Csv);
+        assert_eq!(parse("--output report.json --format csv").unwrap().format, ReportFormat::Csv);
+    }
+
+    #[test]
+    fn bad_arguments_are_rejected() {
+        for args in [
+            "--agent random",
+            "--games many",
+            "--seeds 10..1",
+            "--seeds 1..x",
+            "--difficulty impossible",
+            "--format xml",
+            "--speed 10",
+            "--games",
+        ] {
+            assert!(parse(args).is_err(), "{args} should be rejected");
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use std::fs;
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::core::prelude::*;
+use crate::headless::{create_simulated_app, current_state, run_until_game_started};
+use crate::simulate::report::{to_csv, to_json};
+use crate::simulate::stats::StatsPlugin;
+
+pub use crate::core::pacman::PacmanController;
+pub use crate::simulate::args::*;
+pub use crate::simulate::report::*;
+
+mod args;
+mod report;
+mod stats;
+
+/// How much game time passes with every update. The game runs as fast as possible, so this only decides
+/// how exact the simulation is.
+const TICK_DURATION: Duration = Duration::from_nanos(1_000_000_000 / 60);
+
+/// A game is cut off after this much game time, in case the agent gets stuck somewhere.
+const MAX_GAME_TIME: Duration = Duration::from_secs(60 * 60);
+
+/// Run the simulate command with the arguments which follow "simulate": play the games and write the report.
+/// A short summary is printed to the standard error.
+pub fn run(args: impl IntoIterator<Item=String>) -> Result<(), String> {
+    let config = SimulationConfig::from_args(args)?;
+    let games = simulate(&config);
+
+    let report = match config.format {
+        ReportFormat::Csv => to_csv(&games),
+        ReportFormat::Json => to_json(config.agent, &games),
+    };
+
+    match &config.output {
+        Some(path) => fs::write(path, report).map_err(|e| format!("could not write the report to {}: {e}", path.display()))?,
+        None => print!("{report}"),
+    }
+
+    print_summary(&games);
+    Ok(())
+}
+
+/// Let the agent play one game for every seed, as fast as possible, and report how they went.
+pub fn simulate(config: &SimulationConfig) -> Vec<GameReport> {
+    config.seeds
+        .iter()
+        .enumerate()
+        .map(|(i, seed)| {
+            let report = play_game(config, *seed);
+            eprintln!("game {}/{} (seed {seed}): {} points, level {}", i + 1, config.seeds.len(), report.score, report.level);
+            report
+        })
+        .collect()
+}
+
+fn play_game(config: &SimulationConfig, seed: u64) -> GameReport {
+    let settings = Settings {
+        difficulty: config.difficulty,
+        game_mode: GameMode::SinglePlayer,
+        pacman_controller: config.agent,
+        muted: true,
+        ..default()
+    };
+
+    let mut app = create_simulated_app(seed, TICK_DURATION, settings, StatsPlugin);
+    run_until_game_started(&mut app);
+
+    let max_ticks = MAX_GAME_TIME.as_nanos() / TICK_DURATION.as_nanos();
+    let mut ticks = 0;
+
+    while current_state(app.world()) != Game(GameOver) && ticks < max_ticks {
+        app.update();
+        ticks += 1;
+    }
+
+    GameReport::from_world(seed, ticks < max_ticks, app.world())
+}
+
+fn print_summary(games: &[GameReport]) {
+    if games.is_empty() {
+        return;
+    }
+
+    let average = |value: fn(&GameReport) -> usize| games.iter().map(value).sum::<usize>() as f32 / games.len() as f32;
+
+    eprintln!(
+        "{} games, average score {:.0}, average level {:.1}, best score {}",
+        games.len(),
+        average(|game| game.score),
+        average(|game| game.level),
+        games.iter().map(|game| game.score).max().unwrap_or_default(),
+    );
+}
@@ -0,0 +1,100 @@
+use std::fmt::Display;
+
+use bevy::prelude::*;
+use serde::Serialize;
+
+use crate::core::prelude::*;
+use crate::simulate::stats::GameStats;
+
+/// How one simulated game went.
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct GameReport {
+    pub seed: u64,
+    pub score: usize,
+    pub level: usize,
+    /// False if the game was cut off because it took too long.
+    pub finished: bool,
+    pub deaths_by_ghost: DeathsByGhost,
+    /// How many dots were left every time pacman died.
+    pub dots_remaining_at_death: Vec<usize>,
+    /// How many ghosts pacman ate after every energizer.
+    pub ghosts_eaten_per_energizer: Vec<usize>,
+    /// How many seconds (of game time) every level took, starting with level 1.
+    pub seconds_per_level: Vec<f32>,
+}
+
+/// How often every ghost caught pacman.
+#[derive(Serialize, Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct DeathsByGhost {
+    pub blinky: usize,
+    pub pinky: usize,
+    pub inky: usize,
+    pub clyde: usize,
+}
+
+impl GameReport {
+    pub(super) fn from_world(seed: u64, finished: bool, world: &World) -> Self {
+        let stats = world.resource::<GameStats>();
+        let deaths_of = |ghost| stats.deaths_by_ghost.get(&ghost).copied().unwrap_or_default();
+
+        GameReport {
+            seed,
+            score: **world.resource::<Score>(),
+            level: **world.resource::<Level>(),
+            finished,
+            deaths_by_ghost: DeathsByGhost {
+                blinky: deaths_of(Blinky),
+                pinky: deaths_of(Pinky),
+                inky: deaths_of(Inky),
+                clyde: deaths_of(Clyde),
+            },
+            dots_remaining_at_death: stats.dots_remaining_at_death.clone(),
+            ghosts_eaten_per_energizer: stats.ghosts_eaten_per_energizer.clone(),
+            seconds_per_level: stats.seconds_per_level.clone(),
+        }
+    }
+}
+
+/// The whole report of a simulation, as it gets written to JSON.
+#[derive(Serialize)]
+struct Report<'a> {
+    agent: PacmanController,
+    games: &'a [GameReport],
+}
+
+pub(super) fn to_json(agent: PacmanController, games: &[GameReport]) -> String {
+    serde_json::to_string_pretty(&Report { agent, games }).expect("a report can always be serialized")
+}
+
+/// Write one row per game. Columns with multiple values (like the dots remaining at every death)
+/// separate them with semicolons.
+pub(super) fn to_csv(games: &[GameReport]) -> String {
+    let mut csv = String::from("seed,score,level,finished,deaths_blinky,deaths_pinky,deaths_inky,deaths_clyde,\
+        dots_remaining_at_death,ghosts_eaten_per_energizer,seconds_per_level\n");
+
+    for game in games {
+        let seconds_per_level = game.seconds_per_level.iter().map(|seconds| format!("{seconds:.2}")).collect::<Vec<_>>();
+        let row = [
+            game.seed.to_string(),
+            game.score.to_string(),
+            game.level.to_string(),
+            game.finished.to_string(),
+            game.deaths_by_ghost.blinky.to_string(),
+            game.deaths_by_ghost.pinky.to_string(),
+            game.deaths_by_ghost.inky.to_string(),
+            game.deaths_by_ghost.clyde.to_string(),
+            join(&game.dots_remaining_at_death),
+            join(&game.ghosts_eaten_per_energizer),
+            join(&seconds_per_level),
+        ];
+
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+
+    csv
+}
+
+fn join(values: &[impl Display]) -> String {
+    values.iter().map(|value| value.to_string()).collect::<Vec<_>>().join(";")
+}
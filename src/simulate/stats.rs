@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::core::prelude::*;
+
+/// Watches a simulated game and collects what is reported about it.
+pub(super) struct StatsPlugin;
+
+impl Plugin for StatsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<GameStats>()
+            .add_systems(Update, measure_level_time.run_if(in_game))
+            .add_systems(Last, (
+                record_deaths,
+                record_energizers_and_eaten_ghosts,
+            ))
+        ;
+    }
+}
+
+/// Everything which happened in one game, besides the score and level.
+#[derive(Resource, Default)]
+pub(super) struct GameStats {
+    pub(super) deaths_by_ghost: HashMap<Ghost, usize>,
+    /// How many dots were left every time pacman died.
+    pub(super) dots_remaining_at_death: Vec<usize>,
+    /// How many ghosts were eaten after every energizer.
+    pub(super) ghosts_eaten_per_energizer: Vec<usize>,
+    /// How many seconds every level took, starting with level 1.
+    pub(super) seconds_per_level: Vec<f32>,
+}
+
+fn measure_level_time(
+    time: Res<Time>,
+    level: Res<Level>,
+    mut stats: ResMut<GameStats>,
+) {
+    if **level == 0 {
+        return;
+    }
+
+    let index = **level - 1;
+
+    if stats.seconds_per_level.len() <= index {
+        stats.seconds_per_level.resize(index + 1, 0.0);
+    }
+
+    stats.seconds_per_level[index] += time.delta_seconds();
+}
+
+/// Record one death for every lost life. A hit by several ghosts at once (or of both pacmen at the
+/// same time) costs only one life, so only the first hit of a frame counts and its ghost gets the death.
+fn record_deaths(
+    mut stats: ResMut<GameStats>,
+    mut event_reader: EventReader<PacmanWasHit>,
+    ghost_query: Query<&Ghost>,
+    dot_query: Query<(), With<Dot>>,
+) {
+    let Some(&PacmanWasHit(_, ghost)) = event_reader.read().next() else {
+        return;
+    };
+    event_reader.clear();
+
+    if let Ok(ghost) = ghost_query.get(ghost) {
+        *stats.deaths_by_ghost.entry(*ghost).or_default() += 1;
+    }
+
+    let dots_remaining = dot_query.iter().count();
+    stats.dots_remaining_at_death.push(dots_remaining);
+}
+
+fn record_energizers_and_eaten_ghosts(
+    mut stats: ResMut<GameStats>,
+    mut energizer_events: EventReader<EnergizerWasEaten>,
+    mut ghost_events: EventReader<GhostWasEaten>,
+) {
+    for _ in energizer_events.read() {
+        stats.ghosts_eaten_per_energizer.push(0);
+    }
+
+    for _ in ghost_events.read() {
+        if let Some(eaten) = stats.ghosts_eaten_per_energizer.last_mut() {
+            *eaten += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use crate::core::prelude::*;
+    use super::{record_deaths, GameStats};
+
+    #[test]
+    fn a_hit_by_several_ghosts_at_once_is_one_death() {
+        let mut app = App::new();
+        app
+            .init_resource::<GameStats>()
+            .add_event::<PacmanWasHit>()
+            .add_systems(Update, record_deaths);
+
+        let pacman = app.world_mut().spawn(Pacman).id();
+        let blinky = app.world_mut().spawn(Blinky).id();
+        let pinky = app.world_mut().spawn(Pinky).id();
+
+        app.world_mut().send_event(PacmanWasHit(pacman, blinky));
+        app.world_mut().send_event(PacmanWasHit(pacman, pinky));
+        app.update();
+        app.update();
+
+        let stats = app.world().resource::<GameStats>();
+        assert_eq!(stats.dots_remaining_at_death, vec![0]);
+        assert_eq!(stats.deaths_by_ghost.get(&Blinky), Some(&1));
+        assert_eq!(stats.deaths_by_ghost.get(&Pinky), None);
+    }
+}
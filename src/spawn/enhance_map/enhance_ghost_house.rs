@@ -1,5 +1,6 @@
+use std::collections::BTreeSet;
+
 use bevy::prelude::*;
-use bevy_sprite_sheet::{SpriteSheet, SpriteSheets};
 
 use crate::core::prelude::*;
 
@@ -18,122 +19,126 @@ impl Plugin for EnhanceGhostHousePlugin {
 
 fn enhance_ghost_house(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    sprite_sheets: Res<SpriteSheets>,
+    theme: Res<Theme>,
+    animation_sheets: Res<AnimationSheets>,
     ghost_house_areas: Query<(&GhostHouseArea, &Tiles)>,
+    entrances: Query<(&GhostHouseEntrance, &Tiles)>,
+    centers: Query<(&GhostHouseCenter, &Tiles)>,
+    spawn_points: Query<(&GhostSpawnPoint, &Tiles)>,
 ) {
-    let bottom_left = get_bottom_left(&ghost_house_areas);
-    let rotation = get_rotation(&ghost_house_areas);
-    let spawns = create_spawns(rotation, bottom_left);
-
-    let ghost_house = commands.spawn((
-        Name::new("GhostHouse"),
-        GhostHouse,
-        SpatialBundle::default()
-    )).id();
-
-    for spawn in spawns {
-        commands.entity(ghost_house).with_children(|parent| {
-            parent.spawn((
-                Name::new("GhostSpawn"),
-                spawn
-            ));
-        });
-    }
+    let house_indices = ghost_house_areas
+        .iter()
+        .map(|(area, _)| area.house)
+        .collect::<BTreeSet<_>>();
 
-    spawn_house_walls(
-        &mut commands,
-        ghost_house,
-        bottom_left,
-        rotation,
-        &asset_server,
-        &sprite_sheets
-    );
-}
+    for index in house_indices {
+        let area = ghost_house_areas
+            .iter()
+            .filter(|(area, _)| area.house == index)
+            .map(|(_, tiles)| tiles.to_pos())
+            .collect::<Vec<_>>();
+        let (bottom_left, top_right) = get_bounds(&area);
+        let (entrance, leave_direction) = entrances
+            .iter()
+            .find(|(entrance, _)| entrance.house == index)
+            .map(|(entrance, tiles)| (*tiles, entrance.direction))
+            .unwrap_or_else(|| panic!("the ghost house {index} should have an entrance"));
+        let center = centers
+            .iter()
+            .find(|(center, _)| center.house == index)
+            .map(|(_, tiles)| *tiles)
+            .unwrap_or_else(|| panic!("the ghost house {index} should have a center"));
 
-fn get_bottom_left(ghost_house_areas: &Query<(&GhostHouseArea, &Tiles)>) -> Pos {
-    ghost_house_areas
-        .iter()
-        .map(|(_, tiles)| tiles.to_pos())
-        .fold(
-            Pos::new(isize::MAX, isize::MAX),
-            |acc, pos| Pos::new(isize::min(acc.x(), pos.x()), isize::min(acc.y(), pos.y())),
-        )
-}
+        let ghost_house = GhostHouse {
+            index,
+            entrance_positions: entrance.positions(),
+            entrance_coordinates: entrance.to_vec3(0.0),
+            leave_direction,
+            center_coordinates: center.to_vec3(0.0),
+            exit_direction: get_exit_direction(center.to_vec3(0.0), entrance.to_vec3(0.0)),
+        };
+        let door = get_door(&entrance.positions(), bottom_left, top_right);
 
-fn get_rotation(ghost_house_areas: &Query<(&GhostHouseArea, &Tiles)>) -> Rotation {
-    ghost_house_areas
-        .iter()
-        .find_map(|(area, _)| Some(area.rotation))
-        .expect("at least one ghost house area should exist")
-}
+        let ghost_house_entity = commands.spawn((
+            Name::new("GhostHouse"),
+            ghost_house,
+            SpatialBundle::default()
+        )).id();
 
-/// TODO: wrong. Pinky spawns looking down, Inky and Clyde looking up
-fn create_spawns(rotation: Rotation, bottom_left: Pos) -> [GhostSpawn; 4] {
-    [
-        create_blinky_spawn(rotation, bottom_left),
-        create_pinky_spawn(rotation, bottom_left),
-        create_inky_spawn(rotation, bottom_left),
-        create_clyde_spawn(rotation, bottom_left),
-    ]
-}
+        for (spawn_point, tiles) in spawn_points.iter().filter(|(spawn_point, _)| spawn_point.house == index) {
+            commands.entity(ghost_house_entity).with_children(|parent| {
+                parent.spawn((
+                    Name::new("GhostSpawn"),
+                    create_spawn(spawn_point, tiles)
+                ));
+            });
+        }
 
-fn create_blinky_spawn(rotation: Rotation, bottom_left: Pos) -> GhostSpawn {
-    match rotation {
-        D0 => create_spawn_with_offsets(bottom_left, (3, 5), (4, 5), BLINKY_Z, Blinky, Left),
-        D90 => create_spawn_with_offsets(bottom_left, (5, 3), (5, 4), BLINKY_Z, Blinky, Up),
-        D180 => create_spawn_with_offsets(bottom_left, (3, -1), (4, -1), BLINKY_Z, Blinky, Right),
-        D270 => create_spawn_with_offsets(bottom_left, (-1, 3), (-1, 4), BLINKY_Z, Blinky, Down),
+        spawn_house_walls(
+            &mut commands,
+            ghost_house_entity,
+            bottom_left,
+            top_right,
+            &door,
+            &theme,
+            &animation_sheets
+        );
     }
 }
 
-fn create_pinky_spawn(rotation: Rotation, bottom_left: Pos) -> GhostSpawn {
-    match rotation {
-        D0 => create_spawn_with_offsets(bottom_left, (3, 2), (4, 2), PINKY_Z, Pinky, Up),
-        D90 => create_spawn_with_offsets(bottom_left, (2, 3), (2, 4), PINKY_Z, Pinky, Right),
-        D180 => create_spawn_with_offsets(bottom_left, (3, 2), (4, 2), PINKY_Z, Pinky, Down),
-        D270 => create_spawn_with_offsets(bottom_left, (2, 3), (2, 4), PINKY_Z, Pinky, Left),
-    }
+/// Return the bottom left and top right corner of the rectangle the given positions form.
+fn get_bounds(area: &[Pos]) -> (Pos, Pos) {
+    area.iter().fold(
+        (Pos::new(isize::MAX, isize::MAX), Pos::new(isize::MIN, isize::MIN)),
+        |(bottom_left, top_right), pos| (
+            Pos::new(isize::min(bottom_left.x(), pos.x()), isize::min(bottom_left.y(), pos.y())),
+            Pos::new(isize::max(top_right.x(), pos.x()), isize::max(top_right.y(), pos.y())),
+        ),
+    )
 }
 
-fn create_inky_spawn(rotation: Rotation, bottom_left: Pos) -> GhostSpawn {
-    match rotation {
-        D0 => create_spawn_with_offsets(bottom_left, (1, 2), (2, 2), INKY_Z, Inky, Down),
-        D90 => create_spawn_with_offsets(bottom_left, (2, 5), (2, 6), INKY_Z, Inky, Left),
-        D180 => create_spawn_with_offsets(bottom_left, (5, 2), (6, 2), INKY_Z, Inky, Up),
-        D270 => create_spawn_with_offsets(bottom_left, (2, 1), (2, 2), INKY_Z, Inky, Right),
+/// The direction from the center to the entrance, which is the way ghosts take to leave the house.
+fn get_exit_direction(center: Vec3, entrance: Vec3) -> Dir {
+    let difference = entrance - center;
+
+    match difference.x.abs() > difference.y.abs() {
+        true => if difference.x > 0.0 { Right } else { Left },
+        false => if difference.y > 0.0 { Up } else { Down },
     }
 }
 
-fn create_clyde_spawn(rotation: Rotation, bottom_left: Pos) -> GhostSpawn {
-    match rotation {
-        D0 => create_spawn_with_offsets(bottom_left, (5, 2), (6, 2), CLYDE_Z, Clyde, Down),
-        D90 => create_spawn_with_offsets(bottom_left, (2, 1), (2, 2), CLYDE_Z, Clyde, Left),
-        D180 => create_spawn_with_offsets(bottom_left, (1, 2), (2, 2), CLYDE_Z, Clyde, Up),
-        D270 => create_spawn_with_offsets(bottom_left, (2, 5), (2, 6), CLYDE_Z, Clyde, Right),
-    }
+/// The wall tiles next to the entrance become the door of the house.
+fn get_door(entrance_positions: &[Pos], bottom_left: Pos, top_right: Pos) -> Vec<Pos> {
+    entrance_positions
+        .iter()
+        .flat_map(|pos| pos.neighbours_with_directions())
+        .map(|(pos, _)| pos)
+        .filter(|pos| is_on_border(*pos, bottom_left, top_right))
+        .collect()
 }
 
-fn create_spawn_with_offsets(
-    bottom_left: Pos,
-    offsets_0: (isize, isize),
-    offsets_1: (isize, isize),
-    z: f32,
-    ghost: Ghost,
-    spawn_direction: Dir,
-) -> GhostSpawn {
-    let x = bottom_left.x();
-    let y = bottom_left.y();
-    let positions = [
-        Pos::new(x + offsets_0.0, y + offsets_0.1),
-        Pos::new(x + offsets_1.0, y + offsets_1.1),
-    ];
-    let coordinates = Vec3::from_positions(positions.iter(), z);
+fn is_on_border(pos: Pos, bottom_left: Pos, top_right: Pos) -> bool {
+    let within_x = (bottom_left.x()..=top_right.x()).contains(&pos.x());
+    let within_y = (bottom_left.y()..=top_right.y()).contains(&pos.y());
+    let on_vertical_border = pos.x() == bottom_left.x() || pos.x() == top_right.x();
+    let on_horizontal_border = pos.y() == bottom_left.y() || pos.y() == top_right.y();
+
+    within_x && within_y && (on_vertical_border || on_horizontal_border)
+}
+
+fn create_spawn(spawn_point: &GhostSpawnPoint, tiles: &Tiles) -> GhostSpawn {
+    let z = match spawn_point.ghost {
+        Blinky => BLINKY_Z,
+        Pinky => PINKY_Z,
+        Inky => INKY_Z,
+        Clyde => CLYDE_Z,
+    };
+
     GhostSpawn {
-        ghost,
-        spawn_direction,
-        positions,
-        coordinates,
+        ghost: spawn_point.ghost,
+        house: spawn_point.house,
+        coordinates: tiles.to_vec3(z),
+        spawn_direction: spawn_point.direction,
     }
 }
 
@@ -141,206 +146,72 @@ fn spawn_house_walls(
     commands: &mut Commands,
     ghost_house: Entity,
     bottom_left: Pos,
-    rotation: Rotation,
-    asset_server: &AssetServer,
-    sprite_sheets: &SpriteSheets,
+    top_right: Pos,
+    door: &[Pos],
+    theme: &Theme,
+    animation_sheets: &AnimationSheets,
 ) {
-    let top_right = match rotation {
-        D0 | D180 => Pos::new(bottom_left.x() + 7, bottom_left.y() + 4),
-        _ => Pos::new(bottom_left.x() + 4, bottom_left.y() + 7),
-    };
-
     let corners = spawn_corners(
         commands,
         bottom_left,
         top_right,
-        sprite_sheets,
+        theme,
+        animation_sheets,
     );
-    let top = spawn_top(commands, rotation, bottom_left, top_right, asset_server, sprite_sheets);
-    let bottom = spawn_bottom(commands, rotation, bottom_left, asset_server, sprite_sheets);
-    let left = spawn_left(commands, rotation, bottom_left, asset_server, sprite_sheets);
-    let right = spawn_right(commands, rotation, bottom_left, top_right, asset_server, sprite_sheets);
+    // the horizontal walls are turned upside down when the door is at the bottom
+    let horizontal_rotation = match door.iter().any(|pos| pos.y() == bottom_left.y()) {
+        true => D180,
+        false => D0
+    };
+    let top = (bottom_left.x() + 1..top_right.x()).map(|x| (Pos::new(x, top_right.y()), D0, horizontal_rotation));
+    let bottom = (bottom_left.x() + 1..top_right.x()).map(|x| (Pos::new(x, bottom_left.y()), D180, horizontal_rotation));
+    let left = (bottom_left.y() + 1..top_right.y()).map(|y| (Pos::new(bottom_left.x(), y), D90, D90));
+    let right = (bottom_left.y() + 1..top_right.y()).map(|y| (Pos::new(top_right.x(), y), D90, D90));
+    let sheet = theme.animations(animation_sheets, &SpriteId::GHOST_HOUSE_WALL);
+
+    let walls = top
+        .chain(bottom)
+        .chain(left)
+        .chain(right)
+        .map(|(pos, door_rotation, wall_rotation)| match door.contains(&pos) {
+            true => spawn_entrance(commands, theme, door_rotation, pos),
+            false => spawn_wall(commands, sheet, wall_rotation, pos),
+        })
+        .collect::<Vec<_>>();
+
     commands.entity(ghost_house).push_children(&corners);
-    commands.entity(ghost_house).push_children(&top);
-    commands.entity(ghost_house).push_children(&bottom);
-    commands.entity(ghost_house).push_children(&left);
-    commands.entity(ghost_house).push_children(&right);
+    commands.entity(ghost_house).push_children(&walls);
 }
 
 fn spawn_corners(
     commands: &mut Commands,
     bottom_left: Pos,
     top_right: Pos,
-    sprite_sheets: &SpriteSheets,
+    theme: &Theme,
+    animation_sheets: &AnimationSheets,
 ) -> [Entity; 4] {
-    let sheet = sprite_sheets.get_sheet("textures/walls/ghost_house_wall_corner");
+    let sheet = theme.animations(animation_sheets, &SpriteId::GHOST_HOUSE_WALL_CORNER);
     [
-        spawn_wall(commands, &sheet, D0, Pos::new(bottom_left.x(), top_right.y())),
-        spawn_wall(commands, &sheet, D90, top_right),
-        spawn_wall(commands, &sheet, D180, Pos::new(top_right.x(), bottom_left.y())),
-        spawn_wall(commands, &sheet, D270, bottom_left),
+        spawn_wall(commands, sheet, D0, Pos::new(bottom_left.x(), top_right.y())),
+        spawn_wall(commands, sheet, D90, top_right),
+        spawn_wall(commands, sheet, D180, Pos::new(top_right.x(), bottom_left.y())),
+        spawn_wall(commands, sheet, D270, bottom_left),
     ]
 }
 
-fn spawn_top(
-    commands: &mut Commands,
-    rotation: Rotation,
-    bottom_left: Pos,
-    top_right: Pos,
-    asset_server: &AssetServer,
-    sprite_sheets: &SpriteSheets,
-) -> Vec<Entity> {
-    let x = bottom_left.x() + 1;
-    let y = top_right.y();
-    let sheet = sprite_sheets.get_sheet("textures/walls/ghost_house_wall");
-
-    match rotation {
-        D0 => vec![
-            spawn_wall(commands, &sheet, rotation, Pos::new(x, y)),
-            spawn_wall(commands, &sheet, rotation, Pos::new(x + 1, y)),
-            spawn_entrance(commands, asset_server, rotation, Pos::new(x + 2, y)),
-            spawn_entrance(commands, asset_server, rotation, Pos::new(x + 3, y)),
-            spawn_wall(commands, &sheet, rotation, Pos::new(x + 4, y)),
-            spawn_wall(commands, &sheet, rotation, Pos::new(x + 5, y)),
-        ],
-        D180 => vec![
-            spawn_wall(commands, &sheet, rotation, Pos::new(x, y)),
-            spawn_wall(commands, &sheet, rotation, Pos::new(x + 1, y)),
-            spawn_wall(commands, &sheet, rotation, Pos::new(x + 2, y)),
-            spawn_wall(commands, &sheet, rotation, Pos::new(x + 3, y)),
-            spawn_wall(commands, &sheet, rotation, Pos::new(x + 4, y)),
-            spawn_wall(commands, &sheet, rotation, Pos::new(x + 5, y)),
-        ],
-        _ => vec![
-            spawn_wall(commands, &sheet, rotation, Pos::new(x, y)),
-            spawn_wall(commands, &sheet, rotation, Pos::new(x + 1, y)),
-            spawn_wall(commands, &sheet, rotation, Pos::new(x + 2, y)),
-        ],
-    }
-}
-
-fn spawn_bottom(
-    commands: &mut Commands,
-    rotation: Rotation,
-    bottom_left: Pos,
-    asset_server: &AssetServer,
-    sprite_sheets: &SpriteSheets,
-) -> Vec<Entity> {
-    let x = bottom_left.x() + 1;
-    let y = bottom_left.y();
-    let sheet = sprite_sheets.get_sheet("textures/walls/ghost_house_wall");
-
-    match rotation {
-        D180 => vec![
-            spawn_wall(commands, &sheet, rotation, Pos::new(x, y)),
-            spawn_wall(commands, &sheet, rotation, Pos::new(x + 1, y)),
-            spawn_entrance(commands, asset_server, rotation, Pos::new(x + 2, y)),
-            spawn_entrance(commands, asset_server, rotation, Pos::new(x + 3, y)),
-            spawn_wall(commands, &sheet, rotation, Pos::new(x + 4, y)),
-            spawn_wall(commands, &sheet, rotation, Pos::new(x + 5, y)),
-        ],
-        D0 => vec![
-            spawn_wall(commands, &sheet, rotation, Pos::new(x, y)),
-            spawn_wall(commands, &sheet, rotation, Pos::new(x + 1, y)),
-            spawn_wall(commands, &sheet, rotation, Pos::new(x + 2, y)),
-            spawn_wall(commands, &sheet, rotation, Pos::new(x + 3, y)),
-            spawn_wall(commands, &sheet, rotation, Pos::new(x + 4, y)),
-            spawn_wall(commands, &sheet, rotation, Pos::new(x + 5, y)),
-        ],
-        _ => vec![
-            spawn_wall(commands, &sheet, rotation, Pos::new(x, y)),
-            spawn_wall(commands, &sheet, rotation, Pos::new(x + 1, y)),
-            spawn_wall(commands, &sheet, rotation, Pos::new(x + 2, y)),
-        ],
-    }
-}
-
-fn spawn_left(
-    commands: &mut Commands,
-    rotation: Rotation,
-    bottom_left: Pos,
-    asset_server: &AssetServer,
-    sprite_sheets: &SpriteSheets,
-) -> Vec<Entity> {
-    let x = bottom_left.x();
-    let y = bottom_left.y() + 1;
-    let sheet = sprite_sheets.get_sheet("textures/walls/ghost_house_wall");
-
-    match rotation {
-        D270 => vec![
-            spawn_wall(commands, sheet, D90, Pos::new(x, y)),
-            spawn_wall(commands, sheet, D90, Pos::new(x, y + 1)),
-            spawn_entrance(commands, asset_server, D90, Pos::new(x, y + 2)),
-            spawn_entrance(commands, asset_server, D90, Pos::new(x, y + 3)),
-            spawn_wall(commands, sheet, D90, Pos::new(x, y + 4)),
-            spawn_wall(commands, sheet, D90, Pos::new(x, y + 5)),
-        ],
-        D90 => vec![
-            spawn_wall(commands, sheet, D90, Pos::new(x, y)),
-            spawn_wall(commands, sheet, D90, Pos::new(x, y + 1)),
-            spawn_wall(commands, sheet, D90, Pos::new(x, y + 2)),
-            spawn_wall(commands, sheet, D90, Pos::new(x, y + 3)),
-            spawn_wall(commands, sheet, D90, Pos::new(x, y + 4)),
-            spawn_wall(commands, sheet, D90, Pos::new(x, y + 5)),
-        ],
-        D0 | D180 => vec![
-            spawn_wall(commands, sheet, D90, Pos::new(x, y)),
-            spawn_wall(commands, sheet, D90, Pos::new(x, y + 1)),
-            spawn_wall(commands, sheet, D90, Pos::new(x, y + 2)),
-        ],
-    }
-}
-
-fn spawn_right(
-    commands: &mut Commands,
-    rotation: Rotation,
-    bottom_left: Pos,
-    top_right: Pos,
-    asset_server: &AssetServer,
-    sprite_sheets: &SpriteSheets,
-) -> Vec<Entity> {
-    let x = top_right.x();
-    let y = bottom_left.y() + 1;
-    let sheet = sprite_sheets.get_sheet("textures/walls/ghost_house_wall");
-
-    match rotation {
-        D90 => vec![
-            spawn_wall(commands, sheet, D90, Pos::new(x, y)),
-            spawn_wall(commands, sheet, D90, Pos::new(x, y + 1)),
-            spawn_entrance(commands, asset_server, D90, Pos::new(x, y + 2)),
-            spawn_entrance(commands, asset_server, D90, Pos::new(x, y + 3)),
-            spawn_wall(commands, sheet, D90, Pos::new(x, y + 4)),
-            spawn_wall(commands, sheet, D90, Pos::new(x, y + 5)),
-        ],
-        D270 => vec![
-            spawn_wall(commands, sheet, D90, Pos::new(x, y)),
-            spawn_wall(commands, sheet, D90, Pos::new(x, y + 1)),
-            spawn_wall(commands, sheet, D90, Pos::new(x, y + 2)),
-            spawn_wall(commands, sheet, D90, Pos::new(x, y + 3)),
-            spawn_wall(commands, sheet, D90, Pos::new(x, y + 4)),
-            spawn_wall(commands, sheet, D90, Pos::new(x, y + 5)),
-        ],
-        D0 | D180 => vec![
-            spawn_wall(commands, sheet, D90, Pos::new(x, y)),
-            spawn_wall(commands, sheet, D90, Pos::new(x, y + 1)),
-            spawn_wall(commands, sheet, D90, Pos::new(x, y + 2)),
-        ],
-    }
-}
-
 fn spawn_wall(
     commands: &mut Commands,
-    sheet: &SpriteSheet,
+    sheet: &AsepriteAnimations,
     rotation: Rotation,
     position: Pos,
 ) -> Entity {
     let animations = Animations::new(
         [
-            ("idle", Animation::from_texture(sheet.image_at(0))),
-            ("blinking", Animation::from_textures(0.5, true, sheet.images_at([0, 1])))
+            ("idle", sheet.animation("idle")),
+            ("blinking", sheet.animation("blinking"))
         ]
         , "idle",
-    );
+    ).with_clock(MAZE_FLASH_CLOCK);
 
     let mut transform = Transform::from_translation(position.to_vec3(0.0));
     transform.rotation = rotation.quat_z();
@@ -363,7 +234,7 @@ fn spawn_wall(
 
 fn spawn_entrance(
     commands: &mut Commands,
-    asset_server: &AssetServer,
+    theme: &Theme,
     rotation: Rotation,
     position: Pos,
 ) -> Entity {
@@ -374,7 +245,7 @@ fn spawn_entrance(
         Name::new("Wall"),
         Wall,
         SpriteBundle {
-            texture: asset_server.load("textures/walls/ghost_house_entrance.png"),
+            texture: theme.image(&SpriteId::GHOST_HOUSE_ENTRANCE),
             sprite: Sprite {
                 custom_size: Some(Vec2::splat(WALL_DIMENSION)),
                 ..default()
@@ -383,4 +254,28 @@ fn spawn_entrance(
             ..default()
         }
     )).id()
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::prelude::*;
+    use crate::spawn::enhance_map::enhance_ghost_house::{get_bounds, get_door, get_exit_direction};
+
+    #[test]
+    fn the_wall_next_to_the_entrance_becomes_the_door() {
+        let area = (3..=7).flat_map(|x| (2..=5).map(move |y| Pos::new(x, y))).collect::<Vec<_>>();
+        let (bottom_left, top_right) = get_bounds(&area);
+        assert_eq!((bottom_left, top_right), (Pos::new(3, 2), Pos::new(7, 5)));
+
+        let entrance = [Pos::new(8, 3), Pos::new(8, 4)];
+        assert_eq!(get_door(&entrance, bottom_left, top_right), vec![Pos::new(7, 3), Pos::new(7, 4)]);
+    }
+
+    #[test]
+    fn ghosts_leave_the_house_towards_the_entrance() {
+        let center = Pos::new(5, 3).to_vec3(0.0);
+
+        assert_eq!(get_exit_direction(center, Pos::new(9, 3).to_vec3(0.0)), Right);
+        assert_eq!(get_exit_direction(center, Pos::new(5, 0).to_vec3(0.0)), Down);
+    }
+}
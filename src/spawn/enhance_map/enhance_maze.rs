@@ -1,6 +1,5 @@
 use bevy::prelude::*;
 use bevy::utils::HashMap;
-use bevy_sprite_sheet::{SpriteSheet, SpriteSheets};
 use crate::core::prelude::*;
 
 pub(super) struct EnhanceMazePlugin;
@@ -20,11 +19,12 @@ type IsCorner = bool;
 
 fn enhance_maze(
     mut commands: Commands,
-    sprite_sheets: Res<SpriteSheets>,
+    theme: Res<Theme>,
+    animation_sheets: Res<AnimationSheets>,
     mazes: Query<Entity, With<Maze>>,
     walls: Query<(Entity, &Tiles, &WallStyle), With<Wall>>,
 ) {
-    let wall_animations_map = create_animations(&sprite_sheets);
+    let wall_animations_map = create_animations(&theme, &animation_sheets);
 
     commands.entity(mazes.single()).insert(SpatialBundle::default());
 
@@ -50,27 +50,28 @@ fn enhance_maze(
 }
 
 fn create_animations(
-    sprite_sheets: &SpriteSheets
+    theme: &Theme,
+    animation_sheets: &AnimationSheets
 ) -> HashMap<(WallType, IsCorner), Animations> {
     [
-        (Outer, true, "textures/walls/outer_wall_corner"),
-        (Outer, false, "textures/walls/outer_wall"),
-        (Inner, true, "textures/walls/inner_wall_corner"),
-        (Inner, false, "textures/walls/inner_wall"),
+        (Outer, true, SpriteId::OUTER_WALL_CORNER),
+        (Outer, false, SpriteId::OUTER_WALL),
+        (Inner, true, SpriteId::INNER_WALL_CORNER),
+        (Inner, false, SpriteId::INNER_WALL),
     ]
         .into_iter()
-        .map(|(tp, is_corner, sheet_path)| ((tp, is_corner), create_wall_animations(sprite_sheets.get_sheet(sheet_path))))
+        .map(|(tp, is_corner, sprite)| ((tp, is_corner), create_wall_animations(theme.animations(animation_sheets, &sprite))))
         .collect()
 }
 
-fn create_wall_animations(sheet: &SpriteSheet) -> Animations {
+fn create_wall_animations(sheet: &AsepriteAnimations) -> Animations {
     Animations::new(
         [
-            ("idle", Animation::from_texture(sheet.image_at(0))),
-            ("blinking", Animation::from_textures(0.5, true, sheet.images_at([0, 1])))
+            ("idle", sheet.animation("idle")),
+            ("blinking", sheet.animation("blinking"))
         ]
         , "idle",
-    )
+    ).with_clock(MAZE_FLASH_CLOCK)
 }
 
 fn create_transform(tiles: &Tiles, rotation: &Rotation) -> Transform {
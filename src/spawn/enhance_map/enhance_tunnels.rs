@@ -17,10 +17,12 @@ impl Plugin for EnhanceTunnelPlugin {
 
 fn enhance_tunnels(
     mut commands: Commands,
+    theme: Res<Theme>,
     maps: Query<Entity, With<Map>>,
     tunnels: Query<(Entity, &Tunnel, &Tiles)>,
 ) {
     let map = maps.single();
+    let background = theme.color(&ColorId::BACKGROUND);
 
     for (entity, tunnel, tiles) in &tunnels {
         let tunnel_transform = Transform::from_translation(tiles.to_vec3(TUNNEL_Z));
@@ -30,7 +32,7 @@ fn enhance_tunnels(
             .entity(entity)
             .insert(SpriteBundle {
                 sprite: Sprite {
-                    color: Color::srgb(0.0, 0.0, 0.0),
+                    color: background,
                     custom_size: Some(Vec2::splat(TUNNEL_DIMENSION)),
                     ..default()
                 },
@@ -42,7 +44,7 @@ fn enhance_tunnels(
             Name::new("TunnelEntrance"),
             SpriteBundle {
                 sprite: Sprite {
-                    color: Color::srgb(0.0, 0.0, 0.0),
+                    color: background,
                     custom_size: Some(Vec2::splat(TUNNEL_DIMENSION)),
                     ..default()
                 },
@@ -20,7 +20,11 @@ impl Plugin for EnhanceMapPlugin {
             ))
             .add_systems(
                 OnEnter(Spawn(EnhanceMap)),
-                add_spatial_bundle_to_map,
+                (
+                    add_spatial_bundle_to_map,
+                    add_arcade_fruits_to_map,
+                    validate_fruit_tables,
+                ),
             )
             .add_systems(
                 Update,
@@ -37,6 +41,26 @@ fn add_spatial_bundle_to_map(
     commands.entity(maps.single()).insert(SpatialBundle::default());
 }
 
+/// Maps which do not define their own fruits get the ones of the arcade.
+fn add_arcade_fruits_to_map(
+    mut commands: Commands,
+    maps: Query<Entity, (With<Map>, Without<FruitTable>)>,
+) {
+    for map in &maps {
+        commands.entity(map).insert(FruitTable::default());
+    }
+}
+
+/// Make the fruit tables the maps define usable, see [FruitTable::validate].
+fn validate_fruit_tables(
+    theme: Res<Theme>,
+    mut fruit_tables: Query<&mut FruitTable, With<Map>>,
+) {
+    for mut fruit_table in &mut fruit_tables {
+        fruit_table.validate(|sprite| theme.has_image(sprite));
+    }
+}
+
 fn switch_state_after_enhance(
     mut next_state: ResMut<NextState<GameState>>,
 ) {
@@ -0,0 +1,102 @@
+use std::time::Duration;
+
+use bevy::input::ButtonState;
+use bevy::input::InputSystem;
+use bevy::input::keyboard::{Key, KeyboardInput, NativeKey};
+use bevy::prelude::*;
+use crossterm::event;
+use crossterm::event::{Event, KeyEventKind, KeyModifiers};
+
+pub(super) struct TuiInputPlugin;
+
+impl Plugin for TuiInputPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(
+                PreUpdate,
+                read_terminal_input.before(InputSystem),
+            )
+        ;
+    }
+}
+
+const LETTERS: [KeyCode; 26] = [
+    KeyCode::KeyA, KeyCode::KeyB, KeyCode::KeyC, KeyCode::KeyD, KeyCode::KeyE, KeyCode::KeyF, KeyCode::KeyG,
+    KeyCode::KeyH, KeyCode::KeyI, KeyCode::KeyJ, KeyCode::KeyK, KeyCode::KeyL, KeyCode::KeyM, KeyCode::KeyN,
+    KeyCode::KeyO, KeyCode::KeyP, KeyCode::KeyQ, KeyCode::KeyR, KeyCode::KeyS, KeyCode::KeyT, KeyCode::KeyU,
+    KeyCode::KeyV, KeyCode::KeyW, KeyCode::KeyX, KeyCode::KeyY, KeyCode::KeyZ,
+];
+
+const DIGITS: [KeyCode; 10] = [
+    KeyCode::Digit0, KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3, KeyCode::Digit4,
+    KeyCode::Digit5, KeyCode::Digit6, KeyCode::Digit7, KeyCode::Digit8, KeyCode::Digit9,
+];
+
+/// Pass the keys pressed in the terminal to the game like keys pressed in a window. Ctrl+C quits the game.
+///
+/// Most terminals only report when a key gets pressed, not when it gets released. Therefore, every key is
+/// released one frame after it was pressed. Holding a key still works, as the terminal repeats the key press.
+fn read_terminal_input(
+    mut pressed_keys: Local<Vec<KeyCode>>,
+    mut keyboard_events: EventWriter<KeyboardInput>,
+    mut exit_events: EventWriter<AppExit>,
+) {
+    for key_code in pressed_keys.drain(..) {
+        keyboard_events.send(create_keyboard_input(key_code, ButtonState::Released));
+    }
+
+    while let Ok(true) = event::poll(Duration::ZERO) {
+        let Ok(event) = event::read() else {
+            break;
+        };
+
+        let Event::Key(key_event) = event else {
+            continue;
+        };
+
+        if key_event.kind == KeyEventKind::Release {
+            continue;
+        }
+
+        if key_event.modifiers.contains(KeyModifiers::CONTROL) && key_event.code == event::KeyCode::Char('c') {
+            exit_events.send(AppExit::Success);
+            continue;
+        }
+
+        if let Some(key_code) = to_key_code(key_event.code) {
+            keyboard_events.send(create_keyboard_input(key_code, ButtonState::Pressed));
+            pressed_keys.push(key_code);
+        }
+    }
+}
+
+fn create_keyboard_input(key_code: KeyCode, state: ButtonState) -> KeyboardInput {
+    KeyboardInput {
+        key_code,
+        logical_key: Key::Unidentified(NativeKey::Unidentified),
+        state,
+        // there is no window in the terminal
+        window: Entity::PLACEHOLDER,
+    }
+}
+
+/// Map a key from the terminal to the key code the game uses. Keys the game never needs are ignored.
+fn to_key_code(code: event::KeyCode) -> Option<KeyCode> {
+    let key_code = match code {
+        event::KeyCode::Up => KeyCode::ArrowUp,
+        event::KeyCode::Down => KeyCode::ArrowDown,
+        event::KeyCode::Left => KeyCode::ArrowLeft,
+        event::KeyCode::Right => KeyCode::ArrowRight,
+        event::KeyCode::Enter => KeyCode::Enter,
+        event::KeyCode::Esc => KeyCode::Escape,
+        event::KeyCode::Backspace => KeyCode::Backspace,
+        event::KeyCode::Tab => KeyCode::Tab,
+        event::KeyCode::F(11) => KeyCode::F11,
+        event::KeyCode::Char(' ') => KeyCode::Space,
+        event::KeyCode::Char(c) if c.is_ascii_alphabetic() => LETTERS[(c.to_ascii_lowercase() as u8 - b'a') as usize],
+        event::KeyCode::Char(c) if c.is_ascii_digit() => DIGITS[(c as u8 - b'0') as usize],
+        _ => return None
+    };
+
+    Some(key_code)
+}
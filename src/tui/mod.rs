@@ -0,0 +1,70 @@
+use std::io::{stdout, Write};
+use std::time::Duration;
+
+use bevy::app::ScheduleRunnerPlugin;
+use bevy::prelude::*;
+use crossterm::{cursor, execute, terminal};
+
+use crate::headless::headless_default_plugins;
+use crate::tui::input::TuiInputPlugin;
+use crate::tui::render::TuiRenderPlugin;
+
+mod input;
+mod render;
+
+/// Runs the game in the terminal instead of a window, for example on a headless machine or over SSH.
+///
+/// The game itself runs unchanged, only without a window and a GPU. Instead of rendering the sprites,
+/// the maze and everything in it gets drawn from the ECS state with ANSI colors and box-drawing characters.
+/// The keyboard input is read from the terminal.
+pub struct TuiPlugin;
+
+impl Plugin for TuiPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_plugins((
+                headless_default_plugins(),
+                ScheduleRunnerPlugin::run_loop(Duration::from_secs_f64(1.0 / 60.0)),
+                TuiInputPlugin,
+                TuiRenderPlugin,
+            ))
+            .add_systems(Startup, enter_terminal)
+        ;
+    }
+}
+
+/// Restores the terminal when the app gets dropped, so the shell is usable again after the game exits.
+#[derive(Resource)]
+struct TerminalGuard;
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        leave_terminal()
+    }
+}
+
+/// Switch the terminal to raw mode on an alternate screen, so every key press gets to the game
+/// and the maze does not scroll.
+fn enter_terminal(mut commands: Commands) {
+    if let Err(e) = terminal::enable_raw_mode() {
+        error!("could not switch the terminal to raw mode: {e}");
+    }
+
+    let _ = execute!(stdout(), terminal::EnterAlternateScreen, cursor::Hide);
+
+    // the terminal would stay in raw mode if the game crashes
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        leave_terminal();
+        default_hook(info)
+    }));
+
+    commands.insert_resource(TerminalGuard);
+}
+
+fn leave_terminal() {
+    let mut stdout = stdout();
+    let _ = execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen);
+    let _ = terminal::disable_raw_mode();
+    let _ = stdout.flush();
+}
@@ -0,0 +1,385 @@
+use std::io::{stdout, Write};
+use std::time::Duration;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::time::common_conditions::on_real_timer;
+use bevy::utils::HashSet;
+use crossterm::{cursor, queue, style, terminal};
+use crossterm::style::Color;
+
+use crate::core::prelude::*;
+
+pub(super) struct TuiRenderPlugin;
+
+impl Plugin for TuiRenderPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(
+                Last,
+                draw_game.run_if(on_real_timer(FRAME_TIME)),
+            )
+        ;
+    }
+}
+
+/// How often the terminal gets redrawn. Lower than the frame rate of the game to keep the output small over SSH.
+const FRAME_TIME: Duration = Duration::from_millis(33);
+
+/// Every tile of the maze is two characters wide, as characters in a terminal are about twice as high as wide.
+const CELLS_PER_TILE: usize = 2;
+/// Rows above the maze for the player labels and scores.
+const HEADER_ROWS: usize = 2;
+/// Rows below the maze for the lives, the level and messages.
+const FOOTER_ROWS: usize = 2;
+
+const WALL_COLOR: Color = Color::Rgb { r: 33, g: 33, b: 222 };
+const DOT_COLOR: Color = Color::Rgb { r: 255, g: 184, b: 174 };
+const PACMAN_COLOR: Color = Color::Rgb { r: 255, g: 255, b: 0 };
+const FRIGHTENED_COLOR: Color = Color::Rgb { r: 33, g: 33, b: 255 };
+const TEXT_COLOR: Color = Color::White;
+const READY_COLOR: Color = Color::Rgb { r: 255, g: 255, b: 0 };
+const GAME_OVER_COLOR: Color = Color::Rgb { r: 255, g: 0, b: 0 };
+const PLAYER_COLOR: Color = Color::Rgb { r: 0, g: 255, b: 255 };
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct Cell {
+    glyph: char,
+    color: Color,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Cell {
+            glyph: ' ',
+            color: TEXT_COLOR,
+        }
+    }
+}
+
+/// The characters of one frame, which get written to the terminal.
+#[derive(Default, Eq, PartialEq)]
+struct Canvas {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+}
+
+impl Canvas {
+    fn new(width: usize, height: usize) -> Self {
+        Canvas {
+            width,
+            height,
+            cells: vec![Cell::default(); width * height],
+        }
+    }
+
+    fn set(&mut self, column: usize, row: usize, glyph: char, color: Color) {
+        if column < self.width && row < self.height {
+            self.cells[row * self.width + column] = Cell { glyph, color };
+        }
+    }
+
+    /// Draw a character in the first cell of the given maze tile.
+    fn set_tile(&mut self, map: &Map, pos: Pos, glyph: char, color: Color) {
+        if let Some((column, row)) = tile_to_cell(map, pos) {
+            self.set(column, row, glyph, color);
+            self.set(column + 1, row, ' ', color);
+        }
+    }
+
+    fn write(&mut self, column: usize, row: usize, text: &str, color: Color) {
+        for (i, glyph) in text.chars().enumerate() {
+            self.set(column + i, row, glyph, color)
+        }
+    }
+
+    fn write_centered(&mut self, row: usize, text: &str, color: Color) {
+        let column = self.width.saturating_sub(text.chars().count()) / 2;
+        self.write(column, row, text, color)
+    }
+
+    fn write_right_aligned(&mut self, row: usize, text: &str, color: Color) {
+        let column = self.width.saturating_sub(text.chars().count());
+        self.write(column, row, text, color)
+    }
+}
+
+/// Everything on the screen which is not part of the maze.
+#[derive(SystemParam)]
+struct Hud<'w> {
+    game_state: Res<'w, State<GameState>>,
+    virtual_time: Res<'w, Time<Virtual>>,
+    score: Res<'w, Score>,
+    high_score: Res<'w, HighScore>,
+    lives: Res<'w, Lives>,
+    level: Res<'w, Level>,
+    active_player: Res<'w, ActivePlayer>,
+    game_mode: Res<'w, CurrentGameMode>,
+    other_player: Option<Res<'w, OtherPlayer>>,
+}
+
+/// The entities which are drawn in the maze.
+#[derive(SystemParam)]
+struct MazeEntities<'w, 's> {
+    settings: Res<'w, Settings>,
+    real_time: Res<'w, Time<Real>>,
+    energizer_timer: Option<Res<'w, EnergizerTimer>>,
+    walls: Query<'w, 's, &'static Transform, With<Wall>>,
+    dots: Query<'w, 's, &'static Transform, With<Dot>>,
+    energizers: Query<'w, 's, &'static Transform, With<Energizer>>,
+    fruits: Query<'w, 's, (&'static Transform, &'static Fruit)>,
+    pacmen: Query<'w, 's, (&'static Transform, &'static Dir, &'static Visibility), With<Pacman>>,
+    ghosts: Query<'w, 's, (&'static Transform, &'static Ghost, &'static GhostState, &'static Visibility)>,
+}
+
+/// Draw the current frame to the terminal. Only frames which differ from the last one are written.
+fn draw_game(
+    mut last_canvas: Local<Canvas>,
+    hud: Hud,
+    maze: MazeEntities,
+    maps: Query<&Map>,
+    localization: Option<Res<Localization>>,
+) {
+    let (Ok(map), Some(localization)) = (maps.get_single(), localization) else {
+        return;
+    };
+
+    let mut canvas = Canvas::new(map.width * CELLS_PER_TILE, HEADER_ROWS + map.height + FOOTER_ROWS);
+    draw_walls(&mut canvas, map, &maze);
+    draw_edibles(&mut canvas, map, &maze);
+    draw_ghosts(&mut canvas, map, &maze);
+    draw_pacmen(&mut canvas, map, &maze);
+    draw_hud(&mut canvas, &hud, &localization);
+
+    if *last_canvas == canvas {
+        return;
+    }
+
+    let clear = last_canvas.width != canvas.width || last_canvas.height != canvas.height;
+
+    if let Err(e) = write_canvas(&canvas, clear) {
+        error!("could not draw to the terminal: {e}");
+    }
+
+    *last_canvas = canvas;
+}
+
+/// Draw the walls as lines along the paths of the maze. Walls inside thick blocks stay empty,
+/// so only the outlines of the blocks are visible, like in the arcade.
+fn draw_walls(canvas: &mut Canvas, map: &Map, maze: &MazeEntities) {
+    let walls = maze.walls
+        .iter()
+        .map(|transform| Pos::from_vec3(transform.translation))
+        .collect::<HashSet<_>>();
+    let is_open = |pos: Pos| is_in_map(map, pos) && !walls.contains(&pos);
+    let wall_color = match maze.settings.accessibility.high_contrast_maze {
+        true => rgb(HIGH_CONTRAST_WALL_COLOR),
+        false => WALL_COLOR,
+    };
+
+    for pos in &walls {
+        let (x, y) = (pos.x(), pos.y());
+        let up = Pos::new(x, y + 1);
+        let down = Pos::new(x, y - 1);
+        let left = Pos::new(x - 1, y);
+        let right = Pos::new(x + 1, y);
+
+        // two walls are connected if there is a path along both of them
+        let connected_horizontally = |other: Pos| walls.contains(&other)
+            && [up, down, Pos::new(other.x(), y + 1), Pos::new(other.x(), y - 1)].into_iter().any(is_open);
+        let connected_vertically = |other: Pos| walls.contains(&other)
+            && [left, right, Pos::new(x - 1, other.y()), Pos::new(x + 1, other.y())].into_iter().any(is_open);
+
+        let glyph = match (
+            connected_vertically(up),
+            connected_vertically(down),
+            connected_horizontally(left),
+            connected_horizontally(right),
+        ) {
+            (false, false, false, false) => continue,
+            (true, true, false, false) | (true, false, false, false) | (false, true, false, false) => '│',
+            (false, false, true, true) | (false, false, true, false) | (false, false, false, true) => '─',
+            (false, true, false, true) => '┌',
+            (false, true, true, false) => '┐',
+            (true, false, false, true) => '└',
+            (true, false, true, false) => '┘',
+            (true, true, false, true) => '├',
+            (true, true, true, false) => '┤',
+            (false, true, true, true) => '┬',
+            (true, false, true, true) => '┴',
+            (true, true, true, true) => '┼',
+        };
+
+        if let Some((column, row)) = tile_to_cell(map, *pos) {
+            canvas.set(column, row, glyph, wall_color);
+
+            if connected_horizontally(right) {
+                canvas.set(column + 1, row, '─', wall_color);
+            }
+        }
+    }
+}
+
+fn draw_edibles(canvas: &mut Canvas, map: &Map, maze: &MazeEntities) {
+    for transform in &maze.dots {
+        canvas.set_tile(map, Pos::from_vec3(transform.translation), '·', DOT_COLOR)
+    }
+
+    for transform in &maze.energizers {
+        canvas.set_tile(map, Pos::from_vec3(transform.translation), '●', DOT_COLOR)
+    }
+
+    for (transform, fruit) in &maze.fruits {
+        canvas.set_tile(map, Pos::from_vec3(transform.translation), '%', fruit_color(fruit))
+    }
+}
+
+/// Draw every visible ghost in its color from the ghost palette. Frightened ghosts are blue and start
+/// blinking when the energizer is almost over. Eaten ghosts are only eyes. With the ghost markers, the
+/// initial of the ghost is drawn next to it.
+fn draw_ghosts(canvas: &mut Canvas, map: &Map, maze: &MazeEntities) {
+    let blink = maze.energizer_timer
+        .as_ref()
+        .is_some_and(|timer| timer.is_running_out())
+        && (maze.real_time.elapsed_seconds() * 2.0).fract() < 0.5;
+
+    let accessibility = &maze.settings.accessibility;
+
+    for (transform, ghost, state, visibility) in &maze.ghosts {
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
+
+        let (glyph, color) = match state {
+            Frightened if blink => ('Ω', Color::White),
+            Frightened => ('Ω', FRIGHTENED_COLOR),
+            Eaten => ('"', Color::White),
+            _ => ('Ω', rgb(accessibility.ghost_palette.body_color(*ghost))),
+        };
+        let pos = Pos::from_vec3(transform.translation);
+        canvas.set_tile(map, pos, glyph, color);
+
+        if let (true, Some((column, row))) = (accessibility.ghost_markers, tile_to_cell(map, pos)) {
+            canvas.write(column + 1, row, ghost_marker(*ghost), TEXT_COLOR)
+        }
+    }
+}
+
+/// Draw pacman with his mouth open in the direction he moves.
+fn draw_pacmen(canvas: &mut Canvas, map: &Map, maze: &MazeEntities) {
+    for (transform, dir, visibility) in &maze.pacmen {
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
+
+        let glyph = match dir {
+            Up => 'V',
+            Down => '^',
+            Left => '>',
+            Right => '<',
+        };
+
+        canvas.set_tile(map, Pos::from_vec3(transform.translation), glyph, PACMAN_COLOR)
+    }
+}
+
+fn draw_hud(canvas: &mut Canvas, hud: &Hud, localization: &Localization) {
+    canvas.write(0, 0, localization.text(&TextId::player_label(Player::One)), TEXT_COLOR);
+    canvas.write_centered(0, localization.text(&TextId::HIGH_SCORE), TEXT_COLOR);
+    canvas.write_centered(1, &hud.high_score.score.to_string(), TEXT_COLOR);
+
+    let (score_one, score_two) = match **hud.active_player {
+        Player::One => (Some(**hud.score), hud.other_player.as_ref().map(|other| *other.0.score)),
+        Player::Two => (hud.other_player.as_ref().map(|other| *other.0.score), Some(**hud.score)),
+    };
+
+    if let Some(score) = score_one {
+        canvas.write(0, 1, &score.to_string(), TEXT_COLOR);
+    }
+
+    if let Some(score) = score_two {
+        canvas.write_right_aligned(0, localization.text(&TextId::player_label(Player::Two)), TEXT_COLOR);
+        canvas.write_right_aligned(1, &score.to_string(), TEXT_COLOR);
+    }
+
+    let lives_row = canvas.height - FOOTER_ROWS;
+    let lives = (0..**hud.lives).map(|_| "< ").collect::<String>();
+    canvas.write(0, lives_row, &lives, PACMAN_COLOR);
+    canvas.write_right_aligned(lives_row, &format!("{} {}", localization.text(&TextId::LEVEL), **hud.level), TEXT_COLOR);
+
+    let message_row = canvas.height - 1;
+
+    if hud.virtual_time.is_paused() {
+        canvas.write_centered(message_row, localization.text(&TextId::PAUSED), TEXT_COLOR);
+        return;
+    }
+
+    let ready = localization.text(&TextId::READY);
+
+    match hud.game_state.get() {
+        Game(Ready) if **hud.game_mode == GameMode::Alternating => {
+            let name = localization.text(&TextId::player_name(**hud.active_player));
+            canvas.write_centered(message_row, &format!("{name}  {ready}"), PLAYER_COLOR)
+        }
+        Game(Ready) => canvas.write_centered(message_row, ready, READY_COLOR),
+        Game(GameOver) => canvas.write_centered(message_row, localization.text(&TextId::GAME_OVER), GAME_OVER_COLOR),
+        _ => {}
+    }
+}
+
+fn write_canvas(canvas: &Canvas, clear: bool) -> std::io::Result<()> {
+    let mut stdout = stdout().lock();
+
+    if clear {
+        queue!(stdout, terminal::Clear(terminal::ClearType::All))?;
+    }
+
+    let mut current_color = None;
+
+    for (row, cells) in canvas.cells.chunks(canvas.width).enumerate() {
+        queue!(stdout, cursor::MoveTo(0, row as u16))?;
+
+        for cell in cells {
+            if current_color != Some(cell.color) {
+                queue!(stdout, style::SetForegroundColor(cell.color))?;
+                current_color = Some(cell.color);
+            }
+
+            queue!(stdout, style::Print(cell.glyph))?;
+        }
+    }
+
+    queue!(stdout, style::ResetColor)?;
+    stdout.flush()
+}
+
+fn is_in_map(map: &Map, pos: Pos) -> bool {
+    pos.x() >= 0 && pos.y() >= 0 && (pos.x() as usize) < map.width && (pos.y() as usize) < map.height
+}
+
+/// Return the column and row of the first cell of a maze tile. The y-axis of the maze points up,
+/// while the rows of the terminal go down.
+fn tile_to_cell(map: &Map, pos: Pos) -> Option<(usize, usize)> {
+    if !is_in_map(map, pos) {
+        return None;
+    }
+
+    let column = pos.x() as usize * CELLS_PER_TILE;
+    let row = HEADER_ROWS + map.height - 1 - pos.y() as usize;
+    Some((column, row))
+}
+
+fn rgb([r, g, b]: [u8; 3]) -> Color {
+    Color::Rgb { r, g, b }
+}
+
+fn fruit_color(fruit: &Fruit) -> Color {
+    match fruit {
+        Cherry | Strawberry | Apple => Color::Rgb { r: 255, g: 0, b: 0 },
+        Peach => Color::Rgb { r: 255, g: 184, b: 82 },
+        Grapes => Color::Rgb { r: 0, g: 255, b: 0 },
+        Galaxian | Bell => Color::Rgb { r: 255, g: 255, b: 0 },
+        Key => Color::Rgb { r: 0, g: 255, b: 255 },
+    }
+}